@@ -0,0 +1,49 @@
+//! Serves the types inside of a directory laid out like a [`FsTypeRepository`].
+//!
+//! Usage: `bp-type-server <directory> [<address>] [<origin>]`
+//!
+//! The server listens on `<address>`, which defaults to `127.0.0.1:3000`. Types are expected to be
+//! hosted at `<origin>`, which defaults to `http://<address>/`.
+
+use std::{env, process::ExitCode};
+
+use type_system::{
+    repository::{FsTypeRepository, TypeServer},
+    uri::BaseUri,
+};
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    let directory = match args.next() {
+        Some(directory) => directory,
+        None => {
+            eprintln!("usage: bp-type-server <directory> [<address>] [<origin>]");
+            return ExitCode::FAILURE;
+        }
+    };
+    let address = args.next().unwrap_or_else(|| "127.0.0.1:3000".to_owned());
+    let origin = args.next().unwrap_or_else(|| format!("http://{address}/"));
+
+    let origin = match BaseUri::new(origin) {
+        Ok(origin) => origin,
+        Err(error) => {
+            eprintln!("invalid origin: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let server = match tiny_http::Server::http(&address) {
+        Ok(server) => server,
+        Err(error) => {
+            eprintln!("could not listen on `{address}`: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    eprintln!("serving `{directory}` as `{origin}` on `{address}`");
+    if let Err(error) = TypeServer::new(FsTypeRepository::new(directory), origin).serve(&server) {
+        eprintln!("{error}");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
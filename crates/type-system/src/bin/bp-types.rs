@@ -0,0 +1,537 @@
+//! Command-line tool for working with files containing types of the Type System.
+//!
+//! Every subcommand prints a single JSON document to stdout. The exit code is `0` on success, `1`
+//! if a check failed, e.g. a file is not a valid type, and `2` if the command could not be run.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+};
+
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+use serde_json::{json, Value};
+use type_system::{
+    lint::{Lint, LintConfig, LintDiagnostic, Severity},
+    patch,
+    repository::{FsTypeRepository, TypeRepository, TypeResolver},
+    uri::VersionedUri,
+    Compatibility, DataType, EntityType, LinkType, OntologyType, OntologyTypeKind,
+    ParseOntologyTypeError, PropertyType,
+};
+
+#[derive(Debug, Parser)]
+#[clap(name = "bp-types", version, about)]
+struct Cli {
+    /// Pretty-print the JSON output
+    #[clap(long, global = true)]
+    pretty: bool,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Validates type files
+    Validate {
+        #[clap(required = true)]
+        files: Vec<PathBuf>,
+    },
+    /// Checks type files against authoring conventions
+    Lint {
+        #[clap(required = true)]
+        files: Vec<PathBuf>,
+        /// A JSON file configuring the lint rules
+        #[clap(long)]
+        config: Option<PathBuf>,
+    },
+    /// Prints the JSON Patch transforming one type into another
+    Diff { old: PathBuf, new: PathBuf },
+    /// Checks whether data valid under the old type is still valid under the new type
+    CheckCompat { old: PathBuf, new: PathBuf },
+    /// Prints all types of a repository directory as a single bundle
+    Bundle { root: PathBuf },
+    /// Prints all types a type transitively depends on
+    Deps {
+        uri: String,
+        /// The repository directory to resolve types from
+        #[clap(long)]
+        root: PathBuf,
+    },
+}
+
+/// The outcome of a subcommand, which determines the exit code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    /// The command succeeded.
+    Success,
+    /// A check failed, e.g. a file is not a valid type.
+    Failure,
+    /// The command could not be run.
+    Error,
+}
+
+impl Status {
+    const fn from_success(success: bool) -> Self {
+        if success {
+            Self::Success
+        } else {
+            Self::Failure
+        }
+    }
+}
+
+impl From<Status> for ExitCode {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Success => Self::SUCCESS,
+            Status::Failure => Self::from(1),
+            Status::Error => Self::from(2),
+        }
+    }
+}
+
+/// An error related to a single type file.
+#[derive(Debug, Serialize)]
+#[serde(tag = "reason", content = "inner")]
+enum FileError {
+    Io(String),
+    InvalidJson(String),
+    UnknownKind(Value),
+    InvalidType(ParseOntologyTypeError),
+    MismatchedKind {
+        old: OntologyTypeKind,
+        new: OntologyTypeKind,
+    },
+}
+
+enum AnyType {
+    Data(DataType),
+    Property(PropertyType),
+    Entity(EntityType),
+    Link(LinkType),
+}
+
+impl AnyType {
+    fn read(path: &Path) -> Result<Self, FileError> {
+        let contents =
+            fs::read_to_string(path).map_err(|error| FileError::Io(error.to_string()))?;
+        let value: Value = serde_json::from_str(&contents)
+            .map_err(|error| FileError::InvalidJson(error.to_string()))?;
+        let kind = value.get("kind").cloned().unwrap_or_default();
+        let kind =
+            serde_json::from_value(kind.clone()).map_err(|_| FileError::UnknownKind(kind))?;
+
+        match kind {
+            OntologyTypeKind::DataType => DataType::try_from(value)
+                .map(Self::Data)
+                .map_err(|error| FileError::InvalidType(error.into())),
+            OntologyTypeKind::PropertyType => PropertyType::try_from(value)
+                .map(Self::Property)
+                .map_err(|error| FileError::InvalidType(error.into())),
+            OntologyTypeKind::EntityType => EntityType::try_from(value)
+                .map(Self::Entity)
+                .map_err(|error| FileError::InvalidType(error.into())),
+            OntologyTypeKind::LinkType => LinkType::try_from(value)
+                .map(Self::Link)
+                .map_err(|error| FileError::InvalidType(error.into())),
+        }
+    }
+
+    const fn kind(&self) -> OntologyTypeKind {
+        match self {
+            Self::Data(_) => OntologyTypeKind::DataType,
+            Self::Property(_) => OntologyTypeKind::PropertyType,
+            Self::Entity(_) => OntologyTypeKind::EntityType,
+            Self::Link(_) => OntologyTypeKind::LinkType,
+        }
+    }
+
+    fn id(&self) -> &VersionedUri {
+        match self {
+            Self::Data(data_type) => data_type.id(),
+            Self::Property(property_type) => property_type.id(),
+            Self::Entity(entity_type) => entity_type.id(),
+            Self::Link(link_type) => link_type.id(),
+        }
+    }
+
+    fn warnings(&self) -> Vec<Value> {
+        let result = match self {
+            Self::Data(data_type) => data_type.validate_kind(),
+            Self::Property(property_type) => property_type.validate_kind(),
+            Self::Entity(entity_type) => entity_type.validate_kind(),
+            Self::Link(link_type) => link_type.validate_kind(),
+        };
+        result.err().into_iter().map(|error| json!(error)).collect()
+    }
+
+    fn lint(&self, config: &LintConfig) -> Vec<LintDiagnostic> {
+        match self {
+            Self::Data(data_type) => data_type.lint(config),
+            Self::Property(property_type) => property_type.lint(config),
+            Self::Entity(entity_type) => entity_type.lint(config),
+            Self::Link(link_type) => link_type.lint(config),
+        }
+    }
+}
+
+/// Reads the two files and ensures they contain the same kind of type.
+fn read_pair(old: &Path, new: &Path) -> Result<(AnyType, AnyType), Value> {
+    let read =
+        |path: &Path| AnyType::read(path).map_err(|error| json!({ "path": path, "error": error }));
+    let (old, new) = (read(old)?, read(new)?);
+    if old.kind() == new.kind() {
+        Ok((old, new))
+    } else {
+        Err(json!({
+            "error": FileError::MismatchedKind {
+                old: old.kind(),
+                new: new.kind(),
+            }
+        }))
+    }
+}
+
+/// Runs the subcommand and returns its output together with its [`Status`].
+fn run(command: Command) -> (Value, Status) {
+    match command {
+        Command::Validate { files } => validate(&files),
+        Command::Lint { files, config } => lint(&files, config.as_deref()),
+        Command::Diff { old, new } => diff(&old, &new),
+        Command::CheckCompat { old, new } => check_compat(&old, &new),
+        Command::Bundle { root } => bundle(&root),
+        Command::Deps { uri, root } => deps(&uri, &root),
+    }
+}
+
+fn validate(files: &[PathBuf]) -> (Value, Status) {
+    let mut success = true;
+    let files = files
+        .iter()
+        .map(|path| match AnyType::read(path) {
+            Ok(ontology_type) => json!({
+                "path": path,
+                "valid": true,
+                "kind": ontology_type.kind(),
+                "$id": ontology_type.id().to_string(),
+                "warnings": ontology_type.warnings(),
+            }),
+            Err(error) => {
+                success = false;
+                json!({ "path": path, "valid": false, "error": error })
+            }
+        })
+        .collect::<Vec<_>>();
+
+    (json!({ "files": files }), Status::from_success(success))
+}
+
+fn read_lint_config(path: &Path) -> Result<LintConfig, Value> {
+    fs::read_to_string(path)
+        .map_err(|error| json!({ "path": path, "error": FileError::Io(error.to_string()) }))?
+        .parse()
+        .map_err(|error| json!({ "path": path, "error": error }))
+}
+
+fn lint(files: &[PathBuf], config: Option<&Path>) -> (Value, Status) {
+    let config = match config.map(read_lint_config).transpose() {
+        Ok(config) => config.unwrap_or_default(),
+        Err(error) => return (error, Status::Error),
+    };
+
+    let mut success = true;
+    let files = files
+        .iter()
+        .map(|path| match AnyType::read(path) {
+            Ok(ontology_type) => {
+                let diagnostics = ontology_type.lint(&config);
+                if diagnostics
+                    .iter()
+                    .any(|diagnostic| diagnostic.severity() == Severity::Error)
+                {
+                    success = false;
+                }
+                json!({
+                    "path": path,
+                    "$id": ontology_type.id().to_string(),
+                    "diagnostics": diagnostics,
+                })
+            }
+            Err(error) => {
+                success = false;
+                json!({ "path": path, "error": error })
+            }
+        })
+        .collect::<Vec<_>>();
+
+    (json!({ "files": files }), Status::from_success(success))
+}
+
+fn diff(old: &Path, new: &Path) -> (Value, Status) {
+    let patch = match read_pair(old, new) {
+        Ok((AnyType::Data(old), AnyType::Data(new))) => patch::diff(&old, &new),
+        Ok((AnyType::Property(old), AnyType::Property(new))) => patch::diff(&old, &new),
+        Ok((AnyType::Entity(old), AnyType::Entity(new))) => patch::diff(&old, &new),
+        Ok((AnyType::Link(old), AnyType::Link(new))) => patch::diff(&old, &new),
+        Ok(_) => unreachable!("the kinds of the types were checked"),
+        Err(error) => return (error, Status::Error),
+    };
+
+    (json!({ "patch": patch }), Status::Success)
+}
+
+fn check_compat(old: &Path, new: &Path) -> (Value, Status) {
+    fn check<T: Compatibility>(old: &T, new: &T) -> (bool, bool) {
+        (old.is_compatible_with(new), old.is_equivalent_to(new))
+    }
+
+    let (compatible, equivalent) = match read_pair(old, new) {
+        Ok((AnyType::Data(old), AnyType::Data(new))) => check(&old, &new),
+        Ok((AnyType::Property(old), AnyType::Property(new))) => check(&old, &new),
+        Ok((AnyType::Entity(old), AnyType::Entity(new))) => check(&old, &new),
+        Ok((AnyType::Link(old), AnyType::Link(new))) => check(&old, &new),
+        Ok(_) => unreachable!("the kinds of the types were checked"),
+        Err(error) => return (error, Status::Error),
+    };
+
+    (
+        json!({ "compatible": compatible, "equivalent": equivalent }),
+        Status::from_success(compatible),
+    )
+}
+
+fn bundle(root: &Path) -> (Value, Status) {
+    match FsTypeRepository::new(root).load_bundle() {
+        Ok(bundle) => (Value::from(bundle), Status::Success),
+        Err(error) => (json!({ "error": error.to_string() }), Status::Error),
+    }
+}
+
+fn deps(uri: &str, root: &Path) -> (Value, Status) {
+    match resolve_dependencies(uri, root) {
+        Ok(output) => (output, Status::Success),
+        Err(error) => (error, Status::Error),
+    }
+}
+
+fn resolve_dependencies(uri: &str, root: &Path) -> Result<Value, Value> {
+    let uri = VersionedUri::from_str(uri).map_err(|error| json!({ "error": error }))?;
+    let kind = uri
+        .base_uri()
+        .components()
+        .map_err(|error| json!({ "error": error }))?
+        .kind();
+
+    let bundle = FsTypeRepository::new(root)
+        .resolve_with_dependencies(kind, &uri)
+        .map_err(|error| json!({ "error": error.to_string() }))?;
+
+    let mut dependencies: Vec<_> = bundle
+        .data_types()
+        .map(|data_type| (OntologyTypeKind::DataType, data_type.id()))
+        .chain(
+            bundle
+                .property_types()
+                .map(|property_type| (OntologyTypeKind::PropertyType, property_type.id())),
+        )
+        .chain(
+            bundle
+                .entity_types()
+                .map(|entity_type| (OntologyTypeKind::EntityType, entity_type.id())),
+        )
+        .chain(
+            bundle
+                .link_types()
+                .map(|link_type| (OntologyTypeKind::LinkType, link_type.id())),
+        )
+        .filter(|(_, id)| **id != uri)
+        .map(|(kind, id)| json!({ "kind": kind, "$id": id.to_string() }))
+        .collect();
+    dependencies.sort_by_cached_key(|dependency| dependency["$id"].to_string());
+
+    Ok(json!({ "$id": uri.to_string(), "dependencies": dependencies }))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let (output, status) = run(cli.command);
+
+    if cli.pretty {
+        println!("{output:#}");
+    } else {
+        println!("{output}");
+    }
+
+    status.into()
+}
+
+#[cfg(test)]
+#[path = "../../tests/data/lib.rs"]
+mod test_data;
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    /// Writes the `files` into a temporary directory.
+    fn directory(files: &[(&str, &str)]) -> TempDir {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        for (name, contents) in files {
+            fs::write(directory.path().join(name), contents).expect("failed to write file");
+        }
+        directory
+    }
+
+    fn command(directory: &TempDir, args: &[&str]) -> Command {
+        let args = args.iter().map(|arg| {
+            if arg.ends_with(".json") {
+                directory.path().join(arg).into_os_string()
+            } else {
+                arg.into()
+            }
+        });
+        Cli::try_parse_from(["bp-types".into()].into_iter().chain(args))
+            .expect("invalid arguments")
+            .command
+    }
+
+    #[test]
+    fn validate_files() {
+        let directory = directory(&[
+            ("text.json", test_data::data_type::TEXT_V1),
+            ("invalid.json", "{"),
+            ("unknown.json", r#"{ "kind": "blockType" }"#),
+        ]);
+
+        let (output, status) = run(command(&directory, &[
+            "validate",
+            "text.json",
+            "invalid.json",
+            "unknown.json",
+        ]));
+        assert_eq!(status, Status::Failure);
+
+        let files = output["files"].as_array().expect("files are not an array");
+        assert_eq!(files[0]["valid"], true);
+        assert_eq!(files[0]["kind"], "dataType");
+        assert_eq!(
+            files[0]["$id"],
+            "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1"
+        );
+        assert_eq!(files[1]["valid"], false);
+        assert_eq!(files[1]["error"]["reason"], "InvalidJson");
+        assert_eq!(files[2]["error"]["reason"], "UnknownKind");
+
+        let (_, status) = run(command(&directory, &["validate", "text.json"]));
+        assert_eq!(status, Status::Success);
+    }
+
+    #[test]
+    fn lint_with_missing_config() {
+        let directory = directory(&[("text.json", test_data::data_type::TEXT_V1)]);
+
+        let (output, status) = run(command(&directory, &[
+            "lint",
+            "text.json",
+            "--config",
+            "missing.json",
+        ]));
+        assert_eq!(status, Status::Error);
+        assert_eq!(output["error"]["reason"], "Io");
+
+        let (output, status) = run(command(&directory, &["lint", "text.json"]));
+        assert_ne!(status, Status::Error);
+        assert!(output["files"][0]["diagnostics"].is_array());
+    }
+
+    #[test]
+    fn diff_versions() {
+        let directory = directory(&[
+            ("v1.json", test_data::property_type::USER_ID_V1),
+            ("v2.json", test_data::property_type::USER_ID_V2),
+        ]);
+
+        let (output, status) = run(command(&directory, &["diff", "v1.json", "v2.json"]));
+        assert_eq!(status, Status::Success);
+        assert!(
+            !output["patch"]
+                .as_array()
+                .expect("patch is not an array")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn check_compat_of_versions() {
+        let directory = directory(&[
+            ("v1.json", test_data::property_type::USER_ID_V1),
+            ("v2.json", test_data::property_type::USER_ID_V2),
+        ]);
+
+        let (output, status) = run(command(&directory, &["check-compat", "v1.json", "v2.json"]));
+        assert_eq!(status, Status::Success);
+        assert_eq!(output, json!({ "compatible": true, "equivalent": false }));
+
+        let (output, status) = run(command(&directory, &["check-compat", "v2.json", "v1.json"]));
+        assert_eq!(status, Status::Failure);
+        assert_eq!(output, json!({ "compatible": false, "equivalent": false }));
+    }
+
+    #[test]
+    fn mismatched_kinds() {
+        let directory = directory(&[
+            ("text.json", test_data::data_type::TEXT_V1),
+            ("name.json", test_data::property_type::NAME_V1),
+        ]);
+
+        for subcommand in ["diff", "check-compat"] {
+            let (output, status) =
+                run(command(&directory, &[subcommand, "text.json", "name.json"]));
+            assert_eq!(status, Status::Error);
+            assert_eq!(output["error"]["reason"], "MismatchedKind");
+        }
+    }
+
+    #[test]
+    fn bundle_and_deps() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = FsTypeRepository::new(directory.path());
+        repository
+            .store(&DataType::from_str(test_data::data_type::TEXT_V1).expect("invalid data type"))
+            .expect("failed to store type");
+        repository
+            .store(
+                &PropertyType::from_str(test_data::property_type::NAME_V1)
+                    .expect("invalid property type"),
+            )
+            .expect("failed to store type");
+        let root = directory.path().to_string_lossy();
+
+        let (_, status) = run(command(&directory, &["bundle", &root]));
+        assert_eq!(status, Status::Success);
+
+        let (output, status) = run(command(&directory, &[
+            "deps",
+            "https://blockprotocol.org/@alice/types/property-type/name/v/1",
+            "--root",
+            &root,
+        ]));
+        assert_eq!(status, Status::Success);
+        assert_eq!(
+            output["dependencies"],
+            json!([{
+                "kind": "dataType",
+                "$id": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1",
+            }])
+        );
+
+        let (_, status) = run(command(&directory, &["deps", "invalid", "--root", &root]));
+        assert_eq!(status, Status::Error);
+    }
+}
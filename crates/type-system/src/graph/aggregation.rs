@@ -0,0 +1,264 @@
+use serde::{Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::uri::VersionedUri;
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FilterOperator {
+    Contains,
+    DoesNotContain,
+    Is,
+    IsNot,
+    StartsWith,
+    EndsWith,
+    IsEmpty,
+    IsNotEmpty,
+}
+
+impl FilterOperator {
+    /// Returns if a [`Filter`] using this operator has to provide a value to compare with.
+    #[must_use]
+    pub const fn requires_value(self) -> bool {
+        !matches!(self, Self::IsEmpty | Self::IsNotEmpty)
+    }
+}
+
+/// Compares the `field` of an entity with a value.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Filter {
+    field: String,
+    operator: FilterOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+}
+
+impl Filter {
+    /// Creates a filter using an operator which compares the field with `value`.
+    #[must_use]
+    pub const fn new(field: String, operator: FilterOperator, value: String) -> Self {
+        Self {
+            field,
+            operator,
+            value: Some(value),
+        }
+    }
+
+    /// Creates a filter using an operator which doesn't require a value, like
+    /// [`FilterOperator::IsEmpty`].
+    #[must_use]
+    pub const fn without_value(field: String, operator: FilterOperator) -> Self {
+        Self {
+            field,
+            operator,
+            value: None,
+        }
+    }
+
+    #[must_use]
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    #[must_use]
+    pub const fn operator(&self) -> FilterOperator {
+        self.operator
+    }
+
+    #[must_use]
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MultiFilterOperator {
+    And,
+    Or,
+}
+
+/// Combines multiple [`Filter`]s using an [`MultiFilterOperator`].
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MultiFilter {
+    filters: Vec<Filter>,
+    operator: MultiFilterOperator,
+}
+
+impl MultiFilter {
+    #[must_use]
+    pub const fn new(filters: Vec<Filter>, operator: MultiFilterOperator) -> Self {
+        Self { filters, operator }
+    }
+
+    #[must_use]
+    pub fn filters(&self) -> &[Filter] {
+        &self.filters
+    }
+
+    #[must_use]
+    pub const fn operator(&self) -> MultiFilterOperator {
+        self.operator
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Sort {
+    field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    desc: Option<bool>,
+}
+
+impl Sort {
+    #[must_use]
+    pub const fn new(field: String) -> Self {
+        Self { field, desc: None }
+    }
+
+    #[must_use]
+    pub const fn with_descending(mut self, desc: bool) -> Self {
+        self.desc = Some(desc);
+        self
+    }
+
+    #[must_use]
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// Returns if the entities are sorted in descending order, which is `false` if unspecified.
+    #[must_use]
+    pub fn is_descending(&self) -> bool {
+        self.desc.unwrap_or(false)
+    }
+}
+
+/// An operation to aggregate entities or entity types.
+///
+/// Blocks send the operation they want to apply, and the embedding application responds with the
+/// operation it applied, including any defaults it set and the number of available pages and
+/// results.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AggregationOperation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    entity_type_id: Option<VersionedUri>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    page_number: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    items_per_page: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    multi_sort: Option<Vec<Sort>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    multi_filter: Option<MultiFilter>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    page_count: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    total_count: Option<usize>,
+}
+
+impl AggregationOperation {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_entity_type_id(mut self, entity_type_id: VersionedUri) -> Self {
+        self.entity_type_id = Some(entity_type_id);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_page(mut self, page_number: usize, items_per_page: usize) -> Self {
+        self.page_number = Some(page_number);
+        self.items_per_page = Some(items_per_page);
+        self
+    }
+
+    #[must_use]
+    pub fn with_multi_sort(mut self, multi_sort: Vec<Sort>) -> Self {
+        self.multi_sort = Some(multi_sort);
+        self
+    }
+
+    #[must_use]
+    pub fn with_multi_filter(mut self, multi_filter: MultiFilter) -> Self {
+        self.multi_filter = Some(multi_filter);
+        self
+    }
+
+    /// Sets the number of pages and the total number of results, as returned by the embedding
+    /// application.
+    #[must_use]
+    pub const fn with_counts(mut self, page_count: usize, total_count: usize) -> Self {
+        self.page_count = Some(page_count);
+        self.total_count = Some(total_count);
+        self
+    }
+
+    /// Returns the entity type of the entities to aggregate.
+    #[must_use]
+    pub const fn entity_type_id(&self) -> Option<&VersionedUri> {
+        self.entity_type_id.as_ref()
+    }
+
+    #[must_use]
+    pub const fn page_number(&self) -> Option<usize> {
+        self.page_number
+    }
+
+    #[must_use]
+    pub const fn items_per_page(&self) -> Option<usize> {
+        self.items_per_page
+    }
+
+    #[must_use]
+    pub fn multi_sort(&self) -> Option<&[Sort]> {
+        self.multi_sort.as_deref()
+    }
+
+    #[must_use]
+    pub const fn multi_filter(&self) -> Option<&MultiFilter> {
+        self.multi_filter.as_ref()
+    }
+
+    #[must_use]
+    pub const fn page_count(&self) -> Option<usize> {
+        self.page_count
+    }
+
+    #[must_use]
+    pub const fn total_count(&self) -> Option<usize> {
+        self.total_count
+    }
+}
+
+/// The data of the `aggregateEntities` and `aggregateEntityTypes` messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AggregateData {
+    operation: AggregationOperation,
+}
+
+impl AggregateData {
+    #[must_use]
+    pub const fn new(operation: AggregationOperation) -> Self {
+        Self { operation }
+    }
+
+    #[must_use]
+    pub const fn operation(&self) -> &AggregationOperation {
+        &self.operation
+    }
+}
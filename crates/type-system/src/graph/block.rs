@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{graph::LinkGroup, Entity};
+
+/// The data of the `blockGraph` message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct BlockGraph {
+    depth: usize,
+    linked_entities: Vec<Entity>,
+    link_groups: Vec<LinkGroup>,
+}
+
+impl BlockGraph {
+    #[must_use]
+    pub const fn new(
+        depth: usize,
+        linked_entities: Vec<Entity>,
+        link_groups: Vec<LinkGroup>,
+    ) -> Self {
+        Self {
+            depth,
+            linked_entities,
+            link_groups,
+        }
+    }
+
+    /// Returns the number of links followed from the block entity when resolving the graph.
+    #[must_use]
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the entities linked from the block entity, and from them up to the [`depth`].
+    ///
+    /// [`depth`]: Self::depth
+    #[must_use]
+    pub fn linked_entities(&self) -> &[Entity] {
+        &self.linked_entities
+    }
+
+    /// Returns the links from the block entity and from the [`linked_entities`].
+    ///
+    /// [`linked_entities`]: Self::linked_entities
+    #[must_use]
+    pub fn link_groups(&self) -> &[LinkGroup] {
+        &self.link_groups
+    }
+}
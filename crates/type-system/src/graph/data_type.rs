@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    graph::{schema::serde_schemas, AggregationOperation},
+    DataType,
+};
+
+/// The data of the response to the `aggregateDataTypes` message.
+///
+/// User-defined data types are not part of the type system, so data types can only be aggregated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AggregateDataTypesResponse {
+    operation: AggregationOperation,
+    #[serde(with = "serde_schemas")]
+    results: Vec<DataType>,
+}
+
+impl AggregateDataTypesResponse {
+    #[must_use]
+    pub const fn new(operation: AggregationOperation, results: Vec<DataType>) -> Self {
+        Self { operation, results }
+    }
+
+    /// Returns the operation which was applied, including any defaults set by the embedding
+    /// application.
+    #[must_use]
+    pub const fn operation(&self) -> &AggregationOperation {
+        &self.operation
+    }
+
+    #[must_use]
+    pub fn results(&self) -> &[DataType] {
+        &self.results
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    graph::AggregationOperation,
+    uri::{BaseUri, VersionedUri},
+    Entity, EntityId,
+};
+
+/// A link from an entity which is created together with the entity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CreateEntityLinkData {
+    destination_entity_id: EntityId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    index: Option<usize>,
+    path: String,
+}
+
+impl CreateEntityLinkData {
+    #[must_use]
+    pub const fn new(destination_entity_id: EntityId, path: String) -> Self {
+        Self {
+            destination_entity_id,
+            index: None,
+            path,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    #[must_use]
+    pub const fn destination_entity_id(&self) -> &EntityId {
+        &self.destination_entity_id
+    }
+
+    #[must_use]
+    pub const fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+/// The data of the `createEntity` message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CreateEntityData {
+    entity_type_id: VersionedUri,
+    properties: HashMap<BaseUri, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    links: Vec<CreateEntityLinkData>,
+}
+
+impl CreateEntityData {
+    #[must_use]
+    pub const fn new(
+        entity_type_id: VersionedUri,
+        properties: HashMap<BaseUri, serde_json::Value>,
+    ) -> Self {
+        Self {
+            entity_type_id,
+            properties,
+            links: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_links(mut self, links: Vec<CreateEntityLinkData>) -> Self {
+        self.links = links;
+        self
+    }
+
+    #[must_use]
+    pub const fn entity_type_id(&self) -> &VersionedUri {
+        &self.entity_type_id
+    }
+
+    #[must_use]
+    pub const fn properties(&self) -> &HashMap<BaseUri, serde_json::Value> {
+        &self.properties
+    }
+
+    /// Returns the links to create along with the entity.
+    #[must_use]
+    pub fn links(&self) -> &[CreateEntityLinkData] {
+        &self.links
+    }
+}
+
+/// The data of the `updateEntity` message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UpdateEntityData {
+    entity_id: EntityId,
+    properties: HashMap<BaseUri, serde_json::Value>,
+}
+
+impl UpdateEntityData {
+    #[must_use]
+    pub const fn new(entity_id: EntityId, properties: HashMap<BaseUri, serde_json::Value>) -> Self {
+        Self {
+            entity_id,
+            properties,
+        }
+    }
+
+    #[must_use]
+    pub const fn entity_id(&self) -> &EntityId {
+        &self.entity_id
+    }
+
+    /// Returns the properties to update, which are merged with the other properties of the
+    /// entity.
+    #[must_use]
+    pub const fn properties(&self) -> &HashMap<BaseUri, serde_json::Value> {
+        &self.properties
+    }
+}
+
+/// The data of the `getEntity` and `deleteEntity` messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityIdData {
+    entity_id: EntityId,
+}
+
+impl EntityIdData {
+    #[must_use]
+    pub const fn new(entity_id: EntityId) -> Self {
+        Self { entity_id }
+    }
+
+    #[must_use]
+    pub const fn entity_id(&self) -> &EntityId {
+        &self.entity_id
+    }
+}
+
+/// The data of the responses to the `createEntity`, `updateEntity`, and `getEntity` messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityResponse {
+    entity: Entity,
+}
+
+impl EntityResponse {
+    #[must_use]
+    pub const fn new(entity: Entity) -> Self {
+        Self { entity }
+    }
+
+    #[must_use]
+    pub const fn entity(&self) -> &Entity {
+        &self.entity
+    }
+
+    #[must_use]
+    pub fn into_entity(self) -> Entity {
+        self.entity
+    }
+}
+
+/// The data of the response to the `aggregateEntities` message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AggregateEntitiesResponse {
+    operation: AggregationOperation,
+    results: Vec<Entity>,
+}
+
+impl AggregateEntitiesResponse {
+    #[must_use]
+    pub const fn new(operation: AggregationOperation, results: Vec<Entity>) -> Self {
+        Self { operation, results }
+    }
+
+    /// Returns the operation which was applied, including any defaults set by the embedding
+    /// application.
+    #[must_use]
+    pub const fn operation(&self) -> &AggregationOperation {
+        &self.operation
+    }
+
+    #[must_use]
+    pub fn results(&self) -> &[Entity] {
+        &self.results
+    }
+}
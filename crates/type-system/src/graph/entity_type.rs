@@ -0,0 +1,243 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    graph::{schema::serde_schema, AggregationOperation},
+    uri::VersionedUri,
+    EntityType,
+};
+
+/// An entity type as sent by the graph service, which is its schema next to its id.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct EntityTypeRepr {
+    entity_type_id: VersionedUri,
+    #[serde(with = "serde_schema")]
+    schema: EntityType,
+}
+
+impl From<EntityType> for EntityTypeRepr {
+    fn from(schema: EntityType) -> Self {
+        Self {
+            entity_type_id: schema.id().clone(),
+            schema,
+        }
+    }
+}
+
+impl TryFrom<EntityTypeRepr> for EntityType {
+    type Error = String;
+
+    fn try_from(entity_type: EntityTypeRepr) -> Result<Self, Self::Error> {
+        if entity_type.schema.id() == &entity_type.entity_type_id {
+            Ok(entity_type.schema)
+        } else {
+            Err(format!(
+                "`entityTypeId` `{}` differs from the `$id` of the schema `{}`",
+                entity_type.entity_type_id,
+                entity_type.schema.id()
+            ))
+        }
+    }
+}
+
+/// De/serializes an [`EntityType`] as an [`EntityTypeRepr`].
+mod serde_entity_type {
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::EntityTypeRepr;
+    use crate::EntityType;
+
+    pub(super) fn serialize<S>(entity_type: &EntityType, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        EntityTypeRepr::from(entity_type.clone()).serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<EntityType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        EntityType::try_from(EntityTypeRepr::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// The same as [`serde_entity_type`] for a list of entity types.
+mod serde_entity_types {
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::EntityTypeRepr;
+    use crate::EntityType;
+
+    pub(super) fn serialize<S>(
+        entity_types: &[EntityType],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        entity_types
+            .iter()
+            .cloned()
+            .map(EntityTypeRepr::from)
+            .collect::<Vec<_>>()
+            .serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<Vec<EntityType>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<EntityTypeRepr>::deserialize(deserializer)?
+            .into_iter()
+            .map(|entity_type| EntityType::try_from(entity_type).map_err(de::Error::custom))
+            .collect()
+    }
+}
+
+/// The data of the `createEntityType` message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CreateEntityTypeData {
+    #[serde(with = "serde_schema")]
+    schema: EntityType,
+}
+
+impl CreateEntityTypeData {
+    #[must_use]
+    pub const fn new(schema: EntityType) -> Self {
+        Self { schema }
+    }
+
+    #[must_use]
+    pub const fn schema(&self) -> &EntityType {
+        &self.schema
+    }
+}
+
+/// The data of the `updateEntityType` message.
+///
+/// The graph service merges the `schema` with the existing schema of the entity type. As the
+/// `schema` is a complete [`EntityType`], it replaces the existing schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UpdateEntityTypeData {
+    entity_type_id: VersionedUri,
+    #[serde(with = "serde_schema")]
+    schema: EntityType,
+}
+
+impl UpdateEntityTypeData {
+    #[must_use]
+    pub const fn new(entity_type_id: VersionedUri, schema: EntityType) -> Self {
+        Self {
+            entity_type_id,
+            schema,
+        }
+    }
+
+    #[must_use]
+    pub const fn entity_type_id(&self) -> &VersionedUri {
+        &self.entity_type_id
+    }
+
+    #[must_use]
+    pub const fn schema(&self) -> &EntityType {
+        &self.schema
+    }
+}
+
+/// The data of the `getEntityType` and `deleteEntityType` messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityTypeIdData {
+    entity_type_id: VersionedUri,
+}
+
+impl EntityTypeIdData {
+    #[must_use]
+    pub const fn new(entity_type_id: VersionedUri) -> Self {
+        Self { entity_type_id }
+    }
+
+    #[must_use]
+    pub const fn entity_type_id(&self) -> &VersionedUri {
+        &self.entity_type_id
+    }
+}
+
+/// The data of the responses to the `createEntityType`, `updateEntityType`, and `getEntityType`
+/// messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityTypeResponse {
+    #[serde(with = "serde_entity_type")]
+    entity_type: EntityType,
+}
+
+impl EntityTypeResponse {
+    #[must_use]
+    pub const fn new(entity_type: EntityType) -> Self {
+        Self { entity_type }
+    }
+
+    #[must_use]
+    pub const fn entity_type(&self) -> &EntityType {
+        &self.entity_type
+    }
+
+    #[must_use]
+    pub fn into_entity_type(self) -> EntityType {
+        self.entity_type
+    }
+}
+
+/// The data of the response to the `aggregateEntityTypes` message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AggregateEntityTypesResponse {
+    operation: AggregationOperation,
+    #[serde(with = "serde_entity_types")]
+    results: Vec<EntityType>,
+}
+
+impl AggregateEntityTypesResponse {
+    #[must_use]
+    pub const fn new(operation: AggregationOperation, results: Vec<EntityType>) -> Self {
+        Self { operation, results }
+    }
+
+    /// Returns the operation which was applied, including any defaults set by the embedding
+    /// application.
+    #[must_use]
+    pub const fn operation(&self) -> &AggregationOperation {
+        &self.operation
+    }
+
+    #[must_use]
+    pub fn results(&self) -> &[EntityType] {
+        &self.results
+    }
+}
+
+/// The data of the `entityTypes` message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct EntityTypes(#[serde(with = "serde_entity_types")] Vec<EntityType>);
+
+impl EntityTypes {
+    #[must_use]
+    pub const fn new(entity_types: Vec<EntityType>) -> Self {
+        Self(entity_types)
+    }
+
+    #[must_use]
+    pub fn entity_types(&self) -> &[EntityType] {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn into_entity_types(self) -> Vec<EntityType> {
+        self.0
+    }
+}
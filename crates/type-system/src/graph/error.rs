@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::{
+    graph::{MessageName, MessageSource},
+    ValidationError,
+};
+
+/// A message which cannot be answered by an [`EmbedderGraphHandler`].
+///
+/// [`EmbedderGraphHandler`]: crate::graph::EmbedderGraphHandler
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum DispatchError {
+    #[error("the message is sent to the `{0}` service instead of the graph service")]
+    UnexpectedService(String),
+    #[error("the graph service does not define the message `{0}`")]
+    UnknownMessage(String),
+    #[error("`{}` is not a request sent by a block", .0.as_str())]
+    NotARequest(MessageName),
+    #[error("the message is sent by the embedding application instead of a block")]
+    UnexpectedSource(MessageSource),
+}
+
+/// The reason why the schema sent to update a type is rejected although it's a valid schema.
+///
+/// It's sent in the `extensions` of the [`InvalidInput`] error in the same shape as the errors of
+/// parsing the schema, e.g. a [`ParseEntityTypeError`].
+///
+/// [`InvalidInput`]: crate::graph::ErrorCode::InvalidInput
+/// [`ParseEntityTypeError`]: crate::ParseEntityTypeError
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum UpdateTypeError {
+    /// The schema is not a version of the type which is updated.
+    #[error("failed validation: `{0}`")]
+    ValidationError(ValidationError),
+}
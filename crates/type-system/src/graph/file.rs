@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::EntityId;
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FileMediaType {
+    Image,
+    Video,
+}
+
+/// The data of the `uploadFile` message.
+///
+/// The file is either uploaded directly, or taken from a URL.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UploadFileData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    file: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    media_type: FileMediaType,
+}
+
+impl UploadFileData {
+    /// Uploads the `file` directly.
+    ///
+    /// The file is a blob, which is opaque to the graph service and passed as it was received
+    /// from the block.
+    #[must_use]
+    pub const fn from_file(file: serde_json::Value, media_type: FileMediaType) -> Self {
+        Self {
+            file: Some(file),
+            url: None,
+            media_type,
+        }
+    }
+
+    /// Uploads the file served at `url`.
+    #[must_use]
+    pub const fn from_url(url: String, media_type: FileMediaType) -> Self {
+        Self {
+            file: None,
+            url: Some(url),
+            media_type,
+        }
+    }
+
+    #[must_use]
+    pub const fn file(&self) -> Option<&serde_json::Value> {
+        self.file.as_ref()
+    }
+
+    #[must_use]
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    #[must_use]
+    pub const fn media_type(&self) -> FileMediaType {
+        self.media_type
+    }
+}
+
+/// The data of the response to the `uploadFile` message.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UploadFileResponse {
+    entity_id: EntityId,
+    url: String,
+    media_type: FileMediaType,
+}
+
+impl UploadFileResponse {
+    #[must_use]
+    pub const fn new(entity_id: EntityId, url: String, media_type: FileMediaType) -> Self {
+        Self {
+            entity_id,
+            url,
+            media_type,
+        }
+    }
+
+    /// Returns the ID of the entity storing the metadata of the file.
+    #[must_use]
+    pub const fn entity_id(&self) -> &EntityId {
+        &self.entity_id
+    }
+
+    /// Returns the URL the file is served from.
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    #[must_use]
+    pub const fn media_type(&self) -> FileMediaType {
+        self.media_type
+    }
+}
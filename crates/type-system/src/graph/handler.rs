@@ -0,0 +1,893 @@
+use std::fmt;
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::{
+    graph::{
+        AggregateData, AggregateDataTypesResponse, AggregateEntitiesResponse,
+        AggregateEntityTypesResponse, AggregateLinkTypesResponse, AggregatePropertyTypesResponse,
+        AggregationIdData, CreateEntityData, CreateEntityTypeData, CreateLinkData,
+        CreateLinkTypeData, CreateLinkedAggregationData, CreatePropertyTypeData, DispatchError,
+        EntityIdData, EntityResponse, EntityTypeIdData, EntityTypeResponse, ErrorCode, Link,
+        LinkIdData, LinkResponse, LinkTypeIdData, LinkTypeResponse, LinkedAggregation,
+        LinkedAggregationDefinition, LinkedAggregationDefinitionResponse,
+        LinkedAggregationResponse, Message, MessageError, MessageName, MessageSource,
+        PropertyTypeIdData, PropertyTypeResponse, Response, UpdateEntityData, UpdateEntityTypeData,
+        UpdateLinkData, UpdateLinkTypeData, UpdateLinkedAggregationData, UpdatePropertyTypeData,
+        UpdateTypeError, UploadFileData, UploadFileResponse,
+    },
+    uri::VersionedUri,
+    Entity, EntityType, LinkType, PropertyType, ValidationError,
+};
+
+/// Answers the requests a block sends to the graph service of the embedding application.
+///
+/// Every request has a method, which receives the validated data of the request and returns the
+/// resource of the response. By default, a method responds with [`ErrorCode::NotImplemented`],
+/// so an embedding application only has to implement the requests it supports. Requests are
+/// routed to the methods by [`dispatch`].
+pub trait EmbedderGraphHandler {
+    /// Creates an entity.
+    ///
+    /// The `properties` of the `data` are not checked against the entity type, see [`dispatch`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn create_entity(&mut self, _data: CreateEntityData) -> Result<Entity, MessageError> {
+        Err(MessageError::not_implemented(MessageName::CreateEntity))
+    }
+
+    /// Replaces the properties of an entity.
+    ///
+    /// The `properties` of the `data` are not checked against the entity type, see [`dispatch`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn update_entity(&mut self, _data: UpdateEntityData) -> Result<Entity, MessageError> {
+        Err(MessageError::not_implemented(MessageName::UpdateEntity))
+    }
+
+    /// Deletes an entity.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn delete_entity(&mut self, _data: EntityIdData) -> Result<(), MessageError> {
+        Err(MessageError::not_implemented(MessageName::DeleteEntity))
+    }
+
+    /// Returns an entity.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn get_entity(&mut self, _data: EntityIdData) -> Result<Entity, MessageError> {
+        Err(MessageError::not_implemented(MessageName::GetEntity))
+    }
+
+    /// Returns the entities matching the `operation`, including any defaults applied to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn aggregate_entities(
+        &mut self,
+        _data: AggregateData,
+    ) -> Result<AggregateEntitiesResponse, MessageError> {
+        Err(MessageError::not_implemented(
+            MessageName::AggregateEntities,
+        ))
+    }
+
+    /// Creates an entity type.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn create_entity_type(
+        &mut self,
+        _data: CreateEntityTypeData,
+    ) -> Result<EntityType, MessageError> {
+        Err(MessageError::not_implemented(MessageName::CreateEntityType))
+    }
+
+    /// Replaces the schema of an entity type.
+    ///
+    /// The `schema` of the `data` is guaranteed to be a version of the `entityTypeId`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn update_entity_type(
+        &mut self,
+        _data: UpdateEntityTypeData,
+    ) -> Result<EntityType, MessageError> {
+        Err(MessageError::not_implemented(MessageName::UpdateEntityType))
+    }
+
+    /// Deletes an entity type.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn delete_entity_type(&mut self, _data: EntityTypeIdData) -> Result<(), MessageError> {
+        Err(MessageError::not_implemented(MessageName::DeleteEntityType))
+    }
+
+    /// Returns an entity type.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn get_entity_type(&mut self, _data: EntityTypeIdData) -> Result<EntityType, MessageError> {
+        Err(MessageError::not_implemented(MessageName::GetEntityType))
+    }
+
+    /// Returns the entity types matching the `operation`, including any defaults applied to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn aggregate_entity_types(
+        &mut self,
+        _data: AggregateData,
+    ) -> Result<AggregateEntityTypesResponse, MessageError> {
+        Err(MessageError::not_implemented(
+            MessageName::AggregateEntityTypes,
+        ))
+    }
+
+    /// Creates a link between two entities.
+    ///
+    /// Neither entity is looked up, see [`dispatch`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn create_link(&mut self, _data: CreateLinkData) -> Result<Link, MessageError> {
+        Err(MessageError::not_implemented(MessageName::CreateLink))
+    }
+
+    /// Moves a link to a new position in its list of links.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn update_link(&mut self, _data: UpdateLinkData) -> Result<Link, MessageError> {
+        Err(MessageError::not_implemented(MessageName::UpdateLink))
+    }
+
+    /// Deletes a link.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn delete_link(&mut self, _data: LinkIdData) -> Result<(), MessageError> {
+        Err(MessageError::not_implemented(MessageName::DeleteLink))
+    }
+
+    /// Returns a link.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn get_link(&mut self, _data: LinkIdData) -> Result<Link, MessageError> {
+        Err(MessageError::not_implemented(MessageName::GetLink))
+    }
+
+    /// Creates a linked aggregation.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn create_linked_aggregation(
+        &mut self,
+        _data: CreateLinkedAggregationData,
+    ) -> Result<LinkedAggregationDefinition, MessageError> {
+        Err(MessageError::not_implemented(
+            MessageName::CreateLinkedAggregation,
+        ))
+    }
+
+    /// Replaces the operation of a linked aggregation.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn update_linked_aggregation(
+        &mut self,
+        _data: UpdateLinkedAggregationData,
+    ) -> Result<LinkedAggregationDefinition, MessageError> {
+        Err(MessageError::not_implemented(
+            MessageName::UpdateLinkedAggregation,
+        ))
+    }
+
+    /// Deletes a linked aggregation.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn delete_linked_aggregation(&mut self, _data: AggregationIdData) -> Result<(), MessageError> {
+        Err(MessageError::not_implemented(
+            MessageName::DeleteLinkedAggregation,
+        ))
+    }
+
+    /// Returns a linked aggregation together with its results.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn get_linked_aggregation(
+        &mut self,
+        _data: AggregationIdData,
+    ) -> Result<LinkedAggregation, MessageError> {
+        Err(MessageError::not_implemented(
+            MessageName::GetLinkedAggregation,
+        ))
+    }
+
+    /// Uploads a file and creates an entity for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn upload_file(&mut self, _data: UploadFileData) -> Result<UploadFileResponse, MessageError> {
+        Err(MessageError::not_implemented(MessageName::UploadFile))
+    }
+
+    /// Creates a property type.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn create_property_type(
+        &mut self,
+        _data: CreatePropertyTypeData,
+    ) -> Result<PropertyType, MessageError> {
+        Err(MessageError::not_implemented(
+            MessageName::CreatePropertyType,
+        ))
+    }
+
+    /// Replaces the schema of a property type.
+    ///
+    /// The `schema` of the `data` is guaranteed to be a version of the `propertyTypeId`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn update_property_type(
+        &mut self,
+        _data: UpdatePropertyTypeData,
+    ) -> Result<PropertyType, MessageError> {
+        Err(MessageError::not_implemented(
+            MessageName::UpdatePropertyType,
+        ))
+    }
+
+    /// Deletes a property type.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn delete_property_type(&mut self, _data: PropertyTypeIdData) -> Result<(), MessageError> {
+        Err(MessageError::not_implemented(
+            MessageName::DeletePropertyType,
+        ))
+    }
+
+    /// Returns a property type.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn get_property_type(
+        &mut self,
+        _data: PropertyTypeIdData,
+    ) -> Result<PropertyType, MessageError> {
+        Err(MessageError::not_implemented(MessageName::GetPropertyType))
+    }
+
+    /// Returns the property types matching the `operation`, including any defaults applied to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn aggregate_property_types(
+        &mut self,
+        _data: AggregateData,
+    ) -> Result<AggregatePropertyTypesResponse, MessageError> {
+        Err(MessageError::not_implemented(
+            MessageName::AggregatePropertyTypes,
+        ))
+    }
+
+    /// Creates a link type.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn create_link_type(&mut self, _data: CreateLinkTypeData) -> Result<LinkType, MessageError> {
+        Err(MessageError::not_implemented(MessageName::CreateLinkType))
+    }
+
+    /// Replaces the schema of a link type.
+    ///
+    /// The `schema` of the `data` is guaranteed to be a version of the `linkTypeId`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn update_link_type(&mut self, _data: UpdateLinkTypeData) -> Result<LinkType, MessageError> {
+        Err(MessageError::not_implemented(MessageName::UpdateLinkType))
+    }
+
+    /// Deletes a link type.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn delete_link_type(&mut self, _data: LinkTypeIdData) -> Result<(), MessageError> {
+        Err(MessageError::not_implemented(MessageName::DeleteLinkType))
+    }
+
+    /// Returns a link type.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn get_link_type(&mut self, _data: LinkTypeIdData) -> Result<LinkType, MessageError> {
+        Err(MessageError::not_implemented(MessageName::GetLinkType))
+    }
+
+    /// Returns the link types matching the `operation`, including any defaults applied to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn aggregate_link_types(
+        &mut self,
+        _data: AggregateData,
+    ) -> Result<AggregateLinkTypesResponse, MessageError> {
+        Err(MessageError::not_implemented(
+            MessageName::AggregateLinkTypes,
+        ))
+    }
+
+    /// Returns the data types matching the `operation`, including any defaults applied to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to send in the response, by default [`ErrorCode::NotImplemented`].
+    fn aggregate_data_types(
+        &mut self,
+        _data: AggregateData,
+    ) -> Result<AggregateDataTypesResponse, MessageError> {
+        Err(MessageError::not_implemented(
+            MessageName::AggregateDataTypes,
+        ))
+    }
+}
+
+/// Decodes the `data` of a request, passes it to the `handler` method, and encodes the result as
+/// the data of the response.
+///
+/// Data which cannot be decoded, e.g. because it contains an invalid [`EntityType`], is answered
+/// with [`ErrorCode::InvalidInput`].
+fn call<D, R>(
+    data: Value,
+    handler: impl FnOnce(D) -> Result<R, MessageError>,
+) -> Result<Value, MessageError>
+where
+    D: DeserializeOwned,
+    R: Serialize,
+{
+    let data = serde_json::from_value(data)
+        .map_err(|error| MessageError::new(ErrorCode::InvalidInput, error.to_string()))?;
+    Ok(serde_json::to_value(handler(data)?).expect("responses are always valid JSON"))
+}
+
+/// Creates an [`ErrorCode::InvalidInput`] error, which contains the `error` in its extensions.
+fn invalid_input<E>(error: &E) -> MessageError
+where
+    E: Serialize + fmt::Display,
+{
+    MessageError::new(ErrorCode::InvalidInput, error.to_string())
+        .with_extensions(serde_json::to_value(error).expect("errors are always valid JSON"))
+}
+
+/// Parses the `schema` of the `data` of a request, so the reason why it's invalid, e.g. a
+/// [`ParsePropertyTypeError`], is sent in the response.
+///
+/// [`ParsePropertyTypeError`]: crate::ParsePropertyTypeError
+fn check_schema<T>(data: &Value) -> Result<(), MessageError>
+where
+    T: TryFrom<Value>,
+    T::Error: Serialize + fmt::Display,
+{
+    data.get("schema").map_or(Ok(()), |schema| {
+        T::try_from(schema.clone())
+            .map(drop)
+            .map_err(|error| invalid_input(&error))
+    })
+}
+
+/// Checks that the schema sent to update the type identified by `type_id` is a version of the
+/// same type.
+fn check_version(type_id: &VersionedUri, schema_id: &VersionedUri) -> Result<(), MessageError> {
+    if type_id.base_uri() == schema_id.base_uri() {
+        Ok(())
+    } else {
+        Err(invalid_input(&UpdateTypeError::ValidationError(
+            ValidationError::BaseUriMismatch {
+                base_uri: type_id.base_uri().clone(),
+                versioned_uri: schema_id.clone(),
+            },
+        )))
+    }
+}
+
+/// Routes a `message` sent by a block to the corresponding method of the `handler`.
+///
+/// The data of the message is validated before passing it to the `handler`, invalid data is
+/// answered with [`ErrorCode::InvalidInput`] without calling the `handler`. If a schema is
+/// invalid, the error of parsing it is sent in the `extensions` of the error, or an
+/// [`UpdateTypeError`] if the schema sent to update a type is not a version of that type. Errors
+/// returned by the `handler` are sent in the response.
+///
+/// Validating the data does not require access to the graph, so it only covers the data of the
+/// message itself: the fields of the message, the schemas of types, that an updated type is a
+/// version of the same type, and that the properties of an entity are keyed by [`BaseUri`]s.
+/// Checking the `properties` of an entity against its entity type, e.g. that required properties
+/// are present or that values match their data types, and checking that the entities of a link
+/// exist is left to the `handler`, which should answer invalid requests with
+/// [`ErrorCode::InvalidInput`] as well.
+///
+/// [`BaseUri`]: crate::uri::BaseUri
+///
+/// # Errors
+///
+/// - [`UnexpectedService`] if the message is not sent to the graph service
+/// - [`UnknownMessage`] if the graph service does not define the message
+/// - [`NotARequest`] if the message is not a request, e.g. a response or a message sent on
+///   initialization
+/// - [`UnexpectedSource`] if the message is not sent by a block
+///
+/// [`UnexpectedService`]: DispatchError::UnexpectedService
+/// [`UnknownMessage`]: DispatchError::UnknownMessage
+/// [`NotARequest`]: DispatchError::NotARequest
+/// [`UnexpectedSource`]: DispatchError::UnexpectedSource
+#[expect(
+    clippy::too_many_lines,
+    reason = "every request of the graph service has its own match arm"
+)]
+pub fn dispatch<H>(handler: &mut H, message: &Message) -> Result<Response, DispatchError>
+where
+    H: EmbedderGraphHandler + ?Sized,
+{
+    if message.service() != "graph" {
+        return Err(DispatchError::UnexpectedService(
+            message.service().to_owned(),
+        ));
+    }
+    let name = MessageName::ALL
+        .into_iter()
+        .find(|name| name.as_str() == message.message_name())
+        .ok_or_else(|| DispatchError::UnknownMessage(message.message_name().to_owned()))?;
+    let response = name
+        .responded_to_by()
+        .ok_or(DispatchError::NotARequest(name))?;
+    if message.source() != MessageSource::Block {
+        return Err(DispatchError::UnexpectedSource(message.source()));
+    }
+
+    let data = message.data().cloned().unwrap_or(Value::Null);
+    let result = match name {
+        MessageName::CreateEntity => call(data, |data| {
+            handler.create_entity(data).map(EntityResponse::new)
+        }),
+        MessageName::UpdateEntity => call(data, |data| {
+            handler.update_entity(data).map(EntityResponse::new)
+        }),
+        MessageName::DeleteEntity => call(data, |data| handler.delete_entity(data).map(|()| true)),
+        MessageName::GetEntity => call(data, |data| {
+            handler.get_entity(data).map(EntityResponse::new)
+        }),
+        MessageName::AggregateEntities => call(data, |data| handler.aggregate_entities(data)),
+        MessageName::CreateEntityType => check_schema::<EntityType>(&data).and_then(|()| {
+            call(data, |data| {
+                handler
+                    .create_entity_type(data)
+                    .map(EntityTypeResponse::new)
+            })
+        }),
+        MessageName::UpdateEntityType => check_schema::<EntityType>(&data).and_then(|()| {
+            call(data, |data: UpdateEntityTypeData| {
+                check_version(data.entity_type_id(), data.schema().id())?;
+                handler
+                    .update_entity_type(data)
+                    .map(EntityTypeResponse::new)
+            })
+        }),
+        MessageName::DeleteEntityType => {
+            call(data, |data| handler.delete_entity_type(data).map(|()| true))
+        }
+        MessageName::GetEntityType => call(data, |data| {
+            handler.get_entity_type(data).map(EntityTypeResponse::new)
+        }),
+        MessageName::AggregateEntityTypes => {
+            call(data, |data| handler.aggregate_entity_types(data))
+        }
+        MessageName::CreateLink => call(data, |data| {
+            handler.create_link(data).map(LinkResponse::new)
+        }),
+        MessageName::UpdateLink => call(data, |data| {
+            handler.update_link(data).map(LinkResponse::new)
+        }),
+        MessageName::DeleteLink => call(data, |data| handler.delete_link(data).map(|()| true)),
+        MessageName::GetLink => call(data, |data| handler.get_link(data).map(LinkResponse::new)),
+        MessageName::CreateLinkedAggregation => call(data, |data| {
+            handler
+                .create_linked_aggregation(data)
+                .map(LinkedAggregationDefinitionResponse::new)
+        }),
+        MessageName::UpdateLinkedAggregation => call(data, |data| {
+            handler
+                .update_linked_aggregation(data)
+                .map(LinkedAggregationDefinitionResponse::new)
+        }),
+        MessageName::DeleteLinkedAggregation => call(data, |data| {
+            handler.delete_linked_aggregation(data).map(|()| true)
+        }),
+        MessageName::GetLinkedAggregation => call(data, |data| {
+            handler
+                .get_linked_aggregation(data)
+                .map(LinkedAggregationResponse::new)
+        }),
+        MessageName::UploadFile => call(data, |data| handler.upload_file(data)),
+        MessageName::CreatePropertyType => check_schema::<PropertyType>(&data).and_then(|()| {
+            call(data, |data| {
+                handler
+                    .create_property_type(data)
+                    .map(PropertyTypeResponse::new)
+            })
+        }),
+        MessageName::UpdatePropertyType => check_schema::<PropertyType>(&data).and_then(|()| {
+            call(data, |data: UpdatePropertyTypeData| {
+                check_version(data.property_type_id(), data.schema().id())?;
+                handler
+                    .update_property_type(data)
+                    .map(PropertyTypeResponse::new)
+            })
+        }),
+        MessageName::DeletePropertyType => call(data, |data| {
+            handler.delete_property_type(data).map(|()| true)
+        }),
+        MessageName::GetPropertyType => call(data, |data| {
+            handler
+                .get_property_type(data)
+                .map(PropertyTypeResponse::new)
+        }),
+        MessageName::AggregatePropertyTypes => {
+            call(data, |data| handler.aggregate_property_types(data))
+        }
+        MessageName::CreateLinkType => check_schema::<LinkType>(&data).and_then(|()| {
+            call(data, |data| {
+                handler.create_link_type(data).map(LinkTypeResponse::new)
+            })
+        }),
+        MessageName::UpdateLinkType => check_schema::<LinkType>(&data).and_then(|()| {
+            call(data, |data: UpdateLinkTypeData| {
+                check_version(data.link_type_id(), data.schema().id())?;
+                handler.update_link_type(data).map(LinkTypeResponse::new)
+            })
+        }),
+        MessageName::DeleteLinkType => {
+            call(data, |data| handler.delete_link_type(data).map(|()| true))
+        }
+        MessageName::GetLinkType => call(data, |data| {
+            handler.get_link_type(data).map(LinkTypeResponse::new)
+        }),
+        MessageName::AggregateLinkTypes => call(data, |data| handler.aggregate_link_types(data)),
+        MessageName::AggregateDataTypes => call(data, |data| handler.aggregate_data_types(data)),
+        _ => return Err(DispatchError::NotARequest(name)),
+    };
+
+    Ok(match result {
+        Ok(data) => Response::from_data(response, data),
+        Err(error) => Response::from_error(response, error),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::{test_data, EntityId, EntityTypeReference};
+
+    #[derive(Default)]
+    struct Handler {
+        entities: Vec<Entity>,
+    }
+
+    impl EmbedderGraphHandler for Handler {
+        fn create_entity(&mut self, data: CreateEntityData) -> Result<Entity, MessageError> {
+            let entity = Entity::new(
+                EntityId::new(format!("entity-{}", self.entities.len())),
+                EntityTypeReference::new(data.entity_type_id().clone()),
+                data.properties().clone(),
+            );
+            self.entities.push(entity.clone());
+            Ok(entity)
+        }
+
+        fn get_entity(&mut self, data: EntityIdData) -> Result<Entity, MessageError> {
+            self.entities
+                .iter()
+                .find(|entity| entity.entity_id() == data.entity_id())
+                .cloned()
+                .ok_or_else(|| {
+                    MessageError::new(ErrorCode::NotFound, "entity not found".to_owned())
+                })
+        }
+
+        fn create_property_type(
+            &mut self,
+            data: CreatePropertyTypeData,
+        ) -> Result<PropertyType, MessageError> {
+            Ok(data.schema().clone())
+        }
+    }
+
+    fn block_request(name: &str, data: Value) -> Message {
+        Message::new(
+            "request".to_owned(),
+            name.to_owned(),
+            "graph".to_owned(),
+            MessageSource::Block,
+            "2022-10-01T00:00:00.000Z".to_owned(),
+        )
+        .with_responded_to_by(format!("{name}Response"))
+        .with_data(data)
+    }
+
+    fn entity_type() -> Value {
+        Value::from(
+            EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid entity type"),
+        )
+    }
+
+    #[test]
+    fn dispatch_request() {
+        let mut handler = Handler::default();
+        let request = block_request(
+            "createEntity",
+            json!({
+                "entityTypeId": "https://blockprotocol.org/@alice/types/entity-type/book/v/1",
+                "properties": {
+                    "https://blockprotocol.org/@alice/types/property-type/name/": "The Hobbit"
+                }
+            }),
+        );
+        let response = dispatch(&mut handler, &request).expect("could not dispatch message");
+        assert_eq!(response.message_name(), MessageName::CreateEntityResponse);
+        assert!(response.errors().is_empty());
+        assert_eq!(
+            response.data().expect("missing data")["entity"]["entityId"],
+            json!("entity-0")
+        );
+
+        let message = response.into_message(&request, "2022-10-01T00:00:01.000Z".to_owned());
+        assert_eq!(message.request_id(), "request");
+        assert_eq!(message.message_name(), "createEntityResponse");
+        assert_eq!(message.service(), "graph");
+        assert_eq!(message.source(), MessageSource::Embedder);
+        assert_eq!(message.errors(), None);
+
+        let response = dispatch(
+            &mut handler,
+            &block_request("getEntity", json!({ "entityId": "entity-1" })),
+        )
+        .expect("could not dispatch message");
+        assert_eq!(response.data(), None);
+        assert_eq!(response.errors()[0].code(), ErrorCode::NotFound);
+    }
+
+    #[test]
+    fn not_implemented() {
+        let request = block_request("deleteEntity", json!({ "entityId": "entity-0" }));
+        let response =
+            dispatch(&mut Handler::default(), &request).expect("could not dispatch message");
+        assert_eq!(response.message_name(), MessageName::DeleteEntityResponse);
+        assert_eq!(response.errors()[0].code(), ErrorCode::NotImplemented);
+
+        let message = response.into_message(&request, "2022-10-01T00:00:01.000Z".to_owned());
+        assert_eq!(
+            serde_json::to_value(&message).expect("could not serialize message")["errors"][0]
+                ["code"],
+            json!("NOT_IMPLEMENTED")
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        let mut handler = Handler::default();
+        let response = dispatch(&mut handler, &block_request("createEntity", json!({})))
+            .expect("could not dispatch message");
+        assert_eq!(response.errors()[0].code(), ErrorCode::InvalidInput);
+
+        let mut schema = entity_type();
+        schema["$id"] = json!("https://blockprotocol.org/@alice/types/entity-type/book/");
+        let response = dispatch(
+            &mut handler,
+            &block_request("createEntityType", json!({ "schema": schema })),
+        )
+        .expect("could not dispatch message");
+        assert_eq!(response.errors()[0].code(), ErrorCode::InvalidInput);
+
+        let response = dispatch(
+            &mut handler,
+            &block_request(
+                "updateEntityType",
+                json!({
+                    "entityTypeId": "https://blockprotocol.org/@alice/types/entity-type/page/v/1",
+                    "schema": entity_type()
+                }),
+            ),
+        )
+        .expect("could not dispatch message");
+        assert_eq!(response.errors()[0].code(), ErrorCode::InvalidInput);
+
+        let response = dispatch(
+            &mut handler,
+            &block_request(
+                "updateEntityType",
+                json!({
+                    "entityTypeId": "https://blockprotocol.org/@alice/types/entity-type/book/v/1",
+                    "schema": entity_type()
+                }),
+            ),
+        )
+        .expect("could not dispatch message");
+        assert_eq!(response.errors()[0].code(), ErrorCode::NotImplemented);
+    }
+
+    #[test]
+    fn invalid_message() {
+        let mut handler = Handler::default();
+
+        let message = Message::new(
+            "request".to_owned(),
+            "getEntity".to_owned(),
+            "hook".to_owned(),
+            MessageSource::Block,
+            "2022-10-01T00:00:00.000Z".to_owned(),
+        );
+        assert_eq!(
+            dispatch(&mut handler, &message),
+            Err(DispatchError::UnexpectedService("hook".to_owned()))
+        );
+
+        assert_eq!(
+            dispatch(&mut handler, &block_request("getEntities", json!({}))),
+            Err(DispatchError::UnknownMessage("getEntities".to_owned()))
+        );
+        assert_eq!(
+            dispatch(&mut handler, &block_request("getEntityResponse", json!({}))),
+            Err(DispatchError::NotARequest(MessageName::GetEntityResponse))
+        );
+        assert_eq!(
+            dispatch(&mut handler, &block_request("blockEntity", json!({}))),
+            Err(DispatchError::NotARequest(MessageName::BlockEntity))
+        );
+
+        let message = Message::new(
+            "request".to_owned(),
+            "getEntity".to_owned(),
+            "graph".to_owned(),
+            MessageSource::Embedder,
+            "2022-10-01T00:00:00.000Z".to_owned(),
+        );
+        assert_eq!(
+            dispatch(&mut handler, &message),
+            Err(DispatchError::UnexpectedSource(MessageSource::Embedder))
+        );
+    }
+
+    #[test]
+    fn property_type() {
+        let mut handler = Handler::default();
+        let schema = Value::from(
+            PropertyType::from_str(test_data::property_type::USER_ID_V2)
+                .expect("invalid property type"),
+        );
+
+        let response = dispatch(
+            &mut handler,
+            &block_request("createPropertyType", json!({ "schema": schema })),
+        )
+        .expect("could not dispatch message");
+        assert_eq!(
+            response.message_name(),
+            MessageName::CreatePropertyTypeResponse
+        );
+        assert_eq!(
+            response.data().expect("missing data")["propertyType"],
+            schema
+        );
+
+        let response = dispatch(
+            &mut handler,
+            &block_request(
+                "updatePropertyType",
+                json!({
+                    "propertyTypeId": "https://blockprotocol.org/@alice/types/property-type/user-id/v/1",
+                    "schema": schema
+                }),
+            ),
+        )
+        .expect("could not dispatch message");
+        assert_eq!(response.errors()[0].code(), ErrorCode::NotImplemented);
+
+        let response = dispatch(
+            &mut handler,
+            &block_request("aggregateDataTypes", json!({ "operation": {} })),
+        )
+        .expect("could not dispatch message");
+        assert_eq!(
+            response.message_name(),
+            MessageName::AggregateDataTypesResponse
+        );
+        assert_eq!(response.errors()[0].code(), ErrorCode::NotImplemented);
+    }
+
+    #[test]
+    fn invalid_schema() {
+        let mut handler = Handler::default();
+
+        let mut schema = Value::from(
+            PropertyType::from_str(test_data::property_type::USER_ID_V2)
+                .expect("invalid property type"),
+        );
+        schema["$id"] = json!("https://blockprotocol.org/@alice/types/property-type/user-id/");
+        let response = dispatch(
+            &mut handler,
+            &block_request("createPropertyType", json!({ "schema": schema })),
+        )
+        .expect("could not dispatch message");
+        let error = &response.errors()[0];
+        assert_eq!(error.code(), ErrorCode::InvalidInput);
+        assert_eq!(
+            error.extensions().expect("missing extensions")["reason"],
+            json!("InvalidVersionedUri")
+        );
+
+        let schema = Value::from(
+            LinkType::from_str(test_data::link_type::OWNS_V2).expect("invalid link type"),
+        );
+        let response = dispatch(
+            &mut handler,
+            &block_request(
+                "updateLinkType",
+                json!({
+                    "linkTypeId": "https://blockprotocol.org/@alice/types/link-type/submitted-by/v/1",
+                    "schema": schema
+                }),
+            ),
+        )
+        .expect("could not dispatch message");
+        let error = &response.errors()[0];
+        assert_eq!(error.code(), ErrorCode::InvalidInput);
+        let extensions = error.extensions().expect("missing extensions");
+        assert_eq!(extensions["reason"], json!("ValidationError"));
+        assert_eq!(extensions["inner"]["type"], json!("BaseUriMismatch"));
+    }
+}
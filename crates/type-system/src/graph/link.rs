@@ -0,0 +1,225 @@
+use serde::{Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::EntityId;
+
+/// A link stored by the graph service.
+///
+/// Unlike a [`crate::Link`], which is an instance of a [`LinkType`], the graph service identifies
+/// links by a `linkId` and the kind of a link by the `path` of the field on the source entity,
+/// e.g. `friend`.
+///
+/// [`LinkType`]: crate::LinkType
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Link {
+    link_id: String,
+    source_entity_id: EntityId,
+    destination_entity_id: EntityId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    index: Option<usize>,
+    path: String,
+}
+
+impl Link {
+    #[must_use]
+    pub fn new(link_id: String, data: CreateLinkData) -> Self {
+        Self {
+            link_id,
+            source_entity_id: data.source_entity_id,
+            destination_entity_id: data.destination_entity_id,
+            index: data.index,
+            path: data.path,
+        }
+    }
+
+    #[must_use]
+    pub fn link_id(&self) -> &str {
+        &self.link_id
+    }
+
+    #[must_use]
+    pub const fn source_entity_id(&self) -> &EntityId {
+        &self.source_entity_id
+    }
+
+    #[must_use]
+    pub const fn destination_entity_id(&self) -> &EntityId {
+        &self.destination_entity_id
+    }
+
+    /// Returns the position of the link in an ordered list of links, starting at `0`.
+    #[must_use]
+    pub const fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+/// The links from an entity at a `path`.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LinkGroup {
+    source_entity_id: EntityId,
+    path: String,
+    links: Vec<Link>,
+}
+
+impl LinkGroup {
+    #[must_use]
+    pub const fn new(source_entity_id: EntityId, path: String, links: Vec<Link>) -> Self {
+        Self {
+            source_entity_id,
+            path,
+            links,
+        }
+    }
+
+    #[must_use]
+    pub const fn source_entity_id(&self) -> &EntityId {
+        &self.source_entity_id
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    #[must_use]
+    pub fn links(&self) -> &[Link] {
+        &self.links
+    }
+}
+
+/// The data of the `createLink` message.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CreateLinkData {
+    source_entity_id: EntityId,
+    destination_entity_id: EntityId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    index: Option<usize>,
+    path: String,
+}
+
+impl CreateLinkData {
+    #[must_use]
+    pub const fn new(
+        source_entity_id: EntityId,
+        destination_entity_id: EntityId,
+        path: String,
+    ) -> Self {
+        Self {
+            source_entity_id,
+            destination_entity_id,
+            index: None,
+            path,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    #[must_use]
+    pub const fn source_entity_id(&self) -> &EntityId {
+        &self.source_entity_id
+    }
+
+    #[must_use]
+    pub const fn destination_entity_id(&self) -> &EntityId {
+        &self.destination_entity_id
+    }
+
+    #[must_use]
+    pub const fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+/// The data of the `updateLink` message.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UpdateLinkData {
+    link_id: String,
+    index: usize,
+}
+
+impl UpdateLinkData {
+    #[must_use]
+    pub const fn new(link_id: String, index: usize) -> Self {
+        Self { link_id, index }
+    }
+
+    #[must_use]
+    pub fn link_id(&self) -> &str {
+        &self.link_id
+    }
+
+    /// Returns the new position of the link in an ordered list of links.
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// The data of the `getLink` and `deleteLink` messages.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LinkIdData {
+    link_id: String,
+}
+
+impl LinkIdData {
+    #[must_use]
+    pub const fn new(link_id: String) -> Self {
+        Self { link_id }
+    }
+
+    #[must_use]
+    pub fn link_id(&self) -> &str {
+        &self.link_id
+    }
+}
+
+/// The data of the responses to the `createLink`, `updateLink`, and `getLink` messages.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LinkResponse {
+    link: Link,
+}
+
+impl LinkResponse {
+    #[must_use]
+    pub const fn new(link: Link) -> Self {
+        Self { link }
+    }
+
+    #[must_use]
+    pub const fn link(&self) -> &Link {
+        &self.link
+    }
+
+    #[must_use]
+    pub fn into_link(self) -> Link {
+        self.link
+    }
+}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    graph::{
+        schema::{serde_schema, serde_schemas},
+        AggregationOperation,
+    },
+    uri::VersionedUri,
+    LinkType,
+};
+
+/// The data of the `createLinkType` message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CreateLinkTypeData {
+    #[serde(with = "serde_schema")]
+    schema: LinkType,
+}
+
+impl CreateLinkTypeData {
+    #[must_use]
+    pub const fn new(schema: LinkType) -> Self {
+        Self { schema }
+    }
+
+    #[must_use]
+    pub const fn schema(&self) -> &LinkType {
+        &self.schema
+    }
+}
+
+/// The data of the `updateLinkType` message.
+///
+/// The `schema` replaces the existing schema of the link type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UpdateLinkTypeData {
+    link_type_id: VersionedUri,
+    #[serde(with = "serde_schema")]
+    schema: LinkType,
+}
+
+impl UpdateLinkTypeData {
+    #[must_use]
+    pub const fn new(link_type_id: VersionedUri, schema: LinkType) -> Self {
+        Self {
+            link_type_id,
+            schema,
+        }
+    }
+
+    #[must_use]
+    pub const fn link_type_id(&self) -> &VersionedUri {
+        &self.link_type_id
+    }
+
+    #[must_use]
+    pub const fn schema(&self) -> &LinkType {
+        &self.schema
+    }
+}
+
+/// The data of the `getLinkType` and `deleteLinkType` messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LinkTypeIdData {
+    link_type_id: VersionedUri,
+}
+
+impl LinkTypeIdData {
+    #[must_use]
+    pub const fn new(link_type_id: VersionedUri) -> Self {
+        Self { link_type_id }
+    }
+
+    #[must_use]
+    pub const fn link_type_id(&self) -> &VersionedUri {
+        &self.link_type_id
+    }
+}
+
+/// The data of the responses to the `createLinkType`, `updateLinkType`, and `getLinkType`
+/// messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LinkTypeResponse {
+    #[serde(with = "serde_schema")]
+    link_type: LinkType,
+}
+
+impl LinkTypeResponse {
+    #[must_use]
+    pub const fn new(link_type: LinkType) -> Self {
+        Self { link_type }
+    }
+
+    #[must_use]
+    pub const fn link_type(&self) -> &LinkType {
+        &self.link_type
+    }
+
+    #[must_use]
+    pub fn into_link_type(self) -> LinkType {
+        self.link_type
+    }
+}
+
+/// The data of the response to the `aggregateLinkTypes` message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AggregateLinkTypesResponse {
+    operation: AggregationOperation,
+    #[serde(with = "serde_schemas")]
+    results: Vec<LinkType>,
+}
+
+impl AggregateLinkTypesResponse {
+    #[must_use]
+    pub const fn new(operation: AggregationOperation, results: Vec<LinkType>) -> Self {
+        Self { operation, results }
+    }
+
+    /// Returns the operation which was applied, including any defaults set by the embedding
+    /// application.
+    #[must_use]
+    pub const fn operation(&self) -> &AggregationOperation {
+        &self.operation
+    }
+
+    #[must_use]
+    pub fn results(&self) -> &[LinkType] {
+        &self.results
+    }
+}
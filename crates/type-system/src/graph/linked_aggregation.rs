@@ -0,0 +1,222 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{graph::AggregationOperation, Entity, EntityId};
+
+/// An aggregation of entities linked from an entity at a `path`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LinkedAggregationDefinition {
+    aggregation_id: String,
+    source_entity_id: EntityId,
+    path: String,
+    operation: AggregationOperation,
+}
+
+impl LinkedAggregationDefinition {
+    #[must_use]
+    pub fn new(aggregation_id: String, data: CreateLinkedAggregationData) -> Self {
+        Self {
+            aggregation_id,
+            source_entity_id: data.source_entity_id,
+            path: data.path,
+            operation: data.operation,
+        }
+    }
+
+    #[must_use]
+    pub fn aggregation_id(&self) -> &str {
+        &self.aggregation_id
+    }
+
+    #[must_use]
+    pub const fn source_entity_id(&self) -> &EntityId {
+        &self.source_entity_id
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    #[must_use]
+    pub const fn operation(&self) -> &AggregationOperation {
+        &self.operation
+    }
+}
+
+/// A [`LinkedAggregationDefinition`] together with the results of its operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LinkedAggregation {
+    aggregation_id: String,
+    source_entity_id: EntityId,
+    path: String,
+    operation: AggregationOperation,
+    results: Vec<Entity>,
+}
+
+impl LinkedAggregation {
+    /// Creates the linked aggregation from its `definition`.
+    ///
+    /// The operation of the definition is expected to include any defaults set when applying it.
+    #[must_use]
+    pub fn new(definition: LinkedAggregationDefinition, results: Vec<Entity>) -> Self {
+        Self {
+            aggregation_id: definition.aggregation_id,
+            source_entity_id: definition.source_entity_id,
+            path: definition.path,
+            operation: definition.operation,
+            results,
+        }
+    }
+
+    #[must_use]
+    pub fn aggregation_id(&self) -> &str {
+        &self.aggregation_id
+    }
+
+    #[must_use]
+    pub const fn source_entity_id(&self) -> &EntityId {
+        &self.source_entity_id
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    #[must_use]
+    pub const fn operation(&self) -> &AggregationOperation {
+        &self.operation
+    }
+
+    #[must_use]
+    pub fn results(&self) -> &[Entity] {
+        &self.results
+    }
+}
+
+/// The data of the `createLinkedAggregation` message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CreateLinkedAggregationData {
+    source_entity_id: EntityId,
+    operation: AggregationOperation,
+    path: String,
+}
+
+impl CreateLinkedAggregationData {
+    #[must_use]
+    pub const fn new(
+        source_entity_id: EntityId,
+        operation: AggregationOperation,
+        path: String,
+    ) -> Self {
+        Self {
+            source_entity_id,
+            operation,
+            path,
+        }
+    }
+
+    #[must_use]
+    pub const fn source_entity_id(&self) -> &EntityId {
+        &self.source_entity_id
+    }
+
+    #[must_use]
+    pub const fn operation(&self) -> &AggregationOperation {
+        &self.operation
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+/// The data of the `updateLinkedAggregation` message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UpdateLinkedAggregationData {
+    aggregation_id: String,
+    operation: AggregationOperation,
+}
+
+impl UpdateLinkedAggregationData {
+    #[must_use]
+    pub const fn new(aggregation_id: String, operation: AggregationOperation) -> Self {
+        Self {
+            aggregation_id,
+            operation,
+        }
+    }
+
+    #[must_use]
+    pub fn aggregation_id(&self) -> &str {
+        &self.aggregation_id
+    }
+
+    #[must_use]
+    pub const fn operation(&self) -> &AggregationOperation {
+        &self.operation
+    }
+}
+
+/// The data of the `getLinkedAggregation` and `deleteLinkedAggregation` messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AggregationIdData {
+    aggregation_id: String,
+}
+
+impl AggregationIdData {
+    #[must_use]
+    pub const fn new(aggregation_id: String) -> Self {
+        Self { aggregation_id }
+    }
+
+    #[must_use]
+    pub fn aggregation_id(&self) -> &str {
+        &self.aggregation_id
+    }
+}
+
+/// The data of the responses to the `createLinkedAggregation` and `updateLinkedAggregation`
+/// messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LinkedAggregationDefinitionResponse {
+    linked_aggregation: LinkedAggregationDefinition,
+}
+
+impl LinkedAggregationDefinitionResponse {
+    #[must_use]
+    pub const fn new(linked_aggregation: LinkedAggregationDefinition) -> Self {
+        Self { linked_aggregation }
+    }
+
+    #[must_use]
+    pub const fn linked_aggregation(&self) -> &LinkedAggregationDefinition {
+        &self.linked_aggregation
+    }
+}
+
+/// The data of the response to the `getLinkedAggregation` message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LinkedAggregationResponse {
+    linked_aggregation: LinkedAggregation,
+}
+
+impl LinkedAggregationResponse {
+    #[must_use]
+    pub const fn new(linked_aggregation: LinkedAggregation) -> Self {
+        Self { linked_aggregation }
+    }
+
+    #[must_use]
+    pub const fn linked_aggregation(&self) -> &LinkedAggregation {
+        &self.linked_aggregation
+    }
+}
@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::graph::{ErrorCode, MessageName, MessageSource};
+
+/// The envelope of a message sent between a block and the embedding application.
+///
+/// The envelope is shared by all services, so the `service` and `messageName` are kept as
+/// strings. The data of graph service messages is modelled by the types in [`crate::graph`].
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Message {
+    request_id: String,
+    message_name: String,
+    service: String,
+    source: MessageSource,
+    timestamp: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    responded_to_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    data: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    errors: Option<Vec<MessageError>>,
+}
+
+impl Message {
+    #[must_use]
+    pub const fn new(
+        request_id: String,
+        message_name: String,
+        service: String,
+        source: MessageSource,
+        timestamp: String,
+    ) -> Self {
+        Self {
+            request_id,
+            message_name,
+            service,
+            source,
+            timestamp,
+            responded_to_by: None,
+            data: None,
+            errors: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_responded_to_by(mut self, responded_to_by: String) -> Self {
+        self.responded_to_by = Some(responded_to_by);
+        self
+    }
+
+    #[must_use]
+    pub fn with_data(mut self, data: Value) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    #[must_use]
+    pub fn with_errors(mut self, errors: Vec<MessageError>) -> Self {
+        self.errors = Some(errors);
+        self
+    }
+
+    /// Returns the identifier shared by a request and its response.
+    #[must_use]
+    pub fn request_id(&self) -> &str {
+        &self.request_id
+    }
+
+    #[must_use]
+    pub fn message_name(&self) -> &str {
+        &self.message_name
+    }
+
+    #[must_use]
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    #[must_use]
+    pub const fn source(&self) -> MessageSource {
+        self.source
+    }
+
+    #[must_use]
+    pub fn timestamp(&self) -> &str {
+        &self.timestamp
+    }
+
+    /// Returns the name of the message expected in response, if this message is a request.
+    #[must_use]
+    pub fn responded_to_by(&self) -> Option<&str> {
+        self.responded_to_by.as_deref()
+    }
+
+    #[must_use]
+    pub const fn data(&self) -> Option<&Value> {
+        self.data.as_ref()
+    }
+
+    #[must_use]
+    pub fn errors(&self) -> Option<&[MessageError]> {
+        self.errors.as_deref()
+    }
+}
+
+/// An error sent in response to a message.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MessageError {
+    code: ErrorCode,
+    message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    extensions: Option<Value>,
+}
+
+impl MessageError {
+    #[must_use]
+    pub const fn new(code: ErrorCode, message: String) -> Self {
+        Self {
+            code,
+            message,
+            extensions: None,
+        }
+    }
+
+    /// Creates the error sent when the embedding application does not handle the message.
+    #[must_use]
+    pub fn not_implemented(name: MessageName) -> Self {
+        Self::new(
+            ErrorCode::NotImplemented,
+            format!("`{}` is not implemented", name.as_str()),
+        )
+    }
+
+    #[must_use]
+    pub fn with_extensions(mut self, extensions: Value) -> Self {
+        self.extensions = Some(extensions);
+        self
+    }
+
+    #[must_use]
+    pub const fn code(&self) -> ErrorCode {
+        self.code
+    }
+
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns any additional information about the error.
+    #[must_use]
+    pub const fn extensions(&self) -> Option<&Value> {
+        self.extensions.as_ref()
+    }
+}
+
+/// The contents of the response to a request of the graph service.
+///
+/// The `requestId` and `timestamp` of the response are set when sending it, see
+/// [`into_message`].
+///
+/// [`into_message`]: Self::into_message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    message_name: MessageName,
+    data: Option<Value>,
+    errors: Vec<MessageError>,
+}
+
+impl Response {
+    pub(super) const fn from_data(message_name: MessageName, data: Value) -> Self {
+        Self {
+            message_name,
+            data: Some(data),
+            errors: Vec::new(),
+        }
+    }
+
+    pub(super) fn from_error(message_name: MessageName, error: MessageError) -> Self {
+        Self {
+            message_name,
+            data: None,
+            errors: vec![error],
+        }
+    }
+
+    #[must_use]
+    pub const fn message_name(&self) -> MessageName {
+        self.message_name
+    }
+
+    #[must_use]
+    pub const fn data(&self) -> Option<&Value> {
+        self.data.as_ref()
+    }
+
+    #[must_use]
+    pub fn errors(&self) -> &[MessageError] {
+        &self.errors
+    }
+
+    /// Creates the message responding to the `request`, sent by the embedding application at
+    /// `timestamp`.
+    #[must_use]
+    pub fn into_message(self, request: &Message, timestamp: String) -> Message {
+        let message = Message::new(
+            request.request_id.clone(),
+            self.message_name.as_str().to_owned(),
+            request.service.clone(),
+            MessageSource::Embedder,
+            timestamp,
+        );
+        let message = match self.data {
+            Some(data) => message.with_data(data),
+            None => message,
+        };
+        if self.errors.is_empty() {
+            message
+        } else {
+            message.with_errors(self.errors)
+        }
+    }
+}
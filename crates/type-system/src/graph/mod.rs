@@ -0,0 +1,1031 @@
+//! Messages of the graph service of the Block Protocol.
+//!
+//! The graph service allows blocks to query and modify the graph of entities stored by the
+//! embedding application. Its messages are defined in `graph-service.json` of the
+//! `@blockprotocol/graph` package, and this module contains a model for the data of every message:
+//!
+//! - [`MessageName`] lists the messages together with their metadata, e.g. which message responds
+//!   to a request and which [`ErrorCode`]s a response may contain.
+//! - Messages sent by blocks are named after the message, e.g. [`CreateEntityData`] is the data of
+//!   `createEntity`. Messages which only identify the resource they act on share a model, e.g.
+//!   [`EntityIdData`] is the data of both `getEntity` and `deleteEntity`.
+//! - Responses are modelled by the resource they contain, e.g. [`EntityResponse`] is the data of
+//!   `createEntityResponse`. Responses to deleting a resource carry a plain `bool`.
+//! - Messages sent on initialization are modelled by the data they contain, e.g. [`BlockGraph`],
+//!   [`Entity`], or [`EntityTypes`].
+//!
+//! In addition, the messages proposed by RFC 0352 to manage property types and link types and to
+//! aggregate data types are modelled the same way, see [`MessageName::is_proposed`].
+//!
+//! Embedding applications implement [`EmbedderGraphHandler`] to answer the requests of blocks,
+//! which are routed to the handler by [`dispatch`].
+//!
+//! Entities are represented as [`Entity`] and the schemas of types as [`EntityType`],
+//! [`PropertyType`], [`LinkType`], and [`DataType`], which are validated when deserializing a
+//! message. Messages containing existing entity types send the `entityTypeId` next to the
+//! `schema` of each entity type, as the graph service does.
+//!
+//! [`Entity`]: crate::Entity
+//! [`EntityType`]: crate::EntityType
+//! [`PropertyType`]: crate::PropertyType
+//! [`LinkType`]: crate::LinkType
+//! [`DataType`]: crate::DataType
+
+mod aggregation;
+mod block;
+mod data_type;
+mod entity;
+mod entity_type;
+mod error;
+mod file;
+mod handler;
+mod link;
+mod link_type;
+mod linked_aggregation;
+mod message;
+mod property_type;
+mod schema;
+
+use serde::{Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+pub use self::{
+    aggregation::{
+        AggregateData, AggregationOperation, Filter, FilterOperator, MultiFilter,
+        MultiFilterOperator, Sort,
+    },
+    block::BlockGraph,
+    data_type::AggregateDataTypesResponse,
+    entity::{
+        AggregateEntitiesResponse, CreateEntityData, CreateEntityLinkData, EntityIdData,
+        EntityResponse, UpdateEntityData,
+    },
+    entity_type::{
+        AggregateEntityTypesResponse, CreateEntityTypeData, EntityTypeIdData, EntityTypeResponse,
+        EntityTypes, UpdateEntityTypeData,
+    },
+    error::{DispatchError, UpdateTypeError},
+    file::{FileMediaType, UploadFileData, UploadFileResponse},
+    handler::{dispatch, EmbedderGraphHandler},
+    link::{CreateLinkData, Link, LinkGroup, LinkIdData, LinkResponse, UpdateLinkData},
+    link_type::{
+        AggregateLinkTypesResponse, CreateLinkTypeData, LinkTypeIdData, LinkTypeResponse,
+        UpdateLinkTypeData,
+    },
+    linked_aggregation::{
+        AggregationIdData, CreateLinkedAggregationData, LinkedAggregation,
+        LinkedAggregationDefinition, LinkedAggregationDefinitionResponse,
+        LinkedAggregationResponse, UpdateLinkedAggregationData,
+    },
+    message::{Message, MessageError, Response},
+    property_type::{
+        AggregatePropertyTypesResponse, CreatePropertyTypeData, PropertyTypeIdData,
+        PropertyTypeResponse, UpdatePropertyTypeData,
+    },
+};
+
+/// The error codes which may be returned in response to a message of the graph service.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    Forbidden,
+    InvalidInput,
+    NotFound,
+    /// The embedding application does not handle the message.
+    ///
+    /// This code is not listed in the definitions of the messages, as it may be returned in
+    /// response to any request.
+    NotImplemented,
+}
+
+/// The party sending a message.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MessageSource {
+    Block,
+    Embedder,
+}
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MessageName {
+    CreateEntity,
+    CreateEntityResponse,
+    UpdateEntity,
+    UpdateEntityResponse,
+    DeleteEntity,
+    DeleteEntityResponse,
+    GetEntity,
+    GetEntityResponse,
+    AggregateEntities,
+    AggregateEntitiesResponse,
+    CreateEntityType,
+    CreateEntityTypeResponse,
+    UpdateEntityType,
+    UpdateEntityTypeResponse,
+    DeleteEntityType,
+    DeleteEntityTypeResponse,
+    GetEntityType,
+    GetEntityTypeResponse,
+    AggregateEntityTypes,
+    AggregateEntityTypesResponse,
+    CreateLink,
+    CreateLinkResponse,
+    UpdateLink,
+    UpdateLinkResponse,
+    DeleteLink,
+    DeleteLinkResponse,
+    GetLink,
+    GetLinkResponse,
+    CreateLinkedAggregation,
+    CreateLinkedAggregationResponse,
+    UpdateLinkedAggregation,
+    UpdateLinkedAggregationResponse,
+    DeleteLinkedAggregation,
+    DeleteLinkedAggregationResponse,
+    GetLinkedAggregation,
+    GetLinkedAggregationResponse,
+    UploadFile,
+    UploadFileResponse,
+    BlockEntity,
+    BlockGraph,
+    EntityTypes,
+    LinkedAggregations,
+    Readonly,
+    CreatePropertyType,
+    CreatePropertyTypeResponse,
+    UpdatePropertyType,
+    UpdatePropertyTypeResponse,
+    DeletePropertyType,
+    DeletePropertyTypeResponse,
+    GetPropertyType,
+    GetPropertyTypeResponse,
+    AggregatePropertyTypes,
+    AggregatePropertyTypesResponse,
+    CreateLinkType,
+    CreateLinkTypeResponse,
+    UpdateLinkType,
+    UpdateLinkTypeResponse,
+    DeleteLinkType,
+    DeleteLinkTypeResponse,
+    GetLinkType,
+    GetLinkTypeResponse,
+    AggregateLinkTypes,
+    AggregateLinkTypesResponse,
+    AggregateDataTypes,
+    AggregateDataTypesResponse,
+}
+
+impl MessageName {
+    /// Every message of the graph service in the order of their definition.
+    pub const ALL: [Self; 65] = [
+        Self::CreateEntity,
+        Self::CreateEntityResponse,
+        Self::UpdateEntity,
+        Self::UpdateEntityResponse,
+        Self::DeleteEntity,
+        Self::DeleteEntityResponse,
+        Self::GetEntity,
+        Self::GetEntityResponse,
+        Self::AggregateEntities,
+        Self::AggregateEntitiesResponse,
+        Self::CreateEntityType,
+        Self::CreateEntityTypeResponse,
+        Self::UpdateEntityType,
+        Self::UpdateEntityTypeResponse,
+        Self::DeleteEntityType,
+        Self::DeleteEntityTypeResponse,
+        Self::GetEntityType,
+        Self::GetEntityTypeResponse,
+        Self::AggregateEntityTypes,
+        Self::AggregateEntityTypesResponse,
+        Self::CreateLink,
+        Self::CreateLinkResponse,
+        Self::UpdateLink,
+        Self::UpdateLinkResponse,
+        Self::DeleteLink,
+        Self::DeleteLinkResponse,
+        Self::GetLink,
+        Self::GetLinkResponse,
+        Self::CreateLinkedAggregation,
+        Self::CreateLinkedAggregationResponse,
+        Self::UpdateLinkedAggregation,
+        Self::UpdateLinkedAggregationResponse,
+        Self::DeleteLinkedAggregation,
+        Self::DeleteLinkedAggregationResponse,
+        Self::GetLinkedAggregation,
+        Self::GetLinkedAggregationResponse,
+        Self::UploadFile,
+        Self::UploadFileResponse,
+        Self::BlockEntity,
+        Self::BlockGraph,
+        Self::EntityTypes,
+        Self::LinkedAggregations,
+        Self::Readonly,
+        Self::CreatePropertyType,
+        Self::CreatePropertyTypeResponse,
+        Self::UpdatePropertyType,
+        Self::UpdatePropertyTypeResponse,
+        Self::DeletePropertyType,
+        Self::DeletePropertyTypeResponse,
+        Self::GetPropertyType,
+        Self::GetPropertyTypeResponse,
+        Self::AggregatePropertyTypes,
+        Self::AggregatePropertyTypesResponse,
+        Self::CreateLinkType,
+        Self::CreateLinkTypeResponse,
+        Self::UpdateLinkType,
+        Self::UpdateLinkTypeResponse,
+        Self::DeleteLinkType,
+        Self::DeleteLinkTypeResponse,
+        Self::GetLinkType,
+        Self::GetLinkTypeResponse,
+        Self::AggregateLinkTypes,
+        Self::AggregateLinkTypesResponse,
+        Self::AggregateDataTypes,
+        Self::AggregateDataTypesResponse,
+    ];
+
+    /// Returns the name of the message as it's sent, e.g. `createEntity`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::CreateEntity => "createEntity",
+            Self::CreateEntityResponse => "createEntityResponse",
+            Self::UpdateEntity => "updateEntity",
+            Self::UpdateEntityResponse => "updateEntityResponse",
+            Self::DeleteEntity => "deleteEntity",
+            Self::DeleteEntityResponse => "deleteEntityResponse",
+            Self::GetEntity => "getEntity",
+            Self::GetEntityResponse => "getEntityResponse",
+            Self::AggregateEntities => "aggregateEntities",
+            Self::AggregateEntitiesResponse => "aggregateEntitiesResponse",
+            Self::CreateEntityType => "createEntityType",
+            Self::CreateEntityTypeResponse => "createEntityTypeResponse",
+            Self::UpdateEntityType => "updateEntityType",
+            Self::UpdateEntityTypeResponse => "updateEntityTypeResponse",
+            Self::DeleteEntityType => "deleteEntityType",
+            Self::DeleteEntityTypeResponse => "deleteEntityTypeResponse",
+            Self::GetEntityType => "getEntityType",
+            Self::GetEntityTypeResponse => "getEntityTypeResponse",
+            Self::AggregateEntityTypes => "aggregateEntityTypes",
+            Self::AggregateEntityTypesResponse => "aggregateEntityTypesResponse",
+            Self::CreateLink => "createLink",
+            Self::CreateLinkResponse => "createLinkResponse",
+            Self::UpdateLink => "updateLink",
+            Self::UpdateLinkResponse => "updateLinkResponse",
+            Self::DeleteLink => "deleteLink",
+            Self::DeleteLinkResponse => "deleteLinkResponse",
+            Self::GetLink => "getLink",
+            Self::GetLinkResponse => "getLinkResponse",
+            Self::CreateLinkedAggregation => "createLinkedAggregation",
+            Self::CreateLinkedAggregationResponse => "createLinkedAggregationResponse",
+            Self::UpdateLinkedAggregation => "updateLinkedAggregation",
+            Self::UpdateLinkedAggregationResponse => "updateLinkedAggregationResponse",
+            Self::DeleteLinkedAggregation => "deleteLinkedAggregation",
+            Self::DeleteLinkedAggregationResponse => "deleteLinkedAggregationResponse",
+            Self::GetLinkedAggregation => "getLinkedAggregation",
+            Self::GetLinkedAggregationResponse => "getLinkedAggregationResponse",
+            Self::UploadFile => "uploadFile",
+            Self::UploadFileResponse => "uploadFileResponse",
+            Self::BlockEntity => "blockEntity",
+            Self::BlockGraph => "blockGraph",
+            Self::EntityTypes => "entityTypes",
+            Self::LinkedAggregations => "linkedAggregations",
+            Self::Readonly => "readonly",
+            Self::CreatePropertyType => "createPropertyType",
+            Self::CreatePropertyTypeResponse => "createPropertyTypeResponse",
+            Self::UpdatePropertyType => "updatePropertyType",
+            Self::UpdatePropertyTypeResponse => "updatePropertyTypeResponse",
+            Self::DeletePropertyType => "deletePropertyType",
+            Self::DeletePropertyTypeResponse => "deletePropertyTypeResponse",
+            Self::GetPropertyType => "getPropertyType",
+            Self::GetPropertyTypeResponse => "getPropertyTypeResponse",
+            Self::AggregatePropertyTypes => "aggregatePropertyTypes",
+            Self::AggregatePropertyTypesResponse => "aggregatePropertyTypesResponse",
+            Self::CreateLinkType => "createLinkType",
+            Self::CreateLinkTypeResponse => "createLinkTypeResponse",
+            Self::UpdateLinkType => "updateLinkType",
+            Self::UpdateLinkTypeResponse => "updateLinkTypeResponse",
+            Self::DeleteLinkType => "deleteLinkType",
+            Self::DeleteLinkTypeResponse => "deleteLinkTypeResponse",
+            Self::GetLinkType => "getLinkType",
+            Self::GetLinkTypeResponse => "getLinkTypeResponse",
+            Self::AggregateLinkTypes => "aggregateLinkTypes",
+            Self::AggregateLinkTypesResponse => "aggregateLinkTypesResponse",
+            Self::AggregateDataTypes => "aggregateDataTypes",
+            Self::AggregateDataTypesResponse => "aggregateDataTypesResponse",
+        }
+    }
+
+    /// Returns the response to this message, if it's a request.
+    #[must_use]
+    pub const fn responded_to_by(self) -> Option<Self> {
+        Some(match self {
+            Self::CreateEntity => Self::CreateEntityResponse,
+            Self::UpdateEntity => Self::UpdateEntityResponse,
+            Self::DeleteEntity => Self::DeleteEntityResponse,
+            Self::GetEntity => Self::GetEntityResponse,
+            Self::AggregateEntities => Self::AggregateEntitiesResponse,
+            Self::CreateEntityType => Self::CreateEntityTypeResponse,
+            Self::UpdateEntityType => Self::UpdateEntityTypeResponse,
+            Self::DeleteEntityType => Self::DeleteEntityTypeResponse,
+            Self::GetEntityType => Self::GetEntityTypeResponse,
+            Self::AggregateEntityTypes => Self::AggregateEntityTypesResponse,
+            Self::CreateLink => Self::CreateLinkResponse,
+            Self::UpdateLink => Self::UpdateLinkResponse,
+            Self::DeleteLink => Self::DeleteLinkResponse,
+            Self::GetLink => Self::GetLinkResponse,
+            Self::CreateLinkedAggregation => Self::CreateLinkedAggregationResponse,
+            Self::UpdateLinkedAggregation => Self::UpdateLinkedAggregationResponse,
+            Self::DeleteLinkedAggregation => Self::DeleteLinkedAggregationResponse,
+            Self::GetLinkedAggregation => Self::GetLinkedAggregationResponse,
+            Self::UploadFile => Self::UploadFileResponse,
+            Self::CreatePropertyType => Self::CreatePropertyTypeResponse,
+            Self::UpdatePropertyType => Self::UpdatePropertyTypeResponse,
+            Self::DeletePropertyType => Self::DeletePropertyTypeResponse,
+            Self::GetPropertyType => Self::GetPropertyTypeResponse,
+            Self::AggregatePropertyTypes => Self::AggregatePropertyTypesResponse,
+            Self::CreateLinkType => Self::CreateLinkTypeResponse,
+            Self::UpdateLinkType => Self::UpdateLinkTypeResponse,
+            Self::DeleteLinkType => Self::DeleteLinkTypeResponse,
+            Self::GetLinkType => Self::GetLinkTypeResponse,
+            Self::AggregateLinkTypes => Self::AggregateLinkTypesResponse,
+            Self::AggregateDataTypes => Self::AggregateDataTypesResponse,
+            _ => return None,
+        })
+    }
+
+    /// Returns the request this message responds to, if it's a response.
+    #[must_use]
+    pub fn responds_to(self) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|request| request.responded_to_by() == Some(self))
+    }
+
+    #[must_use]
+    pub const fn source(self) -> MessageSource {
+        if self.responded_to_by().is_some() {
+            MessageSource::Block
+        } else {
+            MessageSource::Embedder
+        }
+    }
+
+    /// Returns if the message is proposed by RFC 0352 for the type system and not yet defined in
+    /// `graph-service.json`.
+    ///
+    /// These are the messages for property types, link types, and data types.
+    #[must_use]
+    pub const fn is_proposed(self) -> bool {
+        matches!(
+            self,
+            Self::CreatePropertyType
+                | Self::CreatePropertyTypeResponse
+                | Self::UpdatePropertyType
+                | Self::UpdatePropertyTypeResponse
+                | Self::DeletePropertyType
+                | Self::DeletePropertyTypeResponse
+                | Self::GetPropertyType
+                | Self::GetPropertyTypeResponse
+                | Self::AggregatePropertyTypes
+                | Self::AggregatePropertyTypesResponse
+                | Self::CreateLinkType
+                | Self::CreateLinkTypeResponse
+                | Self::UpdateLinkType
+                | Self::UpdateLinkTypeResponse
+                | Self::DeleteLinkType
+                | Self::DeleteLinkTypeResponse
+                | Self::GetLinkType
+                | Self::GetLinkTypeResponse
+                | Self::AggregateLinkTypes
+                | Self::AggregateLinkTypesResponse
+                | Self::AggregateDataTypes
+                | Self::AggregateDataTypesResponse
+        )
+    }
+
+    /// Returns if the message is sent by the embedding application when the block is initialized.
+    #[must_use]
+    pub const fn sent_on_initialization(self) -> bool {
+        matches!(
+            self,
+            Self::BlockEntity
+                | Self::BlockGraph
+                | Self::EntityTypes
+                | Self::LinkedAggregations
+                | Self::Readonly
+        )
+    }
+
+    /// Returns the error codes which may be sent with this message.
+    #[must_use]
+    pub const fn error_codes(self) -> &'static [ErrorCode] {
+        match self {
+            Self::CreateEntityResponse
+            | Self::AggregateEntitiesResponse
+            | Self::CreateEntityTypeResponse
+            | Self::AggregateEntityTypesResponse
+            | Self::CreateLinkResponse
+            | Self::CreateLinkedAggregationResponse
+            | Self::CreatePropertyTypeResponse
+            | Self::AggregatePropertyTypesResponse
+            | Self::CreateLinkTypeResponse
+            | Self::AggregateLinkTypesResponse
+            | Self::AggregateDataTypesResponse => &[ErrorCode::Forbidden, ErrorCode::InvalidInput],
+            Self::UpdateEntityResponse
+            | Self::DeleteEntityResponse
+            | Self::GetEntityResponse
+            | Self::UpdateEntityTypeResponse
+            | Self::DeleteEntityTypeResponse
+            | Self::GetEntityTypeResponse
+            | Self::UpdateLinkResponse
+            | Self::DeleteLinkResponse
+            | Self::GetLinkResponse
+            | Self::UpdateLinkedAggregationResponse
+            | Self::DeleteLinkedAggregationResponse
+            | Self::GetLinkedAggregationResponse
+            | Self::UploadFileResponse
+            | Self::UpdatePropertyTypeResponse
+            | Self::DeletePropertyTypeResponse
+            | Self::GetPropertyTypeResponse
+            | Self::UpdateLinkTypeResponse
+            | Self::DeleteLinkTypeResponse
+            | Self::GetLinkTypeResponse => &[
+                ErrorCode::Forbidden,
+                ErrorCode::InvalidInput,
+                ErrorCode::NotFound,
+            ],
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, fmt::Debug, str::FromStr};
+
+    use serde::de::DeserializeOwned;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{test_data, Entity, EntityId, EntityType};
+
+    const GRAPH_SERVICE: &str =
+        include_str!("../../../../packages/@blockprotocol/graph/src/graph-service.json");
+
+    /// Returns the messages defined in `graph-service.json`.
+    fn defined() -> impl Iterator<Item = MessageName> {
+        MessageName::ALL
+            .into_iter()
+            .filter(|name| !name.is_proposed())
+    }
+
+    fn definitions() -> Vec<Value> {
+        let service: Value = serde_json::from_str(GRAPH_SERVICE).expect("invalid graph service");
+        service["messages"]
+            .as_array()
+            .expect("messages are not an array")
+            .clone()
+    }
+
+    /// The shared definitions referenced by the message definitions.
+    const SHARED_DEFINITIONS: [&str; 6] = [
+        include_str!("../../../../site/public/types/services/graph/entity.json"),
+        include_str!("../../../../site/public/types/services/graph/link.json"),
+        include_str!("../../../../site/public/types/services/graph/entity-type.json"),
+        include_str!("../../../../site/public/types/services/graph/aggregation-operation.json"),
+        include_str!("../../../../site/public/types/services/graph/link-group.json"),
+        include_str!(
+            "../../../../site/public/types/services/graph/linked-aggregation-definition.json"
+        ),
+    ];
+
+    /// References to schemas which are not part of the repository, so they accept any value.
+    const UNAVAILABLE_DEFINITIONS: [&str; 2] = [
+        "https://json-schema.org/draft/2020-12/schema",
+        "https://blockprotocol.org/types/services/graph/linked-aggregation",
+    ];
+
+    /// Errors of the examples caused by message definitions which contradict the TypeScript types
+    /// of the graph service, which are followed by the models.
+    const KNOWN_DIFFERENCES: [&str; 1] = [
+        // `CreateEntityData` omits the `sourceEntityId` of the links, as it's the entity which is
+        // about to be created
+        "createEntity/links/0: missing required property `sourceEntityId`",
+    ];
+
+    /// An example of the data of a message.
+    struct Example {
+        value: Value,
+        /// Deserializes a value to the type the example was created from.
+        decode: fn(Value) -> serde_json::Result<()>,
+    }
+
+    fn decode<T: DeserializeOwned>(value: Value) -> serde_json::Result<()> {
+        serde_json::from_value::<T>(value).map(drop)
+    }
+
+    /// Serializes `data` and checks that it's deserialized to the same value.
+    fn encode<T>(data: &T) -> Example
+    where
+        T: Serialize + DeserializeOwned + PartialEq + Debug,
+    {
+        let value = serde_json::to_value(data).expect("could not serialize data");
+        assert_eq!(
+            &serde_json::from_value::<T>(value.clone()).expect("could not deserialize data"),
+            data
+        );
+        Example {
+            value,
+            decode: decode::<T>,
+        }
+    }
+
+    /// Checks values against the subset of JSON schema used by the message definitions and the
+    /// shared definitions they reference.
+    struct Checker {
+        definitions: HashMap<String, Value>,
+        errors: Vec<String>,
+    }
+
+    impl Checker {
+        fn new() -> Self {
+            let definitions = SHARED_DEFINITIONS
+                .into_iter()
+                .map(|definition| {
+                    let definition: Value =
+                        serde_json::from_str(definition).expect("invalid definition");
+                    let id = definition["$id"]
+                        .as_str()
+                        .expect("definition has no `$id`")
+                        .to_owned();
+                    (id, definition)
+                })
+                .collect();
+            Self {
+                definitions,
+                errors: Vec::new(),
+            }
+        }
+
+        /// Returns the schema referenced by `schema` and the document containing it, or `None` if
+        /// the referenced schema accepts any value.
+        fn resolve<'s>(
+            &'s self,
+            schema: &'s Value,
+            root: &'s Value,
+        ) -> Option<(&'s Value, &'s Value)> {
+            let Some(reference) = schema.get("$ref").and_then(Value::as_str) else {
+                return Some((schema, root));
+            };
+            if let Some(pointer) = reference.strip_prefix('#') {
+                let schema = root.pointer(pointer).expect("unresolvable local reference");
+                return self.resolve(schema, root);
+            }
+            if UNAVAILABLE_DEFINITIONS.contains(&reference) {
+                return None;
+            }
+            let definition = self
+                .definitions
+                .get(reference)
+                .unwrap_or_else(|| panic!("unknown reference `{reference}`"));
+            self.resolve(definition, definition)
+        }
+
+        /// Checks `value` against `schema`, which is part of the document `root`.
+        ///
+        /// The message definitions list every field of a message, so unknown properties are
+        /// reported. The shared definitions only list the fields used by the graph service, e.g.
+        /// the `linkId` of a link is missing, so they accept unknown properties like JSON schema
+        /// does.
+        fn check(&mut self, schema: &Value, root: &Value, value: &Value, path: &str) {
+            let Some((schema, root)) = self.resolve(schema, root) else {
+                return;
+            };
+            let (schema, root) = (schema.clone(), root.clone());
+            let shared = root.get("$id").is_some();
+
+            if let Some(expected) = schema.get("type").and_then(Value::as_str) {
+                let matches = match expected {
+                    "object" => value.is_object(),
+                    "array" => value.is_array(),
+                    "string" => value.is_string(),
+                    "number" => value.is_number(),
+                    "integer" => value.is_u64() || value.is_i64(),
+                    "boolean" => value.is_boolean(),
+                    _ => false,
+                };
+                if !matches {
+                    self.errors
+                        .push(format!("{path}: expected {expected}, found {value}"));
+                    return;
+                }
+            }
+            if let Some(variants) = schema.get("enum").and_then(Value::as_array) {
+                if !variants.contains(value) {
+                    self.errors
+                        .push(format!("{path}: {value} is not one of the allowed values"));
+                }
+            }
+            if let Some(variants) = schema.get("anyOf").and_then(Value::as_array) {
+                let errors = std::mem::take(&mut self.errors);
+                let matches = variants.iter().any(|variant| {
+                    self.errors.clear();
+                    self.check(variant, &root, value, path);
+                    self.errors.is_empty()
+                });
+                self.errors = errors;
+                if !matches {
+                    self.errors
+                        .push(format!("{path}: {value} matches none of the variants"));
+                }
+            }
+            if let (Some(properties), Some(object)) = (
+                schema.get("properties").and_then(Value::as_object),
+                value.as_object(),
+            ) {
+                for required in schema["required"].as_array().into_iter().flatten() {
+                    let required = required
+                        .as_str()
+                        .expect("required property is not a string");
+                    if !object.contains_key(required) {
+                        self.errors
+                            .push(format!("{path}: missing required property `{required}`"));
+                    }
+                }
+                for (key, value) in object {
+                    match properties.get(key) {
+                        Some(schema) => self.check(schema, &root, value, &format!("{path}/{key}")),
+                        None if shared => {}
+                        None => self
+                            .errors
+                            .push(format!("{path}: unknown property `{key}`")),
+                    }
+                }
+            }
+            if let (Some(items), Some(array)) = (schema.get("items"), value.as_array()) {
+                for (index, item) in array.iter().enumerate() {
+                    self.check(items, &root, item, &format!("{path}/{index}"));
+                }
+            }
+        }
+
+        /// Removes every property from `value` which `schema` lists as optional, so the result is
+        /// the smallest value accepted by `schema` which can be created from `value`.
+        ///
+        /// Properties which aren't listed by a shared definition are kept, as the definition does
+        /// not state whether they are optional.
+        fn minimal(&self, schema: &Value, root: &Value, value: &Value) -> Value {
+            let Some((schema, root)) = self.resolve(schema, root) else {
+                return value.clone();
+            };
+            match value {
+                Value::Object(object) => {
+                    let Some(properties) = schema.get("properties") else {
+                        return value.clone();
+                    };
+                    let required = schema["required"].as_array().cloned().unwrap_or_default();
+                    Value::Object(
+                        object
+                            .iter()
+                            .filter_map(|(key, value)| match properties.get(key) {
+                                Some(schema) if required.contains(&json!(key)) => {
+                                    Some((key.clone(), self.minimal(schema, root, value)))
+                                }
+                                Some(_) => None,
+                                None => Some((key.clone(), value.clone())),
+                            })
+                            .collect(),
+                    )
+                }
+                Value::Array(array) => schema.get("items").map_or_else(
+                    || value.clone(),
+                    |items| {
+                        array
+                            .iter()
+                            .map(|item| self.minimal(items, root, item))
+                            .collect()
+                    },
+                ),
+                _ => value.clone(),
+            }
+        }
+    }
+
+    fn entity_id(id: &str) -> EntityId {
+        EntityId::new(id.to_owned())
+    }
+
+    fn entity() -> Entity {
+        serde_json::from_value(json!({
+            "entityId": "book",
+            "entityTypeId": "https://blockprotocol.org/@alice/types/entity-type/book/v/1",
+            "properties": {
+                "https://blockprotocol.org/@alice/types/property-type/name/": ["The Hobbit"]
+            }
+        }))
+        .expect("invalid entity")
+    }
+
+    fn entity_type() -> EntityType {
+        EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid entity type")
+    }
+
+    fn operation() -> AggregationOperation {
+        AggregationOperation::new()
+            .with_entity_type_id(entity_type().id().clone())
+            .with_page(1, 10)
+            .with_multi_sort(vec![Sort::new("name".to_owned()).with_descending(true)])
+            .with_multi_filter(MultiFilter::new(
+                vec![
+                    Filter::new(
+                        "name".to_owned(),
+                        FilterOperator::StartsWith,
+                        "The".to_owned(),
+                    ),
+                    Filter::without_value("blurb".to_owned(), FilterOperator::IsNotEmpty),
+                ],
+                MultiFilterOperator::And,
+            ))
+    }
+
+    fn link() -> Link {
+        Link::new(
+            "link".to_owned(),
+            CreateLinkData::new(
+                entity_id("book"),
+                entity_id("alice"),
+                "writtenBy".to_owned(),
+            )
+            .with_index(0),
+        )
+    }
+
+    fn definition() -> LinkedAggregationDefinition {
+        LinkedAggregationDefinition::new(
+            "aggregation".to_owned(),
+            CreateLinkedAggregationData::new(entity_id("shelf"), operation(), "books".to_owned()),
+        )
+    }
+
+    /// Returns examples of the data of the message, using as many and as few optional values as
+    /// possible.
+    #[expect(clippy::too_many_lines)]
+    fn examples(name: MessageName) -> Vec<Example> {
+        let properties = entity().properties().clone();
+        match name {
+            MessageName::CreateEntity => vec![
+                encode(
+                    &CreateEntityData::new(entity_type().id().clone(), properties).with_links(
+                        vec![
+                            CreateEntityLinkData::new(entity_id("alice"), "writtenBy".to_owned())
+                                .with_index(0),
+                        ],
+                    ),
+                ),
+                encode(&CreateEntityData::new(
+                    entity_type().id().clone(),
+                    HashMap::new(),
+                )),
+            ],
+            MessageName::UpdateEntity => {
+                vec![encode(&UpdateEntityData::new(
+                    entity_id("book"),
+                    properties,
+                ))]
+            }
+            MessageName::DeleteEntity | MessageName::GetEntity => {
+                vec![encode(&EntityIdData::new(entity_id("book")))]
+            }
+            MessageName::CreateEntityResponse
+            | MessageName::UpdateEntityResponse
+            | MessageName::GetEntityResponse => vec![encode(&EntityResponse::new(entity()))],
+            MessageName::AggregateEntities | MessageName::AggregateEntityTypes => vec![
+                encode(&AggregateData::new(operation())),
+                encode(&AggregateData::new(AggregationOperation::new())),
+            ],
+            MessageName::AggregateEntitiesResponse => vec![encode(
+                &AggregateEntitiesResponse::new(operation().with_counts(1, 1), vec![entity()]),
+            )],
+            MessageName::CreateEntityType => {
+                vec![encode(&CreateEntityTypeData::new(entity_type()))]
+            }
+            MessageName::UpdateEntityType => vec![encode(&UpdateEntityTypeData::new(
+                entity_type().id().clone(),
+                entity_type(),
+            ))],
+            MessageName::DeleteEntityType | MessageName::GetEntityType => {
+                vec![encode(&EntityTypeIdData::new(entity_type().id().clone()))]
+            }
+            MessageName::CreateEntityTypeResponse
+            | MessageName::UpdateEntityTypeResponse
+            | MessageName::GetEntityTypeResponse => {
+                vec![encode(&EntityTypeResponse::new(entity_type()))]
+            }
+            MessageName::AggregateEntityTypesResponse => {
+                vec![encode(&AggregateEntityTypesResponse::new(
+                    operation().with_counts(1, 1),
+                    vec![entity_type()],
+                ))]
+            }
+            MessageName::CreateLink => vec![
+                encode(
+                    &CreateLinkData::new(
+                        entity_id("book"),
+                        entity_id("alice"),
+                        "writtenBy".to_owned(),
+                    )
+                    .with_index(0),
+                ),
+                encode(&CreateLinkData::new(
+                    entity_id("book"),
+                    entity_id("alice"),
+                    "writtenBy".to_owned(),
+                )),
+            ],
+            MessageName::UpdateLink => vec![encode(&UpdateLinkData::new("link".to_owned(), 1))],
+            MessageName::DeleteLink | MessageName::GetLink => {
+                vec![encode(&LinkIdData::new("link".to_owned()))]
+            }
+            MessageName::CreateLinkResponse
+            | MessageName::UpdateLinkResponse
+            | MessageName::GetLinkResponse => vec![encode(&LinkResponse::new(link()))],
+            MessageName::CreateLinkedAggregation => {
+                vec![encode(&CreateLinkedAggregationData::new(
+                    entity_id("shelf"),
+                    operation(),
+                    "books".to_owned(),
+                ))]
+            }
+            MessageName::UpdateLinkedAggregation => vec![encode(
+                &UpdateLinkedAggregationData::new("aggregation".to_owned(), operation()),
+            )],
+            MessageName::DeleteLinkedAggregation | MessageName::GetLinkedAggregation => {
+                vec![encode(&AggregationIdData::new("aggregation".to_owned()))]
+            }
+            MessageName::CreateLinkedAggregationResponse
+            | MessageName::UpdateLinkedAggregationResponse => vec![encode(
+                &LinkedAggregationDefinitionResponse::new(definition()),
+            )],
+            MessageName::GetLinkedAggregationResponse => {
+                vec![encode(&LinkedAggregationResponse::new(
+                    LinkedAggregation::new(definition(), vec![entity()]),
+                ))]
+            }
+            MessageName::UploadFile => vec![
+                encode(&UploadFileData::from_url(
+                    "https://example.com/cover.png".to_owned(),
+                    FileMediaType::Image,
+                )),
+                encode(&UploadFileData::from_file(json!({}), FileMediaType::Video)),
+            ],
+            MessageName::UploadFileResponse => vec![encode(&UploadFileResponse::new(
+                entity_id("cover"),
+                "https://example.com/cover.png".to_owned(),
+                FileMediaType::Image,
+            ))],
+            MessageName::DeleteEntityResponse
+            | MessageName::DeleteEntityTypeResponse
+            | MessageName::DeleteLinkResponse
+            | MessageName::DeleteLinkedAggregationResponse
+            | MessageName::Readonly => vec![encode(&true)],
+            MessageName::BlockEntity => vec![encode(&entity())],
+            MessageName::BlockGraph => vec![encode(&BlockGraph::new(1, vec![entity()], vec![
+                LinkGroup::new(entity_id("book"), "writtenBy".to_owned(), vec![link()]),
+            ]))],
+            MessageName::EntityTypes => vec![encode(&EntityTypes::new(vec![entity_type()]))],
+            MessageName::LinkedAggregations => vec![encode(&vec![definition()])],
+            // Proposed messages are not defined in `graph-service.json`, so there is nothing to
+            // check the examples against.
+            MessageName::CreatePropertyType
+            | MessageName::CreatePropertyTypeResponse
+            | MessageName::UpdatePropertyType
+            | MessageName::UpdatePropertyTypeResponse
+            | MessageName::DeletePropertyType
+            | MessageName::DeletePropertyTypeResponse
+            | MessageName::GetPropertyType
+            | MessageName::GetPropertyTypeResponse
+            | MessageName::AggregatePropertyTypes
+            | MessageName::AggregatePropertyTypesResponse
+            | MessageName::CreateLinkType
+            | MessageName::CreateLinkTypeResponse
+            | MessageName::UpdateLinkType
+            | MessageName::UpdateLinkTypeResponse
+            | MessageName::DeleteLinkType
+            | MessageName::DeleteLinkTypeResponse
+            | MessageName::GetLinkType
+            | MessageName::GetLinkTypeResponse
+            | MessageName::AggregateLinkTypes
+            | MessageName::AggregateLinkTypesResponse
+            | MessageName::AggregateDataTypes
+            | MessageName::AggregateDataTypesResponse => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn message_definitions() {
+        let definitions = definitions();
+        assert_eq!(definitions.len(), defined().count());
+
+        for (definition, name) in definitions.iter().zip(defined()) {
+            assert_eq!(
+                serde_json::from_value::<MessageName>(definition["messageName"].clone())
+                    .expect("unknown message"),
+                name
+            );
+            assert_eq!(definition["messageName"], name.as_str());
+            assert_eq!(
+                serde_json::from_value::<MessageSource>(definition["source"].clone())
+                    .expect("unknown source"),
+                name.source()
+            );
+            assert_eq!(
+                serde_json::from_value::<Option<MessageName>>(definition["respondedToBy"].clone())
+                    .expect("unknown response"),
+                name.responded_to_by()
+            );
+            assert_eq!(
+                definition["sentOnInitialization"].as_bool(),
+                Some(name.sent_on_initialization())
+            );
+            assert_eq!(
+                serde_json::from_value::<Vec<ErrorCode>>(definition["errorCodes"].clone())
+                    .expect("unknown error code"),
+                name.error_codes()
+            );
+            if let Some(response) = name.responded_to_by() {
+                assert_eq!(response.responds_to(), Some(name));
+            }
+        }
+    }
+
+    #[test]
+    fn message_data() {
+        let mut checker = Checker::new();
+        for (definition, name) in definitions().iter().zip(defined()) {
+            let schema = &definition["data"];
+            for example in examples(name) {
+                checker.check(schema, schema, &example.value, name.as_str());
+
+                // Values omitting every optional property are valid data of the message as well
+                let minimal = checker.minimal(schema, schema, &example.value);
+                checker.check(schema, schema, &minimal, name.as_str());
+                if let Err(error) = (example.decode)(minimal.clone()) {
+                    checker.errors.push(format!(
+                        "{}: could not decode {minimal}: {error}",
+                        name.as_str()
+                    ));
+                }
+            }
+        }
+        checker
+            .errors
+            .retain(|error| !KNOWN_DIFFERENCES.contains(&error.as_str()));
+        assert!(checker.errors.is_empty(), "{}", checker.errors.join("\n"));
+    }
+
+    #[test]
+    fn proposed_messages() {
+        for name in MessageName::ALL
+            .into_iter()
+            .filter(|name| name.is_proposed())
+        {
+            // The messages mirror the corresponding messages for entity types
+            let entity_type_message = name
+                .as_str()
+                .replace("PropertyType", "EntityType")
+                .replace("LinkType", "EntityType")
+                .replace("DataType", "EntityType");
+            let entity_type_message =
+                serde_json::from_value::<MessageName>(Value::String(entity_type_message))
+                    .expect("unknown message");
+
+            assert_eq!(name.source(), entity_type_message.source());
+            assert_eq!(name.error_codes(), entity_type_message.error_codes());
+            assert!(!name.sent_on_initialization());
+            if let Some(response) = name.responded_to_by() {
+                assert!(response.is_proposed());
+                assert_eq!(response.responds_to(), Some(name));
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_entity_type() {
+        let mut schema = Value::from(entity_type());
+        assert!(
+            serde_json::from_value::<CreateEntityTypeData>(json!({ "schema": schema })).is_ok()
+        );
+
+        schema["$id"] = json!("https://blockprotocol.org/@alice/types/entity-type/book/");
+        assert!(
+            serde_json::from_value::<CreateEntityTypeData>(json!({ "schema": schema })).is_err()
+        );
+    }
+}
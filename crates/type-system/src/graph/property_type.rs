@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    graph::{
+        schema::{serde_schema, serde_schemas},
+        AggregationOperation,
+    },
+    uri::VersionedUri,
+    PropertyType,
+};
+
+/// The data of the `createPropertyType` message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CreatePropertyTypeData {
+    #[serde(with = "serde_schema")]
+    schema: PropertyType,
+}
+
+impl CreatePropertyTypeData {
+    #[must_use]
+    pub const fn new(schema: PropertyType) -> Self {
+        Self { schema }
+    }
+
+    #[must_use]
+    pub const fn schema(&self) -> &PropertyType {
+        &self.schema
+    }
+}
+
+/// The data of the `updatePropertyType` message.
+///
+/// The `schema` replaces the existing schema of the property type, as merging schemas with a
+/// top-level `oneOf` would be ambiguous.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UpdatePropertyTypeData {
+    property_type_id: VersionedUri,
+    #[serde(with = "serde_schema")]
+    schema: PropertyType,
+}
+
+impl UpdatePropertyTypeData {
+    #[must_use]
+    pub const fn new(property_type_id: VersionedUri, schema: PropertyType) -> Self {
+        Self {
+            property_type_id,
+            schema,
+        }
+    }
+
+    #[must_use]
+    pub const fn property_type_id(&self) -> &VersionedUri {
+        &self.property_type_id
+    }
+
+    #[must_use]
+    pub const fn schema(&self) -> &PropertyType {
+        &self.schema
+    }
+}
+
+/// The data of the `getPropertyType` and `deletePropertyType` messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PropertyTypeIdData {
+    property_type_id: VersionedUri,
+}
+
+impl PropertyTypeIdData {
+    #[must_use]
+    pub const fn new(property_type_id: VersionedUri) -> Self {
+        Self { property_type_id }
+    }
+
+    #[must_use]
+    pub const fn property_type_id(&self) -> &VersionedUri {
+        &self.property_type_id
+    }
+}
+
+/// The data of the responses to the `createPropertyType`, `updatePropertyType`, and
+/// `getPropertyType` messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PropertyTypeResponse {
+    #[serde(with = "serde_schema")]
+    property_type: PropertyType,
+}
+
+impl PropertyTypeResponse {
+    #[must_use]
+    pub const fn new(property_type: PropertyType) -> Self {
+        Self { property_type }
+    }
+
+    #[must_use]
+    pub const fn property_type(&self) -> &PropertyType {
+        &self.property_type
+    }
+
+    #[must_use]
+    pub fn into_property_type(self) -> PropertyType {
+        self.property_type
+    }
+}
+
+/// The data of the response to the `aggregatePropertyTypes` message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AggregatePropertyTypesResponse {
+    operation: AggregationOperation,
+    #[serde(with = "serde_schemas")]
+    results: Vec<PropertyType>,
+}
+
+impl AggregatePropertyTypesResponse {
+    #[must_use]
+    pub const fn new(operation: AggregationOperation, results: Vec<PropertyType>) -> Self {
+        Self { operation, results }
+    }
+
+    /// Returns the operation which was applied, including any defaults set by the embedding
+    /// application.
+    #[must_use]
+    pub const fn operation(&self) -> &AggregationOperation {
+        &self.operation
+    }
+
+    #[must_use]
+    pub fn results(&self) -> &[PropertyType] {
+        &self.results
+    }
+}
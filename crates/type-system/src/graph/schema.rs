@@ -0,0 +1,58 @@
+//! Types are de/serialized through their JSON representation, which validates them when
+//! deserializing.
+
+pub(super) mod serde_schema {
+    use std::fmt;
+
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+    use serde_json::Value;
+
+    pub(in crate::graph) fn serialize<T, S>(schema: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Clone + Into<Value>,
+        S: Serializer,
+    {
+        schema.clone().into().serialize(serializer)
+    }
+
+    pub(in crate::graph) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: TryFrom<Value>,
+        T::Error: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        T::try_from(Value::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// The same as [`serde_schema`] for a list of types.
+pub(super) mod serde_schemas {
+    use std::fmt;
+
+    use serde::{de, ser::SerializeSeq, Deserialize, Deserializer, Serializer};
+    use serde_json::Value;
+
+    pub(in crate::graph) fn serialize<T, S>(schemas: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Clone + Into<Value>,
+        S: Serializer,
+    {
+        let mut sequence = serializer.serialize_seq(Some(schemas.len()))?;
+        for schema in schemas {
+            sequence.serialize_element(&schema.clone().into())?;
+        }
+        sequence.end()
+    }
+
+    pub(in crate::graph) fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        T: TryFrom<Value>,
+        T::Error: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        Vec::<Value>::deserialize(deserializer)?
+            .into_iter()
+            .map(|schema| T::try_from(schema).map_err(de::Error::custom))
+            .collect()
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::uri::BaseUri;
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum GetPropertyError {
+    #[error("the entity does not have the property `{0}`")]
+    MissingProperty(BaseUri),
+    #[error("the value of the property `{base_uri}` cannot be decoded: `{reason}`")]
+    InvalidValue { base_uri: BaseUri, reason: String },
+}
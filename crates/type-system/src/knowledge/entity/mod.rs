@@ -0,0 +1,259 @@
+mod error;
+
+use std::{collections::HashMap, fmt};
+
+pub use error::GetPropertyError;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::{uri::BaseUri, EntityTypeReference};
+
+/// The identifier of an [`Entity`], which is assigned by the graph service storing it.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct EntityId(String);
+
+impl EntityId {
+    #[must_use]
+    pub const fn new(id: String) -> Self {
+        Self(id)
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for EntityId {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+/// An instance of an [`EntityType`].
+///
+/// The properties are keyed by the [`BaseUri`] of their [`PropertyType`], as in the `properties`
+/// of the [`EntityType`].
+///
+/// As for the `Entity` of the graph service, only the `entityId` is required: a missing
+/// `entityTypeId` is read as an entity of an unknown type, missing `properties` as no properties,
+/// and other fields set by the embedding application are ignored.
+///
+/// [`EntityType`]: crate::EntityType
+/// [`PropertyType`]: crate::PropertyType
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Entity {
+    entity_id: EntityId,
+    #[serde(
+        rename = "entityTypeId",
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "entity_type_id"
+    )]
+    entity_type: Option<EntityTypeReference>,
+    #[serde(default)]
+    properties: HashMap<BaseUri, serde_json::Value>,
+}
+
+impl Entity {
+    #[must_use]
+    pub const fn new(
+        entity_id: EntityId,
+        entity_type: EntityTypeReference,
+        properties: HashMap<BaseUri, serde_json::Value>,
+    ) -> Self {
+        Self {
+            entity_id,
+            entity_type: Some(entity_type),
+            properties,
+        }
+    }
+
+    #[must_use]
+    pub const fn entity_id(&self) -> &EntityId {
+        &self.entity_id
+    }
+
+    /// Returns the type of the entity, if it is known.
+    #[must_use]
+    pub const fn entity_type(&self) -> Option<&EntityTypeReference> {
+        self.entity_type.as_ref()
+    }
+
+    #[must_use]
+    pub const fn properties(&self) -> &HashMap<BaseUri, serde_json::Value> {
+        &self.properties
+    }
+
+    #[must_use]
+    pub fn properties_mut(&mut self) -> &mut HashMap<BaseUri, serde_json::Value> {
+        &mut self.properties
+    }
+
+    /// Decodes the value of the property identified by `base_uri`.
+    ///
+    /// A missing property is decoded as `null`, so optional properties can be read as
+    /// [`Option`].
+    ///
+    /// # Errors
+    ///
+    /// - [`MissingProperty`] if the entity does not have the property and `T` cannot be decoded
+    ///   from `null`
+    /// - [`InvalidValue`] if the value of the property cannot be decoded as `T`
+    ///
+    /// [`MissingProperty`]: GetPropertyError::MissingProperty
+    /// [`InvalidValue`]: GetPropertyError::InvalidValue
+    pub fn get<T: DeserializeOwned>(&self, base_uri: &BaseUri) -> Result<T, GetPropertyError> {
+        self.properties.get(base_uri).map_or_else(
+            || {
+                T::deserialize(serde_json::Value::Null)
+                    .map_err(|_| GetPropertyError::MissingProperty(base_uri.clone()))
+            },
+            |value| {
+                T::deserialize(value).map_err(|error| GetPropertyError::InvalidValue {
+                    base_uri: base_uri.clone(),
+                    reason: error.to_string(),
+                })
+            },
+        )
+    }
+}
+
+/// The graph service refers to the entity type by its URI instead of an [`EntityTypeReference`].
+mod entity_type_id {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{uri::VersionedUri, EntityTypeReference};
+
+    #[expect(
+        clippy::ref_option,
+        reason = "serde passes a reference to the field to `serialize_with`"
+    )]
+    pub(super) fn serialize<S>(
+        entity_type: &Option<EntityTypeReference>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        entity_type
+            .as_ref()
+            .map(EntityTypeReference::uri)
+            .serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D>(
+        deserializer: D,
+    ) -> Result<Option<EntityTypeReference>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<VersionedUri>::deserialize(deserializer)
+            .map(|uri| uri.map(EntityTypeReference::new))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::uri::VersionedUri;
+
+    fn base_uri(uri: &str) -> BaseUri {
+        BaseUri::new(uri.to_owned()).expect("invalid base URI")
+    }
+
+    fn book() -> serde_json::Value {
+        json!({
+            "entityId": "f0a0b0e3-ba7d-4d34-9bc8-7b1e1b4b58a4",
+            "entityTypeId": "https://blockprotocol.org/@alice/types/entity-type/book/v/1",
+            "properties": {
+                "https://blockprotocol.org/@alice/types/property-type/name/": ["The Hobbit"],
+                "https://blockprotocol.org/@alice/types/property-type/published-on/": 1937
+            }
+        })
+    }
+
+    #[test]
+    fn round_trip() {
+        let entity: Entity = serde_json::from_value(book()).expect("invalid entity");
+        assert_eq!(
+            entity.entity_id().as_str(),
+            "f0a0b0e3-ba7d-4d34-9bc8-7b1e1b4b58a4"
+        );
+        assert_eq!(
+            entity.entity_type().map(EntityTypeReference::uri),
+            Some(
+                &VersionedUri::from_str(
+                    "https://blockprotocol.org/@alice/types/entity-type/book/v/1"
+                )
+                .expect("invalid versioned URI")
+            )
+        );
+        assert_eq!(entity.properties().len(), 2);
+        assert_eq!(
+            serde_json::to_value(&entity).expect("could not serialize entity"),
+            book()
+        );
+    }
+
+    #[test]
+    fn optional_fields() {
+        let entity: Entity = serde_json::from_value(json!({
+            "entityId": "f0a0b0e3-ba7d-4d34-9bc8-7b1e1b4b58a4",
+            "accountId": "alice"
+        }))
+        .expect("invalid entity");
+        assert_eq!(entity.entity_type(), None);
+        assert!(entity.properties().is_empty());
+        assert_eq!(
+            serde_json::to_value(&entity).expect("could not serialize entity"),
+            json!({
+                "entityId": "f0a0b0e3-ba7d-4d34-9bc8-7b1e1b4b58a4",
+                "properties": {}
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_entity() {
+        let mut value = book();
+        value["entityTypeId"] = json!("https://blockprotocol.org/@alice/types/entity-type/book/");
+        assert!(serde_json::from_value::<Entity>(value).is_err());
+
+        let mut value = book();
+        value["properties"] = json!({ "name": "The Hobbit" });
+        assert!(serde_json::from_value::<Entity>(value).is_err());
+    }
+
+    #[test]
+    fn get() {
+        let entity: Entity = serde_json::from_value(book()).expect("invalid entity");
+        let name = base_uri("https://blockprotocol.org/@alice/types/property-type/name/");
+        let published_on =
+            base_uri("https://blockprotocol.org/@alice/types/property-type/published-on/");
+        let blurb = base_uri("https://blockprotocol.org/@alice/types/property-type/blurb/");
+
+        assert_eq!(
+            entity.get::<Vec<String>>(&name),
+            Ok(vec!["The Hobbit".to_owned()])
+        );
+        assert_eq!(entity.get::<u16>(&published_on), Ok(1937));
+        assert_eq!(entity.get::<Option<String>>(&blurb), Ok(None));
+        assert_eq!(
+            entity.get::<String>(&blurb),
+            Err(GetPropertyError::MissingProperty(blurb))
+        );
+        assert!(matches!(
+            entity.get::<String>(&published_on),
+            Err(GetPropertyError::InvalidValue { base_uri, .. }) if base_uri == published_on
+        ));
+    }
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::{uri::VersionedUri, EntityId};
+
+/// An instance of a [`LinkType`] from one [`Entity`] to another.
+///
+/// Links of an ordered array of links have an index, which is their position in the array.
+///
+/// [`LinkType`]: crate::LinkType
+/// [`Entity`]: crate::Entity
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Link {
+    source_entity_id: EntityId,
+    destination_entity_id: EntityId,
+    #[serde(rename = "linkTypeId")]
+    link_type: VersionedUri,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    index: Option<usize>,
+}
+
+impl Link {
+    #[must_use]
+    pub const fn new(
+        source_entity_id: EntityId,
+        destination_entity_id: EntityId,
+        link_type: VersionedUri,
+    ) -> Self {
+        Self {
+            source_entity_id,
+            destination_entity_id,
+            link_type,
+            index: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    #[must_use]
+    pub const fn source_entity_id(&self) -> &EntityId {
+        &self.source_entity_id
+    }
+
+    #[must_use]
+    pub const fn destination_entity_id(&self) -> &EntityId {
+        &self.destination_entity_id
+    }
+
+    #[must_use]
+    pub const fn link_type(&self) -> &VersionedUri {
+        &self.link_type
+    }
+
+    #[must_use]
+    pub const fn index(&self) -> Option<usize> {
+        self.index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let link = Link::new(
+            EntityId::new("book".to_owned()),
+            EntityId::new("alice".to_owned()),
+            VersionedUri::from_str(
+                "https://blockprotocol.org/@alice/types/link-type/written-by/v/1",
+            )
+            .expect("invalid versioned URI"),
+        );
+        let value = json!({
+            "sourceEntityId": "book",
+            "destinationEntityId": "alice",
+            "linkTypeId": "https://blockprotocol.org/@alice/types/link-type/written-by/v/1"
+        });
+        assert_eq!(
+            serde_json::to_value(&link).expect("could not serialize link"),
+            value
+        );
+        assert_eq!(
+            serde_json::from_value::<Link>(value).expect("invalid link"),
+            link
+        );
+
+        let ordered = link.with_index(2);
+        let value = serde_json::to_value(&ordered).expect("could not serialize link");
+        assert_eq!(value["index"], json!(2));
+        assert_eq!(
+            serde_json::from_value::<Link>(value).expect("invalid link"),
+            ordered
+        );
+    }
+
+    #[test]
+    fn null_index() {
+        let link: Link = serde_json::from_value(json!({
+            "sourceEntityId": "book",
+            "destinationEntityId": "alice",
+            "linkTypeId": "https://blockprotocol.org/@alice/types/link-type/written-by/v/1",
+            "index": null
+        }))
+        .expect("invalid link");
+        assert_eq!(link.index(), None);
+    }
+}
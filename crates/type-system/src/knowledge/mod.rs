@@ -0,0 +1,21 @@
+//! Definitions of the data described by the Type System.
+//!
+//! While the [`ontology`] defines the shape of data, this module contains the data itself:
+//! [`Entity`]s, which are instances of [`EntityType`]s, and [`Link`]s between them, which are
+//! instances of [`LinkType`]s.
+//!
+//! An [`Entity`] is read from and written as the `Entity` of the graph service of the Block
+//! Protocol. A [`Link`] is not the `Link` of the graph service, which identifies the kind of link
+//! by the `path` of the property it is stored in instead of by its [`LinkType`], see
+//! [`graph::Link`] for the latter.
+//!
+//! [`graph::Link`]: crate::graph::Link
+//! [`ontology`]: crate::ontology
+//! [`EntityType`]: crate::EntityType
+//! [`LinkType`]: crate::LinkType
+
+mod entity;
+mod link;
+
+pub use entity::{Entity, EntityId, GetPropertyError};
+pub use link::Link;
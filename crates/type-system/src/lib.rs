@@ -45,9 +45,12 @@
     reason = "This encourages importing `as` which breaks IDEs"
 )]
 
+pub mod graph;
+mod knowledge;
 mod ontology;
 mod utils;
 
+pub use knowledge::*;
 pub use ontology::*;
 
 #[cfg(test)]
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::{
+    uri::VersionedUri, ParseDataTypeError, ParseEntityTypeError, ParseLinkTypeError,
+    ParsePropertyTypeError,
+};
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum ParseTypeBundleError {
+    #[error("invalid data type: `{0}`")]
+    InvalidDataType(ParseDataTypeError),
+    #[error("invalid property type: `{0}`")]
+    InvalidPropertyType(ParsePropertyTypeError),
+    #[error("invalid entity type: `{0}`")]
+    InvalidEntityType(ParseEntityTypeError),
+    #[error("invalid link type: `{0}`")]
+    InvalidLinkType(ParseLinkTypeError),
+    #[error("`{0}` is contained more than once")]
+    DuplicateType(VersionedUri),
+    #[error("error in JSON: `{0}`")]
+    InvalidJson(String),
+    #[error("error in YAML: `{0}`")]
+    InvalidYaml(String),
+    #[error("error in TOML: `{0}`")]
+    InvalidToml(String),
+}
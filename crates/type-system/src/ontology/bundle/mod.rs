@@ -0,0 +1,525 @@
+mod error;
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use serde::Deserialize;
+
+pub use self::error::ParseTypeBundleError;
+#[cfg(any(feature = "yaml", feature = "toml"))]
+use crate::format;
+#[cfg(feature = "toml")]
+use crate::format::SerializeTomlError;
+use crate::{
+    repr,
+    uri::{BaseUri, VersionedUri},
+    DataType, EntityType, LinkType, PropertyType, PropertyTypeReference, PropertyValues,
+    ValidationError, ValueOrArray, ValueOrMaybeOrderedArray,
+};
+
+/// A collection of types of the Type System, indexed by their [`VersionedUri`].
+///
+/// A bundle is not required to be self-contained, i.e. the types inside of a bundle may refer to
+/// types which are not part of the bundle.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TypeBundle {
+    data_types: HashMap<VersionedUri, DataType>,
+    property_types: HashMap<VersionedUri, PropertyType>,
+    entity_types: HashMap<VersionedUri, EntityType>,
+    link_types: HashMap<VersionedUri, LinkType>,
+}
+
+impl TypeBundle {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a [`DataType`] into the bundle.
+    ///
+    /// If the bundle already contained a [`DataType`] with the same [`VersionedUri`], it's
+    /// replaced and returned.
+    pub fn insert_data_type(&mut self, data_type: DataType) -> Option<DataType> {
+        self.data_types.insert(data_type.id().clone(), data_type)
+    }
+
+    /// Inserts a [`PropertyType`] into the bundle.
+    ///
+    /// If the bundle already contained a [`PropertyType`] with the same [`VersionedUri`], it's
+    /// replaced and returned.
+    pub fn insert_property_type(&mut self, property_type: PropertyType) -> Option<PropertyType> {
+        self.property_types
+            .insert(property_type.id().clone(), property_type)
+    }
+
+    /// Inserts an [`EntityType`] into the bundle.
+    ///
+    /// If the bundle already contained an [`EntityType`] with the same [`VersionedUri`], it's
+    /// replaced and returned.
+    pub fn insert_entity_type(&mut self, entity_type: EntityType) -> Option<EntityType> {
+        self.entity_types
+            .insert(entity_type.id().clone(), entity_type)
+    }
+
+    /// Inserts a [`LinkType`] into the bundle.
+    ///
+    /// If the bundle already contained a [`LinkType`] with the same [`VersionedUri`], it's
+    /// replaced and returned.
+    pub fn insert_link_type(&mut self, link_type: LinkType) -> Option<LinkType> {
+        self.link_types.insert(link_type.id().clone(), link_type)
+    }
+
+    #[must_use]
+    pub fn data_type(&self, uri: &VersionedUri) -> Option<&DataType> {
+        self.data_types.get(uri)
+    }
+
+    #[must_use]
+    pub fn property_type(&self, uri: &VersionedUri) -> Option<&PropertyType> {
+        self.property_types.get(uri)
+    }
+
+    #[must_use]
+    pub fn entity_type(&self, uri: &VersionedUri) -> Option<&EntityType> {
+        self.entity_types.get(uri)
+    }
+
+    #[must_use]
+    pub fn link_type(&self, uri: &VersionedUri) -> Option<&LinkType> {
+        self.link_types.get(uri)
+    }
+
+    pub fn data_types(&self) -> impl Iterator<Item = &DataType> {
+        self.data_types.values()
+    }
+
+    pub fn property_types(&self) -> impl Iterator<Item = &PropertyType> {
+        self.property_types.values()
+    }
+
+    pub fn entity_types(&self) -> impl Iterator<Item = &EntityType> {
+        self.entity_types.values()
+    }
+
+    pub fn link_types(&self) -> impl Iterator<Item = &LinkType> {
+        self.link_types.values()
+    }
+
+    /// Returns `true` if the bundle contains a type of any kind with the given [`VersionedUri`].
+    #[must_use]
+    pub fn contains(&self, uri: &VersionedUri) -> bool {
+        self.data_types.contains_key(uri)
+            || self.property_types.contains_key(uri)
+            || self.entity_types.contains_key(uri)
+            || self.link_types.contains_key(uri)
+    }
+
+    /// Returns the total number of types in the bundle.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data_types.len()
+            + self.property_types.len()
+            + self.entity_types.len()
+            + self.link_types.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Checks that every type inside of the bundle can have valid instances.
+    ///
+    /// A type cannot have any valid instances if it has contradicting constraints, e.g. an array
+    /// requiring more items than it allows, or if it requires a property or link whose type cannot
+    /// have any valid instances. Types which are not part of the bundle are assumed to have valid
+    /// instances.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::Unsatisfiable`] listing the types without valid instances, sorted by
+    ///   their [`VersionedUri`]
+    pub fn check_satisfiability(&self) -> Result<(), ValidationError> {
+        let mut unsatisfiable = HashSet::new();
+
+        // Marking a type as unsatisfiable may render the types requiring it unsatisfiable as well,
+        // so this is repeated until no further types are marked.
+        loop {
+            let marked = unsatisfiable.len();
+            for property_type in self.property_types.values() {
+                if !property_values_satisfiable(property_type.one_of(), &unsatisfiable) {
+                    unsatisfiable.insert(property_type.id());
+                }
+            }
+            for entity_type in self.entity_types.values() {
+                if !entity_type_satisfiable(entity_type, &unsatisfiable) {
+                    unsatisfiable.insert(entity_type.id());
+                }
+            }
+            if unsatisfiable.len() == marked {
+                break;
+            }
+        }
+
+        if unsatisfiable.is_empty() {
+            Ok(())
+        } else {
+            let mut uris: Vec<_> = unsatisfiable.into_iter().cloned().collect();
+            uris.sort_by_cached_key(ToString::to_string);
+            Err(ValidationError::Unsatisfiable(uris))
+        }
+    }
+}
+
+fn property_values_satisfiable(
+    values: &[PropertyValues],
+    unsatisfiable: &HashSet<&VersionedUri>,
+) -> bool {
+    values.iter().any(|value| match value {
+        PropertyValues::DataTypeReference(reference) => !unsatisfiable.contains(reference.uri()),
+        PropertyValues::PropertyTypeObject(object) => {
+            properties_satisfiable(object.properties(), object.required(), unsatisfiable)
+        }
+        PropertyValues::ArrayOfPropertyValues(array) => array.is_satisfiable(
+            property_values_satisfiable(array.items().one_of(), unsatisfiable),
+        ),
+    })
+}
+
+/// Properties which are not required can always be omitted, so only required properties are
+/// checked.
+fn properties_satisfiable(
+    properties: &HashMap<BaseUri, ValueOrArray<PropertyTypeReference>>,
+    required: &[BaseUri],
+    unsatisfiable: &HashSet<&VersionedUri>,
+) -> bool {
+    required
+        .iter()
+        .all(|base_uri| match properties.get(base_uri) {
+            Some(ValueOrArray::Value(reference)) => !unsatisfiable.contains(reference.uri()),
+            Some(ValueOrArray::Array(array)) => {
+                array.is_satisfiable(!unsatisfiable.contains(array.items().uri()))
+            }
+            None => false,
+        })
+}
+
+fn entity_type_satisfiable(
+    entity_type: &EntityType,
+    unsatisfiable: &HashSet<&VersionedUri>,
+) -> bool {
+    let properties_satisfied = properties_satisfiable(
+        entity_type.properties(),
+        entity_type.required(),
+        unsatisfiable,
+    );
+
+    // A required link needs at least one entity it links to
+    let links_satisfied = entity_type.required_links().iter().all(|link_type| {
+        entity_type.links().get(link_type).map_or(false, |link| {
+            let target_satisfiable = !unsatisfiable.contains(link.inner().uri());
+            match link {
+                ValueOrMaybeOrderedArray::Value(_) => target_satisfiable,
+                ValueOrMaybeOrderedArray::Array(array) => {
+                    target_satisfiable
+                        && array.array().max_items() != Some(0)
+                        && array.array().is_satisfiable(target_satisfiable)
+                }
+            }
+        })
+    });
+
+    properties_satisfied && links_satisfied
+}
+
+impl From<TypeBundle> for serde_json::Value {
+    /// Serializes the bundle as an object containing the types of each kind sorted by their
+    /// `$id`, e.g. `{ "dataTypes": [...], "propertyTypes": [...], ... }`.
+    fn from(bundle: TypeBundle) -> Self {
+        fn sorted<T: Into<serde_json::Value>>(
+            types: HashMap<VersionedUri, T>,
+        ) -> serde_json::Value {
+            let mut types: Vec<_> = types.into_iter().collect();
+            types.sort_by_cached_key(|(uri, _)| uri.to_string());
+            types.into_iter().map(|(_, value)| value.into()).collect()
+        }
+
+        serde_json::json!({
+            "dataTypes": sorted(bundle.data_types),
+            "propertyTypes": sorted(bundle.property_types),
+            "entityTypes": sorted(bundle.entity_types),
+            "linkTypes": sorted(bundle.link_types),
+        })
+    }
+}
+
+/// The representation of a [`TypeBundle`] as created by `From<TypeBundle> for serde_json::Value`.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct TypeBundleRepr {
+    #[serde(default)]
+    data_types: Vec<repr::DataType>,
+    #[serde(default)]
+    property_types: Vec<repr::PropertyType>,
+    #[serde(default)]
+    entity_types: Vec<repr::EntityType>,
+    #[serde(default)]
+    link_types: Vec<repr::LinkType>,
+}
+
+impl TryFrom<TypeBundleRepr> for TypeBundle {
+    type Error = ParseTypeBundleError;
+
+    fn try_from(bundle_repr: TypeBundleRepr) -> Result<Self, Self::Error> {
+        let mut bundle = Self::new();
+        for data_type in bundle_repr.data_types {
+            let data_type =
+                DataType::try_from(data_type).map_err(ParseTypeBundleError::InvalidDataType)?;
+            if bundle.contains(data_type.id()) {
+                return Err(ParseTypeBundleError::DuplicateType(data_type.id().clone()));
+            }
+            bundle.insert_data_type(data_type);
+        }
+        for property_type in bundle_repr.property_types {
+            let property_type = PropertyType::try_from(property_type)
+                .map_err(ParseTypeBundleError::InvalidPropertyType)?;
+            if bundle.contains(property_type.id()) {
+                return Err(ParseTypeBundleError::DuplicateType(
+                    property_type.id().clone(),
+                ));
+            }
+            bundle.insert_property_type(property_type);
+        }
+        for entity_type in bundle_repr.entity_types {
+            let entity_type = EntityType::try_from(entity_type)
+                .map_err(ParseTypeBundleError::InvalidEntityType)?;
+            if bundle.contains(entity_type.id()) {
+                return Err(ParseTypeBundleError::DuplicateType(
+                    entity_type.id().clone(),
+                ));
+            }
+            bundle.insert_entity_type(entity_type);
+        }
+        for link_type in bundle_repr.link_types {
+            let link_type =
+                LinkType::try_from(link_type).map_err(ParseTypeBundleError::InvalidLinkType)?;
+            if bundle.contains(link_type.id()) {
+                return Err(ParseTypeBundleError::DuplicateType(link_type.id().clone()));
+            }
+            bundle.insert_link_type(link_type);
+        }
+        Ok(bundle)
+    }
+}
+
+impl FromStr for TypeBundle {
+    type Err = ParseTypeBundleError;
+
+    fn from_str(bundle_str: &str) -> Result<Self, Self::Err> {
+        let bundle_repr: TypeBundleRepr = serde_json::from_str(bundle_str)
+            .map_err(|err| ParseTypeBundleError::InvalidJson(err.to_string()))?;
+
+        Self::try_from(bundle_repr)
+    }
+}
+
+impl TryFrom<serde_json::Value> for TypeBundle {
+    type Error = ParseTypeBundleError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        let bundle_repr: TypeBundleRepr = serde_json::from_value(value)
+            .map_err(|err| ParseTypeBundleError::InvalidJson(err.to_string()))?;
+
+        Self::try_from(bundle_repr)
+    }
+}
+
+#[cfg(any(feature = "yaml", feature = "toml"))]
+impl TypeBundle {
+    /// Parses a bundle from its YAML representation.
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseTypeBundleError::InvalidYaml`] if `bundle_yaml` is not a valid YAML representation
+    ///   of a bundle
+    /// - the same errors as parsing the JSON representation otherwise
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(bundle_yaml: &str) -> Result<Self, ParseTypeBundleError> {
+        let bundle_repr: TypeBundleRepr = serde_yaml::from_str(bundle_yaml)
+            .map_err(|err| ParseTypeBundleError::InvalidYaml(err.to_string()))?;
+
+        Self::try_from(bundle_repr)
+    }
+
+    /// Serializes the bundle as YAML.
+    #[cfg(feature = "yaml")]
+    #[must_use]
+    pub fn to_yaml(&self) -> String {
+        format::to_yaml(&self.clone().into())
+    }
+
+    /// Parses a bundle from its TOML representation.
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseTypeBundleError::InvalidToml`] if `bundle_toml` is not a valid TOML representation
+    ///   of a bundle
+    /// - the same errors as parsing the JSON representation otherwise
+    #[cfg(feature = "toml")]
+    pub fn from_toml(bundle_toml: &str) -> Result<Self, ParseTypeBundleError> {
+        let bundle_repr: TypeBundleRepr = toml::from_str(bundle_toml)
+            .map_err(|err| ParseTypeBundleError::InvalidToml(err.to_string()))?;
+
+        Self::try_from(bundle_repr)
+    }
+
+    /// Serializes the bundle as TOML.
+    ///
+    /// # Errors
+    ///
+    /// - [`SerializeTomlError`] if a type of the bundle contains a value which cannot be
+    ///   represented in TOML, e.g. `null`
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> Result<String, SerializeTomlError> {
+        format::to_toml(&self.clone().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{test_data, Array, DataTypeReference, OneOf};
+
+    #[test]
+    fn insert_and_lookup() {
+        let mut bundle = TypeBundle::new();
+        assert!(bundle.is_empty());
+
+        let text = DataType::from_str(test_data::data_type::TEXT_V1).expect("invalid data type");
+        let book =
+            EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid entity type");
+
+        assert_eq!(bundle.insert_data_type(text.clone()), None);
+        assert_eq!(bundle.insert_entity_type(book.clone()), None);
+        assert_eq!(bundle.insert_data_type(text.clone()), Some(text.clone()));
+
+        assert_eq!(bundle.len(), 2);
+        assert_eq!(bundle.data_type(text.id()), Some(&text));
+        assert_eq!(bundle.entity_type(book.id()), Some(&book));
+        assert_eq!(bundle.property_type(book.id()), None);
+        assert!(bundle.contains(book.id()));
+    }
+
+    #[test]
+    fn serialization() {
+        let mut bundle = TypeBundle::new();
+        let text = DataType::from_str(test_data::data_type::TEXT_V1).expect("invalid data type");
+        let number =
+            DataType::from_str(test_data::data_type::NUMBER_V1).expect("invalid data type");
+        bundle.insert_data_type(text.clone());
+        bundle.insert_data_type(number.clone());
+
+        assert_eq!(
+            serde_json::Value::from(bundle),
+            serde_json::json!({
+                "dataTypes": [serde_json::Value::from(number), serde_json::Value::from(text)],
+                "propertyTypes": [],
+                "entityTypes": [],
+                "linkTypes": [],
+            })
+        );
+    }
+
+    #[test]
+    fn satisfiability() {
+        let mut bundle = TypeBundle::new();
+        for property_type in [
+            test_data::property_type::NAME_V1,
+            test_data::property_type::INTERESTS_V1,
+        ] {
+            bundle.insert_property_type(
+                PropertyType::from_str(property_type).expect("invalid property type"),
+            );
+        }
+        bundle.insert_entity_type(
+            EntityType::from_str(test_data::entity_type::PLAYLIST_V1).expect("invalid type"),
+        );
+        assert_eq!(bundle.check_satisfiability(), Ok(()));
+
+        // Impossible bounds are rejected when parsing, so the type is constructed without
+        // validation
+        let name = PropertyType::from_str(test_data::property_type::NAME_V1).expect("invalid type");
+        let text = DataTypeReference::new(
+            VersionedUri::from_str(
+                "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1",
+            )
+            .expect("invalid URI"),
+        );
+        bundle.insert_property_type(PropertyType::new(
+            name.id().clone(),
+            name.title().to_owned(),
+            name.plural_title().to_owned(),
+            None,
+            OneOf::new([PropertyValues::ArrayOfPropertyValues(Array::new_unchecked(
+                OneOf::new([PropertyValues::DataTypeReference(text)]).expect("invalid oneOf"),
+                Some(2),
+                Some(1),
+            ))])
+            .expect("invalid oneOf"),
+        ));
+        // Playlists only optionally have a name, so they are not affected
+        assert_eq!(
+            bundle.check_satisfiability(),
+            Err(ValidationError::Unsatisfiable(vec![name.id().clone()]))
+        );
+
+        let name_uri = name.id().to_string();
+        let name_base_uri = name.id().base_uri().to_string();
+        let playlist_uri = "https://blockprotocol.org/@alice/types/entity-type/playlist/v/1";
+        let named_playlist = EntityType::try_from(serde_json::json!({
+            "kind": "entityType",
+            "$id": "https://blockprotocol.org/@alice/types/entity-type/named-playlist/v/1",
+            "type": "object",
+            "title": "Named Playlist",
+            "pluralTitle": "Named Playlists",
+            "properties": { &name_base_uri: { "$ref": name_uri } },
+            "required": [name_base_uri]
+        }))
+        .expect("invalid entity type");
+        let collection = EntityType::try_from(serde_json::json!({
+            "kind": "entityType",
+            "$id": "https://blockprotocol.org/@alice/types/entity-type/collection/v/1",
+            "type": "object",
+            "title": "Collection",
+            "pluralTitle": "Collections",
+            "properties": {},
+            "links": {
+                "https://blockprotocol.org/@alice/types/link-type/contains/v/1": {
+                    "type": "array",
+                    "items": { "$ref": named_playlist.id().to_string() },
+                    "ordered": false
+                },
+                "https://blockprotocol.org/@alice/types/link-type/refers-to/v/1": {
+                    "$ref": playlist_uri
+                }
+            },
+            "requiredLinks": ["https://blockprotocol.org/@alice/types/link-type/contains/v/1"]
+        }))
+        .expect("invalid entity type");
+        bundle.insert_entity_type(named_playlist.clone());
+        bundle.insert_entity_type(collection.clone());
+
+        assert_eq!(
+            bundle.check_satisfiability(),
+            Err(ValidationError::Unsatisfiable(vec![
+                collection.id().clone(),
+                named_playlist.id().clone(),
+                name.id().clone(),
+            ]))
+        );
+    }
+}
@@ -0,0 +1,413 @@
+//! Canonical serialization and content hashing of types of the Type System.
+//!
+//! A published version of a type is immutable, so two types with the same [`VersionedUri`] are
+//! required to have the same content. The canonical JSON form of a type is independent of how
+//! the type was written down:
+//!
+//! - object keys are sorted, which includes the keys of `properties`, `links`, `default` and any
+//!   other map inside the type,
+//! - the order of arrays is preserved, in particular the order of `oneOf`,
+//! - optional fields are normalized by round-tripping through the native representation, e.g. an
+//!   empty `required` list is omitted and `ordered` is always present on link arrays,
+//! - numbers are written like ECMAScript does, so `10` and `10.0` are the same number,
+//! - no insignificant whitespace is emitted.
+//!
+//! This is the JSON Canonicalization Scheme of RFC 8785 applied to the normalized type.
+//!
+//! The [`ContentHash`] of a type is the SHA-256 digest of its canonical JSON form.
+//!
+//! [`VersionedUri`]: crate::uri::VersionedUri
+
+use std::{cmp::Ordering, fmt, fmt::Write, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// Returns the canonical JSON serialization of the given type.
+#[must_use]
+pub fn to_canonical_json<T>(ontology_type: &T) -> String
+where
+    T: Clone + Into<serde_json::Value>,
+{
+    let mut canonical = String::new();
+    write_canonical_value(&mut canonical, &ontology_type.clone().into());
+    canonical
+}
+
+/// Returns the [`ContentHash`] of the given type.
+#[must_use]
+pub fn content_hash<T>(ontology_type: &T) -> ContentHash
+where
+    T: Clone + Into<serde_json::Value>,
+{
+    ContentHash(Sha256::digest(to_canonical_json(ontology_type).as_bytes()).into())
+}
+
+fn write_canonical_value(output: &mut String, value: &serde_json::Value) {
+    match value {
+        serde_json::Value::Array(values) => {
+            output.push('[');
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                write_canonical_value(output, value);
+            }
+            output.push(']');
+        }
+        serde_json::Value::Object(object) => {
+            // Keys are compared by their UTF-16 code units as required by RFC 8785
+            let mut entries = object.iter().collect::<Vec<_>>();
+            entries.sort_by(|(lhs, _), (rhs, _)| compare_keys(lhs, rhs));
+
+            output.push('{');
+            for (index, (key, value)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                output.push_str(
+                    &serde_json::to_string(key).expect("failed to serialize a JSON string"),
+                );
+                output.push(':');
+                write_canonical_value(output, value);
+            }
+            output.push('}');
+        }
+        serde_json::Value::Number(number) => write_canonical_number(
+            output,
+            number
+                .as_f64()
+                .expect("JSON numbers are representable as `f64`"),
+        ),
+        scalar => output
+            .push_str(&serde_json::to_string(scalar).expect("failed to serialize a JSON scalar")),
+    }
+}
+
+/// Writes `number` in the format of ECMAScript's `Number.prototype.toString` as required by
+/// RFC 8785, so `10`, `10.0` and `1e1` are all written as `10`.
+fn write_canonical_number(output: &mut String, number: f64) {
+    if number == 0.0 {
+        // This also covers `-0`, which is written as `0`
+        output.push('0');
+        return;
+    }
+    if number.is_sign_negative() {
+        output.push('-');
+    }
+
+    // `LowerExp` emits the shortest digits which round-trip, e.g. `1.5e-7`
+    let scientific = format!("{:e}", number.abs());
+    let (mantissa, exponent) = scientific
+        .split_once('e')
+        .expect("scientific notation contains an exponent");
+    let digits = mantissa.replace('.', "");
+    let exponent = exponent
+        .parse::<i32>()
+        .expect("exponent of scientific notation is an integer");
+    let length = i32::try_from(digits.len()).expect("number has too many digits");
+    // The position of the decimal point relative to the start of `digits`
+    let point = exponent + 1;
+
+    if length <= point && point <= 21 {
+        output.push_str(&digits);
+        output.extend((length..point).map(|_| '0'));
+    } else if 0 < point && point <= 21 {
+        let (integer, fraction) = digits.split_at(point.unsigned_abs() as usize);
+        output.push_str(integer);
+        output.push('.');
+        output.push_str(fraction);
+    } else if -6 < point && point <= 0 {
+        output.push_str("0.");
+        output.extend((point..0).map(|_| '0'));
+        output.push_str(&digits);
+    } else {
+        let (first, rest) = digits.split_at(1);
+        output.push_str(first);
+        if !rest.is_empty() {
+            output.push('.');
+            output.push_str(rest);
+        }
+        let sign = if exponent < 0 { '-' } else { '+' };
+        let _ = write!(output, "e{sign}{}", exponent.unsigned_abs());
+    }
+}
+
+fn compare_keys(lhs: &str, rhs: &str) -> Ordering {
+    lhs.encode_utf16().cmp(rhs.encode_utf16())
+}
+
+/// The SHA-256 digest of the canonical JSON form of a type.
+///
+/// The hash is displayed and serialized as a lowercase hexadecimal string.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ContentHash([u8; 32]);
+
+impl ContentHash {
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for ContentHash {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, fmt)
+    }
+}
+
+impl fmt::Display for ContentHash {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(fmt, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum ParseContentHashError {
+    #[error("expected 64 hexadecimal characters, found {0}")]
+    InvalidLength(usize),
+    #[error("invalid hexadecimal character: `{0}`")]
+    InvalidCharacter(char),
+}
+
+impl FromStr for ContentHash {
+    type Err = ParseContentHashError;
+
+    fn from_str(hash: &str) -> Result<Self, Self::Err> {
+        let digits = hash
+            .chars()
+            .map(|character| {
+                character
+                    .to_digit(16)
+                    .ok_or(ParseContentHashError::InvalidCharacter(character))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if digits.len() != 64 {
+            return Err(ParseContentHashError::InvalidLength(digits.len()));
+        }
+
+        let mut bytes = [0; 32];
+        for (byte, pair) in bytes.iter_mut().zip(digits.chunks_exact(2)) {
+            // Both digits are less than 16, so the result always fits into a byte
+            *byte = u8::try_from(pair[0] << 4 | pair[1]).expect("hex digit out of range");
+        }
+
+        Ok(Self(bytes))
+    }
+}
+
+impl Serialize for ContentHash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_string().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ContentHash {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{test_data, DataType, EntityType, LinkType, PropertyType};
+
+    fn entity_type_from_value(value: serde_json::Value) -> EntityType {
+        EntityType::try_from(value).expect("invalid entity type")
+    }
+
+    #[test]
+    fn sorted_keys() {
+        let link_type: LinkType = test_data::link_type::OWNS_V2
+            .parse()
+            .expect("invalid link type");
+
+        assert_eq!(
+            to_canonical_json(&link_type),
+            r#"{"$id":"https://blockprotocol.org/@alice/types/link-type/owns/v/2","description":"Have (something) as one's own; possess","kind":"linkType","pluralTitle":"Owns","relatedKeywords":["has","have","possess","own"],"title":"Owns"}"#
+        );
+    }
+
+    #[test]
+    fn map_order_is_irrelevant() {
+        let lhs = entity_type_from_value(json!({
+            "kind": "entityType",
+            "$id": "https://example.com/entity-type/v/1",
+            "type": "object",
+            "title": "Entity",
+            "pluralTitle": "Entities",
+            "properties": {
+                "https://example.com/property-type-a/": { "$ref": "https://example.com/property-type-a/v/1" },
+                "https://example.com/property-type-b/": { "$ref": "https://example.com/property-type-b/v/1" }
+            },
+            "required": []
+        }));
+        let rhs = entity_type_from_value(json!({
+            "properties": {
+                "https://example.com/property-type-b/": { "$ref": "https://example.com/property-type-b/v/1" },
+                "https://example.com/property-type-a/": { "$ref": "https://example.com/property-type-a/v/1" }
+            },
+            "pluralTitle": "Entities",
+            "title": "Entity",
+            "type": "object",
+            "$id": "https://example.com/entity-type/v/1",
+            "kind": "entityType"
+        }));
+
+        assert_eq!(to_canonical_json(&lhs), to_canonical_json(&rhs));
+        assert_eq!(content_hash(&lhs), content_hash(&rhs));
+    }
+
+    #[test]
+    fn optional_fields_are_normalized() {
+        let entity_type = |ordered: serde_json::Value| {
+            let mut array = json!({
+                "type": "array",
+                "items": { "$ref": "https://example.com/entity-type/v/1" }
+            });
+            if !ordered.is_null() {
+                array["ordered"] = ordered;
+            }
+
+            entity_type_from_value(json!({
+                "kind": "entityType",
+                "$id": "https://example.com/entity-type/v/1",
+                "type": "object",
+                "title": "Entity",
+                "pluralTitle": "Entities",
+                "properties": {},
+                "links": {
+                    "https://example.com/link-type/v/1": array
+                }
+            }))
+        };
+
+        assert_eq!(
+            content_hash(&entity_type(serde_json::Value::Null)),
+            content_hash(&entity_type(json!(false)))
+        );
+        assert_ne!(
+            content_hash(&entity_type(json!(false))),
+            content_hash(&entity_type(json!(true)))
+        );
+    }
+
+    #[test]
+    fn one_of_order_is_preserved() {
+        let property_type = |one_of: serde_json::Value| {
+            PropertyType::try_from(json!({
+                "kind": "propertyType",
+                "$id": "https://example.com/property-type/v/1",
+                "title": "User ID",
+                "pluralTitle": "User IDs",
+                "oneOf": one_of
+            }))
+            .expect("invalid property type")
+        };
+        let text =
+            json!({ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" });
+        let number = json!({ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1" });
+
+        assert_ne!(
+            content_hash(&property_type(json!([text, number]))),
+            content_hash(&property_type(json!([number, text])))
+        );
+    }
+
+    #[test]
+    fn content_changes_hash() {
+        let data_type: DataType = test_data::data_type::TEXT_V1
+            .parse()
+            .expect("invalid data type");
+        let mut changed = data_type.clone();
+        changed
+            .additional_properties_mut()
+            .insert("maxLength".to_owned(), json!(10));
+
+        assert_ne!(content_hash(&data_type), content_hash(&changed));
+    }
+
+    #[test]
+    fn numbers() {
+        let canonical = |number: serde_json::Value| {
+            let mut output = String::new();
+            write_canonical_value(&mut output, &number);
+            output
+        };
+
+        assert_eq!(canonical(json!(10)), "10");
+        assert_eq!(canonical(json!(10.0)), "10");
+        assert_eq!(canonical(json!(-0.0)), "0");
+        assert_eq!(canonical(json!(-1.5)), "-1.5");
+        assert_eq!(canonical(json!(0.000_001)), "0.000001");
+        assert_eq!(canonical(json!(0.000_000_1)), "1e-7");
+        assert_eq!(canonical(json!(123_456.789)), "123456.789");
+        assert_eq!(canonical(json!(1e20)), "100000000000000000000");
+        assert_eq!(canonical(json!(1e21)), "1e+21");
+        assert_eq!(canonical(json!(1.25e30)), "1.25e+30");
+        assert_eq!(canonical(json!(u64::MAX)), "18446744073709552000");
+    }
+
+    #[test]
+    fn integral_floats_are_normalized() {
+        let data_type: DataType = test_data::data_type::TEXT_V1
+            .parse()
+            .expect("invalid data type");
+        let with_max_length = |max_length: serde_json::Value| {
+            let mut data_type = data_type.clone();
+            data_type
+                .additional_properties_mut()
+                .insert("maxLength".to_owned(), max_length);
+            data_type
+        };
+
+        assert_eq!(
+            content_hash(&with_max_length(json!(10))),
+            content_hash(&with_max_length(json!(10.0)))
+        );
+    }
+
+    #[test]
+    fn hash_serialization() {
+        let data_type: DataType = test_data::data_type::TEXT_V1
+            .parse()
+            .expect("invalid data type");
+        let hash = content_hash(&data_type);
+
+        let serialized = serde_json::to_value(hash).expect("failed to serialize hash");
+        assert_eq!(serialized.as_str().map(str::len), Some(64));
+        assert_eq!(
+            serde_json::from_value::<ContentHash>(serialized).expect("failed to deserialize hash"),
+            hash
+        );
+    }
+
+    #[test]
+    fn invalid_hash() {
+        assert_eq!(
+            ContentHash::from_str("abc"),
+            Err(ParseContentHashError::InvalidLength(3))
+        );
+        assert_eq!(
+            ContentHash::from_str(&"g".repeat(64)),
+            Err(ParseContentHashError::InvalidCharacter('g'))
+        );
+    }
+}
@@ -7,9 +7,13 @@ use std::{collections::HashMap, str::FromStr};
 
 pub use error::ParseDataTypeError;
 
+#[cfg(any(feature = "yaml", feature = "toml"))]
+use crate::format;
+#[cfg(feature = "toml")]
+use crate::format::SerializeTomlError;
 use crate::{
     uri::{BaseUri, ParseVersionedUriError, VersionedUri},
-    ValidateUri, ValidationError,
+    Compatibility, ValidateUri, ValidationError,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -74,6 +78,16 @@ impl DataType {
     }
 }
 
+impl Compatibility for DataType {
+    /// Data types currently represent disjoint value spaces, so a data type is only compatible with
+    /// another version of itself which has the same constraints.
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        self.id.base_uri() == other.id.base_uri()
+            && self.json_type == other.json_type
+            && self.additional_properties == other.additional_properties
+    }
+}
+
 impl FromStr for DataType {
     type Err = ParseDataTypeError;
 
@@ -85,6 +99,57 @@ impl FromStr for DataType {
     }
 }
 
+#[cfg(any(feature = "yaml", feature = "toml"))]
+impl DataType {
+    /// Parses a data type from its YAML representation.
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseDataTypeError::InvalidYaml`] if `data_type_yaml` is not a valid YAML representation
+    ///   of a data type
+    /// - the same errors as parsing the JSON representation otherwise
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(data_type_yaml: &str) -> Result<Self, ParseDataTypeError> {
+        let data_type_repr: repr::DataType = serde_yaml::from_str(data_type_yaml)
+            .map_err(|err| ParseDataTypeError::InvalidYaml(err.to_string()))?;
+
+        Self::try_from(data_type_repr)
+    }
+
+    /// Serializes the data type as YAML.
+    #[cfg(feature = "yaml")]
+    #[must_use]
+    pub fn to_yaml(&self) -> String {
+        format::to_yaml(&self.clone().into())
+    }
+
+    /// Parses a data type from its TOML representation.
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseDataTypeError::InvalidToml`] if `data_type_toml` is not a valid TOML representation
+    ///   of a data type
+    /// - the same errors as parsing the JSON representation otherwise
+    #[cfg(feature = "toml")]
+    pub fn from_toml(data_type_toml: &str) -> Result<Self, ParseDataTypeError> {
+        let data_type_repr: repr::DataType = toml::from_str(data_type_toml)
+            .map_err(|err| ParseDataTypeError::InvalidToml(err.to_string()))?;
+
+        Self::try_from(data_type_repr)
+    }
+
+    /// Serializes the data type as TOML.
+    ///
+    /// # Errors
+    ///
+    /// - [`SerializeTomlError`] if the data type contains a value which cannot be represented in
+    ///   TOML, e.g. `null`
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> Result<String, SerializeTomlError> {
+        format::to_toml(&self.clone().into())
+    }
+}
+
 impl TryFrom<serde_json::Value> for DataType {
     type Error = ParseDataTypeError;
 
@@ -135,6 +200,12 @@ impl ValidateUri for DataTypeReference {
     }
 }
 
+impl Compatibility for DataTypeReference {
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        self.uri == other.uri
+    }
+}
+
 impl TryFrom<serde_json::Value> for DataTypeReference {
     type Error = ParseVersionedUriError;
 
@@ -0,0 +1,75 @@
+use std::fmt::Write;
+
+use super::Diagram;
+
+/// Escapes the characters which have a special meaning inside of quoted strings and record
+/// labels.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        if matches!(character, '{' | '}' | '|' | '<' | '>' | '"' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(character);
+    }
+    escaped
+}
+
+impl Diagram {
+    /// Renders the diagram as DOT graph.
+    ///
+    /// Entity types are rendered as record nodes identified by their [`VersionedUri`]. Entity
+    /// types which are not part of the diagram are dashed.
+    ///
+    /// [`VersionedUri`]: crate::uri::VersionedUri
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut dot = "digraph types {\n  node [shape=record];\n".to_owned();
+
+        for node in self.nodes() {
+            let mut label = format!("{{{}", escape(&node.label));
+            if !node.attributes.is_empty() {
+                label.push('|');
+                for (name, value) in &node.attributes {
+                    let _ = write!(label, "{}: {}\\l", escape(name), escape(value));
+                }
+            }
+            label.push('}');
+
+            let style = if self.bundle.entity_type(&node.id).is_some() {
+                ""
+            } else {
+                ", style=dashed"
+            };
+            let _ = writeln!(
+                dot,
+                "  \"{}\" [label=\"{label}\"{style}];",
+                escape(&node.id.to_string())
+            );
+        }
+
+        for edge in self.edges() {
+            let ordered = if edge.ordered { " (ordered)" } else { "" };
+            let _ = writeln!(
+                dot,
+                "  \"{}\" -> \"{}\" [label=\"{}{ordered}\", headlabel=\"{}\"];",
+                escape(&edge.source.to_string()),
+                escape(&edge.target.to_string()),
+                escape(&edge.label),
+                edge.cardinality,
+            );
+        }
+
+        for (previous, next) in self.versions() {
+            let _ = writeln!(
+                dot,
+                "  \"{}\" -> \"{}\" [label=\"next version\", style=dashed, arrowhead=empty];",
+                escape(&previous.to_string()),
+                escape(&next.to_string()),
+            );
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
@@ -0,0 +1,91 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+};
+
+use super::Diagram;
+use crate::{
+    ontology::shared::naming::{pascal_case, unique, words},
+    uri::VersionedUri,
+};
+
+/// Escapes the characters which would end a label or a member of a class.
+fn escape(text: &str) -> String {
+    text.replace('"', "#quot;")
+        .replace('{', "#123;")
+        .replace('}', "#125;")
+}
+
+impl Diagram {
+    /// Renders the diagram as Mermaid class diagram.
+    ///
+    /// Classes are named after the titles and versions of the entity types, e.g. `BookV1`, and
+    /// are labelled with the title and version. Classes of entity types which are not part of the
+    /// diagram are labelled with their [`VersionedUri`].
+    #[must_use]
+    pub fn to_mermaid(&self) -> String {
+        let nodes = self.nodes();
+
+        let mut names = HashSet::new();
+        let mut classes = HashMap::new();
+        for node in &nodes {
+            let title = self.bundle.entity_type(&node.id).map_or_else(
+                || {
+                    node.id.base_uri().components().map_or_else(
+                        |_| "Type".to_owned(),
+                        |components| components.slug().to_owned(),
+                    )
+                },
+                |entity_type| entity_type.title().to_owned(),
+            );
+            let mut base = format!("{}V{}", pascal_case(&words(&title)), node.id.version());
+            if !base.starts_with(|character: char| character.is_ascii_alphabetic()) {
+                base.insert(0, 'T');
+            }
+            classes.insert(&node.id, unique(&mut names, &base, "_"));
+        }
+        let class = |uri: &VersionedUri| &classes[uri];
+
+        let mut mermaid = "classDiagram\n".to_owned();
+        for node in &nodes {
+            let _ = write!(
+                mermaid,
+                "  class {}[\"{}\"]",
+                class(&node.id),
+                escape(&node.label)
+            );
+            if node.attributes.is_empty() {
+                mermaid.push('\n');
+            } else {
+                mermaid.push_str(" {\n");
+                for (name, value) in &node.attributes {
+                    let _ = writeln!(mermaid, "    {}: {}", escape(name), escape(value));
+                }
+                mermaid.push_str("  }\n");
+            }
+        }
+
+        for edge in self.edges() {
+            let ordered = if edge.ordered { " (ordered)" } else { "" };
+            let _ = writeln!(
+                mermaid,
+                "  {} --> \"{}\" {} : {}{ordered}",
+                class(&edge.source),
+                edge.cardinality,
+                class(&edge.target),
+                escape(&edge.label),
+            );
+        }
+
+        for (previous, next) in self.versions() {
+            let _ = writeln!(
+                mermaid,
+                "  {} ..> {} : next version",
+                class(previous),
+                class(next)
+            );
+        }
+
+        mermaid
+    }
+}
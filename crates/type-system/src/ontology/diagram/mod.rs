@@ -0,0 +1,489 @@
+//! Visualizing the graph of types.
+//!
+//! A [`Diagram`] shows the entity types of a [`TypeBundle`] and can be rendered as
+//! [DOT](https://graphviz.org/doc/info/lang.html) ([`Diagram::to_dot`]) or as
+//! [Mermaid](https://mermaid.js.org/syntax/classDiagram.html) class diagram
+//! ([`Diagram::to_mermaid`]):
+//!
+//! - Every entity type becomes a node, which lists its properties as attributes, e.g. `Name:
+//!   Text[]`. Attributes are annotated with the titles of the data types of their values, and the
+//!   properties of objects are listed as separate attributes, e.g. `Address.Street: Text`.
+//! - Every link becomes an edge labelled with the title of the link type. The cardinality of the
+//!   link is taken from the `minItems` and `maxItems` of arrays, and ordered links are marked.
+//!   Entity types which are linked to, but are not part of the bundle, are shown as empty nodes.
+//! - Optionally, the versions of entity types with the same [`BaseUri`] are connected by edges.
+//!
+//! [`BaseUri`]: crate::uri::BaseUri
+
+mod dot;
+mod mermaid;
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+};
+
+use crate::{
+    repository::TypeResolver,
+    uri::{BaseUri, VersionedUri},
+    EntityType, OntologyTypeKind, PropertyTypeReference, PropertyValues, TypeBundle, ValueOrArray,
+    ValueOrMaybeOrderedArray,
+};
+
+/// The number of targets of a link.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Cardinality {
+    min: usize,
+    max: Option<usize>,
+}
+
+impl fmt::Display for Cardinality {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.max {
+            Some(max) if max == self.min => write!(fmt, "{max}"),
+            Some(max) => write!(fmt, "{}..{max}", self.min),
+            None => write!(fmt, "{}..*", self.min),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node {
+    id: VersionedUri,
+    /// The title and version, e.g. `Book v1`, or the URI if the entity type is not part of the
+    /// diagram.
+    label: String,
+    /// The names and types of the attributes.
+    attributes: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Edge {
+    source: VersionedUri,
+    target: VersionedUri,
+    label: String,
+    cardinality: Cardinality,
+    ordered: bool,
+}
+
+/// A diagram of the entity types of a [`TypeBundle`].
+#[derive(Debug, Clone)]
+pub struct Diagram {
+    bundle: TypeBundle,
+    version_history: bool,
+}
+
+impl Diagram {
+    /// Creates a diagram of the entity types inside of `bundle`.
+    #[must_use]
+    pub const fn new(bundle: TypeBundle) -> Self {
+        Self {
+            bundle,
+            version_history: false,
+        }
+    }
+
+    /// Creates a diagram of the entity type identified by `uri` and all entity types it
+    /// transitively links to.
+    ///
+    /// # Errors
+    ///
+    /// - if the entity type or any of its dependencies could not be resolved by `resolver`
+    pub fn resolve<R: TypeResolver>(uri: &VersionedUri, resolver: &R) -> Result<Self, R::Error> {
+        resolver
+            .resolve_with_dependencies(OntologyTypeKind::EntityType, uri)
+            .map(Self::new)
+    }
+
+    /// Sets whether consecutive versions of the same entity type are connected by edges.
+    #[must_use]
+    pub const fn with_version_history(mut self, version_history: bool) -> Self {
+        self.version_history = version_history;
+        self
+    }
+
+    fn entity_types(&self) -> Vec<&EntityType> {
+        let mut entity_types: Vec<_> = self.bundle.entity_types().collect();
+        entity_types.sort_by_key(|entity_type| {
+            (
+                entity_type.id().base_uri().to_string(),
+                entity_type.id().version(),
+            )
+        });
+        entity_types
+    }
+
+    /// Returns the nodes, sorted by their URI, including the entity types which are not part of
+    /// the bundle but are linked to.
+    fn nodes(&self) -> Vec<Node> {
+        let mut nodes = BTreeMap::new();
+        for entity_type in self.entity_types() {
+            let mut attributes = Vec::new();
+            self.attributes(
+                &mut attributes,
+                &mut HashSet::new(),
+                "",
+                entity_type.properties(),
+            );
+            nodes.insert(
+                (
+                    entity_type.id().base_uri().to_string(),
+                    entity_type.id().version(),
+                ),
+                Node {
+                    id: entity_type.id().clone(),
+                    label: format!("{} v{}", entity_type.title(), entity_type.id().version()),
+                    attributes,
+                },
+            );
+        }
+        for edge in self.edges() {
+            nodes
+                .entry((edge.target.base_uri().to_string(), edge.target.version()))
+                .or_insert_with(|| Node {
+                    id: edge.target.clone(),
+                    label: edge.target.to_string(),
+                    attributes: Vec::new(),
+                });
+        }
+        nodes.into_values().collect()
+    }
+
+    /// Adds the attributes of `properties`.
+    ///
+    /// `visited` contains the property types whose objects are currently added, so the objects of
+    /// a property type containing itself are only added once.
+    fn attributes<'s>(
+        &'s self,
+        attributes: &mut Vec<(String, String)>,
+        visited: &mut HashSet<&'s VersionedUri>,
+        prefix: &str,
+        properties: &'s HashMap<BaseUri, ValueOrArray<PropertyTypeReference>>,
+    ) {
+        let mut properties: Vec<_> = properties.iter().collect();
+        properties.sort_by_key(|(base_uri, _)| base_uri.as_str());
+
+        for (base_uri, property) in properties {
+            let (reference, array) = match property {
+                ValueOrArray::Value(reference) => (reference, ""),
+                ValueOrArray::Array(array) => (array.items(), "[]"),
+            };
+            if let Some(property_type) = self.bundle.property_type(reference.uri()) {
+                let name = format!("{prefix}{}", property_type.title());
+                let value = self.values(property_type.one_of());
+                attributes.push((name.clone(), format!("{value}{array}")));
+                if visited.insert(reference.uri()) {
+                    self.objects(attributes, visited, &name, property_type.one_of());
+                    visited.remove(reference.uri());
+                }
+            } else {
+                attributes.push((
+                    format!("{prefix}{base_uri}"),
+                    format!("{}{array}", reference.uri()),
+                ));
+            }
+        }
+    }
+
+    /// Adds the attributes of the objects inside of `values`.
+    fn objects<'s>(
+        &'s self,
+        attributes: &mut Vec<(String, String)>,
+        visited: &mut HashSet<&'s VersionedUri>,
+        name: &str,
+        values: &'s [PropertyValues],
+    ) {
+        for value in values {
+            match value {
+                PropertyValues::DataTypeReference(_) => {}
+                PropertyValues::PropertyTypeObject(object) => {
+                    self.attributes(
+                        attributes,
+                        visited,
+                        &format!("{name}."),
+                        object.properties(),
+                    );
+                }
+                PropertyValues::ArrayOfPropertyValues(array) => {
+                    self.objects(attributes, visited, name, array.items().one_of());
+                }
+            }
+        }
+    }
+
+    /// Describes `values`, e.g. `Text | Number[]`.
+    fn values(&self, values: &[PropertyValues]) -> String {
+        values
+            .iter()
+            .map(|value| match value {
+                PropertyValues::DataTypeReference(reference) => {
+                    self.bundle.data_type(reference.uri()).map_or_else(
+                        || reference.uri().to_string(),
+                        |data_type| data_type.title().to_owned(),
+                    )
+                }
+                PropertyValues::PropertyTypeObject(_) => "Object".to_owned(),
+                PropertyValues::ArrayOfPropertyValues(array) => match array.items().one_of() {
+                    [value] => format!("{}[]", self.values(std::slice::from_ref(value))),
+                    values => format!("[{}]", self.values(values)),
+                },
+            })
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+
+    fn edges(&self) -> Vec<Edge> {
+        let mut edges = Vec::new();
+        for entity_type in self.entity_types() {
+            let mut links: Vec<_> = entity_type.links().iter().collect();
+            links.sort_by_key(|(link_type, _)| link_type.to_string());
+
+            for (link_type, link) in links {
+                let required = entity_type.required_links().contains(link_type);
+                let (cardinality, ordered) = match link {
+                    ValueOrMaybeOrderedArray::Value(_) => (
+                        Cardinality {
+                            min: usize::from(required),
+                            max: Some(1),
+                        },
+                        false,
+                    ),
+                    ValueOrMaybeOrderedArray::Array(array) => (
+                        Cardinality {
+                            min: array.array().min_items().unwrap_or_default(),
+                            max: array.array().max_items(),
+                        },
+                        array.ordered(),
+                    ),
+                };
+                edges.push(Edge {
+                    source: entity_type.id().clone(),
+                    target: link.inner().uri().clone(),
+                    label: self.bundle.link_type(link_type).map_or_else(
+                        || link_type.to_string(),
+                        |link_type| link_type.title().to_owned(),
+                    ),
+                    cardinality,
+                    ordered,
+                });
+            }
+        }
+        edges
+    }
+
+    /// Returns pairs of consecutive versions of the same entity type.
+    fn versions(&self) -> Vec<(&VersionedUri, &VersionedUri)> {
+        if !self.version_history {
+            return Vec::new();
+        }
+        self.entity_types()
+            .windows(2)
+            .filter_map(|window| {
+                let (previous, next) = (window[0].id(), window[1].id());
+                (previous.base_uri() == next.base_uri()).then_some((previous, next))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::{repository::FsTypeRepository, test_data, DataType, LinkType, PropertyType};
+
+    fn bundle() -> TypeBundle {
+        let mut bundle = TypeBundle::new();
+        bundle.insert_data_type(
+            DataType::from_str(test_data::data_type::TEXT_V1).expect("invalid data type"),
+        );
+        bundle.insert_property_type(
+            PropertyType::from_str(test_data::property_type::NAME_V1)
+                .expect("invalid property type"),
+        );
+        bundle.insert_property_type(
+            PropertyType::try_from(json!({
+                "kind": "propertyType",
+                "$id": "https://blockprotocol.org/@alice/types/property-type/address/v/1",
+                "title": "Address",
+                "pluralTitle": "Addresses",
+                "oneOf": [{
+                    "type": "object",
+                    "properties": {
+                        "https://blockprotocol.org/@alice/types/property-type/name/": {
+                            "$ref": "https://blockprotocol.org/@alice/types/property-type/name/v/1"
+                        }
+                    }
+                }]
+            }))
+            .expect("invalid property type"),
+        );
+        for entity_type in [
+            test_data::entity_type::PLAYLIST_V1,
+            test_data::entity_type::SONG_V1,
+        ] {
+            bundle.insert_entity_type(
+                EntityType::from_str(entity_type).expect("invalid entity type"),
+            );
+        }
+        bundle.insert_entity_type(
+            EntityType::try_from(json!({
+                "kind": "entityType",
+                "$id": "https://blockprotocol.org/@alice/types/entity-type/playlist/v/2",
+                "type": "object",
+                "title": "Playlist",
+                "pluralTitle": "Playlists",
+                "properties": {
+                    "https://blockprotocol.org/@alice/types/property-type/name/": {
+                        "$ref": "https://blockprotocol.org/@alice/types/property-type/name/v/1"
+                    },
+                    "https://blockprotocol.org/@alice/types/property-type/address/": {
+                        "type": "array",
+                        "items": {
+                            "$ref": "https://blockprotocol.org/@alice/types/property-type/address/v/1"
+                        }
+                    }
+                },
+                "links": {
+                    "https://blockprotocol.org/@alice/types/link-type/contains/v/1": {
+                        "type": "array",
+                        "items": { "$ref": "https://blockprotocol.org/@alice/types/entity-type/song/v/1" },
+                        "ordered": true,
+                        "minItems": 1,
+                        "maxItems": 10
+                    },
+                    "https://blockprotocol.org/@alice/types/link-type/owns/v/2": {
+                        "$ref": "https://blockprotocol.org/@alice/types/entity-type/person/v/1"
+                    }
+                },
+                "requiredLinks": ["https://blockprotocol.org/@alice/types/link-type/owns/v/2"]
+            }))
+            .expect("invalid entity type"),
+        );
+        bundle.insert_link_type(
+            LinkType::from_str(test_data::link_type::OWNS_V2).expect("invalid link type"),
+        );
+        bundle.insert_link_type(
+            LinkType::try_from(json!({
+                "kind": "linkType",
+                "$id": "https://blockprotocol.org/@alice/types/link-type/contains/v/1",
+                "title": "Contains",
+                "pluralTitle": "Contains",
+                "description": "Has as a part"
+            }))
+            .expect("invalid link type"),
+        );
+        bundle
+    }
+
+    #[test]
+    fn dot() {
+        assert_eq!(
+            Diagram::new(bundle()).with_version_history(true).to_dot(),
+            r#"digraph types {
+  node [shape=record];
+  "https://blockprotocol.org/@alice/types/entity-type/person/v/1" [label="{https://blockprotocol.org/@alice/types/entity-type/person/v/1}", style=dashed];
+  "https://blockprotocol.org/@alice/types/entity-type/playlist/v/1" [label="{Playlist v1|Name: Text\l}"];
+  "https://blockprotocol.org/@alice/types/entity-type/playlist/v/2" [label="{Playlist v2|Address: Object[]\lAddress.Name: Text\lName: Text\l}"];
+  "https://blockprotocol.org/@alice/types/entity-type/song/v/1" [label="{Song v1|Name: Text\l}"];
+  "https://blockprotocol.org/@alice/types/entity-type/playlist/v/1" -> "https://blockprotocol.org/@alice/types/entity-type/song/v/1" [label="Contains (ordered)", headlabel="0..*"];
+  "https://blockprotocol.org/@alice/types/entity-type/playlist/v/2" -> "https://blockprotocol.org/@alice/types/entity-type/song/v/1" [label="Contains (ordered)", headlabel="1..10"];
+  "https://blockprotocol.org/@alice/types/entity-type/playlist/v/2" -> "https://blockprotocol.org/@alice/types/entity-type/person/v/1" [label="Owns", headlabel="1"];
+  "https://blockprotocol.org/@alice/types/entity-type/playlist/v/1" -> "https://blockprotocol.org/@alice/types/entity-type/playlist/v/2" [label="next version", style=dashed, arrowhead=empty];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn recursive_property_types() {
+        let mut bundle = TypeBundle::new();
+        bundle.insert_data_type(
+            DataType::from_str(test_data::data_type::TEXT_V1).expect("invalid data type"),
+        );
+        for property_type in [
+            test_data::property_type::NAME_V1,
+            test_data::property_type::TREE_NODE_V1,
+        ] {
+            bundle.insert_property_type(
+                PropertyType::from_str(property_type).expect("invalid property type"),
+            );
+        }
+        bundle.insert_entity_type(
+            EntityType::from_str(test_data::entity_type::TREE_V1).expect("invalid entity type"),
+        );
+
+        assert_eq!(
+            Diagram::new(bundle).to_mermaid(),
+            r#"classDiagram
+  class TreeV1["Tree v1"] {
+    Tree Node: Object
+    Tree Node.Name: Text
+    Tree Node.Tree Node: Object[]
+  }
+"#
+        );
+    }
+
+    #[test]
+    fn mermaid() {
+        assert_eq!(
+            Diagram::new(bundle()).to_mermaid(),
+            r#"classDiagram
+  class PersonV1["https://blockprotocol.org/@alice/types/entity-type/person/v/1"]
+  class PlaylistV1["Playlist v1"] {
+    Name: Text
+  }
+  class PlaylistV2["Playlist v2"] {
+    Address: Object[]
+    Address.Name: Text
+    Name: Text
+  }
+  class SongV1["Song v1"] {
+    Name: Text
+  }
+  PlaylistV1 --> "0..*" SongV1 : Contains (ordered)
+  PlaylistV2 --> "1..10" SongV1 : Contains (ordered)
+  PlaylistV2 --> "1" PersonV1 : Owns
+"#
+        );
+    }
+
+    #[test]
+    fn resolve() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = FsTypeRepository::new(directory.path());
+        let bundle = bundle();
+        for data_type in bundle.data_types() {
+            repository.store(data_type).expect("failed to store type");
+        }
+        for property_type in bundle.property_types() {
+            repository
+                .store(property_type)
+                .expect("failed to store type");
+        }
+        for entity_type in bundle.entity_types() {
+            repository.store(entity_type).expect("failed to store type");
+        }
+        for link_type in bundle.link_types() {
+            repository.store(link_type).expect("failed to store type");
+        }
+
+        let uri = VersionedUri::from_str(
+            "https://blockprotocol.org/@alice/types/entity-type/playlist/v/1",
+        )
+        .expect("invalid URI");
+        let diagram = Diagram::resolve(&uri, &repository).expect("could not resolve types");
+        assert_eq!(
+            diagram
+                .nodes()
+                .iter()
+                .map(|node| node.label.as_str())
+                .collect::<Vec<_>>(),
+            ["Playlist v1", "Song v1"]
+        );
+    }
+}
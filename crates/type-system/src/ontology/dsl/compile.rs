@@ -0,0 +1,355 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    str::FromStr,
+};
+
+use super::{
+    parser::{Bounds, Header, Item, LinkMember, Name, PropertyMember, Reference, Value},
+    DslErrorKind, ParseDslError, Span,
+};
+use crate::{
+    ontology::shared::naming::{plural, slug, title, words},
+    uri::{TypeUriComponents, VersionedUri},
+    Array, DataTypeReference, EntityType, EntityTypeReference, LinkType, Links, MaybeOrderedArray,
+    Object, OneOf, OntologyTypeKind, PropertyType, PropertyTypeReference, PropertyValues,
+    TypeBundle, ValueOrArray, ValueOrMaybeOrderedArray,
+};
+
+struct Namespace {
+    host: String,
+    namespace: String,
+}
+
+pub(super) struct Compiler<'a> {
+    source: &'a str,
+    default: Option<Namespace>,
+    aliases: HashMap<String, Namespace>,
+    bundle: TypeBundle,
+}
+
+impl<'a> Compiler<'a> {
+    pub(super) fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            default: None,
+            aliases: HashMap::new(),
+            bundle: TypeBundle::new(),
+        }
+    }
+
+    pub(super) fn compile(mut self, items: Vec<Item>) -> Result<TypeBundle, ParseDslError> {
+        for item in items {
+            match item {
+                Item::Namespace { uri, span } => {
+                    self.default = Some(self.namespace(&uri, span)?);
+                }
+                Item::Use { uri, alias, span } => {
+                    let namespace = self.namespace(&uri, span)?;
+                    match self.aliases.entry(alias) {
+                        Entry::Occupied(entry) => {
+                            return Err(ParseDslError::new(
+                                self.source,
+                                DslErrorKind::Duplicate(entry.key().clone()),
+                                span,
+                            ));
+                        }
+                        Entry::Vacant(entry) => {
+                            entry.insert(namespace);
+                        }
+                    }
+                }
+                Item::Property { header, values } => {
+                    let property_type = self.property_type(&header, &values)?;
+                    self.bundle.insert_property_type(property_type);
+                }
+                Item::Link { header } => {
+                    let link_type = self.link_type(&header)?;
+                    self.bundle.insert_link_type(link_type);
+                }
+                Item::Entity {
+                    header,
+                    properties,
+                    links,
+                } => {
+                    let entity_type = self.entity_type(&header, &properties, &links)?;
+                    self.bundle.insert_entity_type(entity_type);
+                }
+            }
+        }
+        Ok(self.bundle)
+    }
+
+    fn error(&self, kind: DslErrorKind, span: Span) -> ParseDslError {
+        ParseDslError::new(self.source, kind, span)
+    }
+
+    /// Splits a namespace like `https://blockprotocol.org/@alice` into its host and name.
+    fn namespace(&self, uri: &str, span: Span) -> Result<Namespace, ParseDslError> {
+        uri.trim_end_matches('/')
+            .strip_prefix("https://")
+            .and_then(|rest| rest.split_once("/@"))
+            .filter(|(host, namespace)| {
+                TypeUriComponents::new(
+                    (*host).to_owned(),
+                    (*namespace).to_owned(),
+                    OntologyTypeKind::EntityType,
+                    "type".to_owned(),
+                )
+                .is_ok()
+            })
+            .map(|(host, namespace)| Namespace {
+                host: host.to_owned(),
+                namespace: namespace.to_owned(),
+            })
+            .ok_or_else(|| self.error(DslErrorKind::InvalidNamespace(uri.to_owned()), span))
+    }
+
+    fn uri(
+        &self,
+        reference: &Reference,
+        kind: OntologyTypeKind,
+    ) -> Result<VersionedUri, ParseDslError> {
+        let (namespace, ident) = match &reference.name {
+            Name::Uri(uri) => {
+                return VersionedUri::from_str(uri).map_err(|_| {
+                    self.error(DslErrorKind::InvalidUri(uri.clone()), reference.span)
+                });
+            }
+            Name::Ident {
+                namespace: Some((alias, span)),
+                ident,
+            } => (
+                self.aliases.get(alias).ok_or_else(|| {
+                    self.error(DslErrorKind::UnknownNamespace(alias.clone()), *span)
+                })?,
+                ident,
+            ),
+            Name::Ident {
+                namespace: None,
+                ident,
+            } => (
+                self.default
+                    .as_ref()
+                    .ok_or_else(|| self.error(DslErrorKind::MissingNamespace, reference.span))?,
+                ident,
+            ),
+        };
+
+        let components = TypeUriComponents::new(
+            namespace.host.clone(),
+            namespace.namespace.clone(),
+            kind,
+            slug(&words(ident)),
+        )
+        .map_err(|_| self.error(DslErrorKind::InvalidUri(ident.clone()), reference.span))?;
+        Ok(VersionedUri::new(
+            components.to_base_uri(),
+            reference.version.unwrap_or(1),
+        ))
+    }
+
+    /// Returns the URI, title, and plural title of a declaration.
+    fn header(
+        &self,
+        header: &Header,
+        kind: OntologyTypeKind,
+    ) -> Result<(VersionedUri, String, String), ParseDslError> {
+        let id = self.uri(&header.reference, kind)?;
+        if self.bundle.contains(&id) {
+            return Err(self.error(
+                DslErrorKind::Duplicate(id.to_string()),
+                header.reference.span,
+            ));
+        }
+
+        let title = match (&header.title, &header.reference.name) {
+            (Some(title), _) => title.clone(),
+            (None, Name::Ident { ident, .. }) => title(&words(ident)),
+            (None, Name::Uri(uri)) => id
+                .base_uri()
+                .components()
+                .map(|components| title(&words(components.slug())))
+                .map_err(|_| {
+                    self.error(
+                        DslErrorKind::MissingTitle(uri.clone()),
+                        header.reference.span,
+                    )
+                })?,
+        };
+        let plural = header.plural.clone().unwrap_or_else(|| plural(&title));
+        Ok((id, title, plural))
+    }
+
+    fn description(header: &Header) -> Option<String> {
+        (!header.doc.is_empty()).then(|| header.doc.join("\n"))
+    }
+
+    /// Checks that the label of a property is derived from the slug of the property type.
+    ///
+    /// Property types which don't follow the naming convention can be labelled arbitrarily.
+    fn check_label(
+        &self,
+        label: &str,
+        uri: &VersionedUri,
+        span: Span,
+    ) -> Result<(), ParseDslError> {
+        if let Ok(components) = uri.base_uri().components() {
+            let expected = components.slug();
+            if slug(&words(expected)) == expected && slug(&words(label)) != expected {
+                return Err(self.error(
+                    DslErrorKind::MismatchedLabel {
+                        label: label.to_owned(),
+                        slug: expected.to_owned(),
+                    },
+                    span,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn array<T>(&self, items: T, bounds: &Bounds, span: Span) -> Result<Array<T>, ParseDslError> {
+        Array::new(items, bounds.min, bounds.max)
+            .map_err(|error| self.error(DslErrorKind::InvalidType(error), span))
+    }
+
+    fn property_object<const MIN: usize>(
+        &self,
+        members: &[PropertyMember],
+        span: Span,
+    ) -> Result<Object<ValueOrArray<PropertyTypeReference>, MIN>, ParseDslError> {
+        let mut properties = HashMap::new();
+        let mut required = Vec::new();
+        for member in members {
+            let uri = self.uri(&member.reference, OntologyTypeKind::PropertyType)?;
+            self.check_label(&member.label, &uri, member.span)?;
+            let base_uri = uri.base_uri().clone();
+            let reference = PropertyTypeReference::new(uri);
+            let value = match &member.array {
+                Some(bounds) => ValueOrArray::Array(self.array(reference, bounds, member.span)?),
+                None => ValueOrArray::Value(reference),
+            };
+            match properties.entry(base_uri) {
+                Entry::Occupied(entry) => {
+                    return Err(self.error(
+                        DslErrorKind::Duplicate(entry.key().to_string()),
+                        member.span,
+                    ));
+                }
+                Entry::Vacant(entry) => {
+                    if !member.optional {
+                        required.push(entry.key().clone());
+                    }
+                    entry.insert(value);
+                }
+            }
+        }
+        Object::new(properties, required)
+            .map_err(|error| self.error(DslErrorKind::InvalidType(error), span))
+    }
+
+    fn property_values(&self, values: &[Value]) -> Result<Vec<PropertyValues>, ParseDslError> {
+        values
+            .iter()
+            .map(|value| match value {
+                Value::Reference(reference) => Ok(PropertyValues::DataTypeReference(
+                    DataTypeReference::new(self.uri(reference, OntologyTypeKind::DataType)?),
+                )),
+                Value::Object(members, span) => Ok(PropertyValues::PropertyTypeObject(
+                    self.property_object(members, *span)?,
+                )),
+                Value::Array(values, bounds, span) => {
+                    let one_of = OneOf::new(self.property_values(values)?)
+                        .map_err(|error| self.error(DslErrorKind::InvalidType(error), *span))?;
+                    Ok(PropertyValues::ArrayOfPropertyValues(
+                        self.array(one_of, bounds, *span)?,
+                    ))
+                }
+            })
+            .collect()
+    }
+
+    fn property_type(
+        &self,
+        header: &Header,
+        values: &[Value],
+    ) -> Result<PropertyType, ParseDslError> {
+        let (id, title, plural) = self.header(header, OntologyTypeKind::PropertyType)?;
+        let one_of = OneOf::new(self.property_values(values)?)
+            .map_err(|error| self.error(DslErrorKind::InvalidType(error), header.span))?;
+        Ok(PropertyType::new(
+            id,
+            title,
+            plural,
+            Self::description(header),
+            one_of,
+        ))
+    }
+
+    fn link_type(&self, header: &Header) -> Result<LinkType, ParseDslError> {
+        let (id, title, plural) = self.header(header, OntologyTypeKind::LinkType)?;
+        let description = Self::description(header)
+            .ok_or_else(|| self.error(DslErrorKind::MissingDescription, header.span))?;
+        Ok(LinkType::new(
+            id,
+            title,
+            plural,
+            description,
+            header.keywords.clone(),
+        ))
+    }
+
+    fn entity_type(
+        &self,
+        header: &Header,
+        properties: &[PropertyMember],
+        link_members: &[LinkMember],
+    ) -> Result<EntityType, ParseDslError> {
+        let (id, title, plural) = self.header(header, OntologyTypeKind::EntityType)?;
+        let property_object = self.property_object(properties, header.span)?;
+
+        let mut links = HashMap::new();
+        let mut required = Vec::new();
+        for member in link_members {
+            let link = self.uri(&member.link, OntologyTypeKind::LinkType)?;
+            let target =
+                EntityTypeReference::new(self.uri(&member.target, OntologyTypeKind::EntityType)?);
+            let value = match &member.array {
+                Some(bounds) => ValueOrMaybeOrderedArray::Array(
+                    MaybeOrderedArray::new(member.ordered, target, bounds.min, bounds.max)
+                        .map_err(|error| {
+                            self.error(DslErrorKind::InvalidType(error), member.span)
+                        })?,
+                ),
+                None => ValueOrMaybeOrderedArray::Value(target),
+            };
+            match links.entry(link) {
+                Entry::Occupied(entry) => {
+                    return Err(self.error(
+                        DslErrorKind::Duplicate(entry.key().to_string()),
+                        member.span,
+                    ));
+                }
+                Entry::Vacant(entry) => {
+                    if member.required {
+                        required.push(entry.key().clone());
+                    }
+                    entry.insert(value);
+                }
+            }
+        }
+        let links = Links::new(links, required)
+            .map_err(|error| self.error(DslErrorKind::InvalidType(error), header.span))?;
+
+        Ok(EntityType::new(
+            id,
+            title,
+            plural,
+            Self::description(header),
+            property_object,
+            links,
+            HashMap::new(),
+            Vec::new(),
+        ))
+    }
+}
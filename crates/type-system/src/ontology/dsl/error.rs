@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::ValidationError;
+
+/// A range of bytes in the source of a DSL document.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Span {
+    start: usize,
+    end: usize,
+}
+
+impl Span {
+    #[must_use]
+    pub const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// The offset of the first byte of the span.
+    #[must_use]
+    pub const fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The offset of the first byte after the span.
+    #[must_use]
+    pub const fn end(&self) -> usize {
+        self.end
+    }
+
+    pub(super) const fn to(self, other: Self) -> Self {
+        Self::new(self.start, other.end)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum DslErrorKind {
+    #[error("unexpected character `{0}`")]
+    UnexpectedCharacter(char),
+    #[error("unterminated string")]
+    UnterminatedString,
+    #[error("expected {expected}, found {found}")]
+    UnexpectedToken { expected: String, found: String },
+    #[error("no default namespace was declared")]
+    MissingNamespace,
+    #[error("the namespace `{0}` was not imported")]
+    UnknownNamespace(String),
+    #[error("`{0}` is not a valid namespace, expected `https://<host>/@<namespace>`")]
+    InvalidNamespace(String),
+    #[error("`{0}` cannot be turned into a type URI")]
+    InvalidUri(String),
+    #[error("the label `{label}` does not match the slug `{slug}` of the type it refers to")]
+    MismatchedLabel { label: String, slug: String },
+    #[error("`{0}` is defined more than once")]
+    Duplicate(String),
+    #[error("cannot derive a title from `{0}`, a title has to be specified")]
+    MissingTitle(String),
+    #[error("link types require a description")]
+    MissingDescription,
+    #[error("invalid type: {0}")]
+    InvalidType(ValidationError),
+}
+
+/// An error which occurred while compiling a DSL document.
+///
+/// The error points to the part of the document which caused it, both as [`Span`] and as
+/// one-based line and column of the start of the span.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Error)]
+#[error("{line}:{column}: {kind}")]
+pub struct ParseDslError {
+    kind: DslErrorKind,
+    span: Span,
+    line: usize,
+    column: usize,
+}
+
+impl ParseDslError {
+    pub(super) fn new(source: &str, kind: DslErrorKind, span: Span) -> Self {
+        let mut line = 1;
+        let mut column = 1;
+        for (_, character) in source
+            .char_indices()
+            .take_while(|(offset, _)| *offset < span.start)
+        {
+            if character == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+        Self {
+            kind,
+            span,
+            line,
+            column,
+        }
+    }
+
+    #[must_use]
+    pub const fn kind(&self) -> &DslErrorKind {
+        &self.kind
+    }
+
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        self.span
+    }
+
+    #[must_use]
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    #[must_use]
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+}
@@ -0,0 +1,180 @@
+use std::{fmt, iter::Peekable, str::CharIndices};
+
+use super::{DslErrorKind, ParseDslError, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Token {
+    Ident(String),
+    /// A version like `v1`.
+    Version(u32),
+    Number(usize),
+    String(String),
+    /// The contents of a `///` comment.
+    Doc(String),
+    Symbol(&'static str),
+    Eof,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ident(ident) => write!(fmt, "`{ident}`"),
+            Self::Version(version) => write!(fmt, "version `v{version}`"),
+            Self::Number(number) => write!(fmt, "number `{number}`"),
+            Self::String(string) => write!(fmt, "string \"{string}\""),
+            Self::Doc(_) => fmt.write_str("doc comment"),
+            Self::Symbol(symbol) => write!(fmt, "`{symbol}`"),
+            Self::Eof => fmt.write_str("end of input"),
+        }
+    }
+}
+
+/// Identifiers with a special meaning, which cannot be used as labels.
+pub(super) const KEYWORDS: [&str; 11] = [
+    "as",
+    "entity",
+    "keywords",
+    "link",
+    "namespace",
+    "ordered",
+    "plural",
+    "property",
+    "required",
+    "title",
+    "use",
+];
+
+/// Returns if `ident` would be read as a single identifier token.
+pub(super) fn is_ident(ident: &str) -> bool {
+    let mut characters = ident.chars();
+    characters
+        .next()
+        .map_or(false, |first| first.is_ascii_alphabetic() || first == '_')
+        && characters.all(is_ident_character)
+        && parse_version(ident).is_none()
+}
+
+fn parse_version(ident: &str) -> Option<u32> {
+    let digits = ident.strip_prefix('v')?;
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+type Characters<'a> = Peekable<CharIndices<'a>>;
+
+/// Appends characters to `text` as long as they match `predicate`.
+fn take_while(characters: &mut Characters<'_>, text: &mut String, predicate: fn(char) -> bool) {
+    while let Some(&(_, character)) = characters.peek() {
+        if !predicate(character) {
+            break;
+        }
+        text.push(character);
+        characters.next();
+    }
+}
+
+const fn is_ident_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || character == '_'
+}
+
+pub(super) fn tokenize(source: &str) -> Result<Vec<(Token, Span)>, ParseDslError> {
+    let mut tokens = Vec::new();
+    let mut characters = source.char_indices().peekable();
+    let position = |characters: &mut Characters<'_>| {
+        characters
+            .peek()
+            .map_or(source.len(), |(position, _)| *position)
+    };
+
+    while let Some((start, character)) = characters.next() {
+        let token = match character {
+            character if character.is_whitespace() => continue,
+            '/' if matches!(characters.peek(), Some((_, '/'))) => {
+                characters.next();
+                let mut comment = String::new();
+                take_while(&mut characters, &mut comment, |character| character != '\n');
+                if let Some(doc) = comment.strip_prefix('/') {
+                    let doc = doc.strip_prefix(' ').unwrap_or(doc).trim_end();
+                    Token::Doc(doc.to_owned())
+                } else {
+                    continue;
+                }
+            }
+            character if character.is_ascii_alphabetic() || character == '_' => {
+                let mut ident = character.to_string();
+                take_while(&mut characters, &mut ident, is_ident_character);
+                parse_version(&ident).map_or_else(|| Token::Ident(ident), Token::Version)
+            }
+            character if character.is_ascii_digit() => {
+                let mut digits = character.to_string();
+                take_while(&mut characters, &mut digits, |character| {
+                    character.is_ascii_digit()
+                });
+                let number = digits.parse().map_err(|_| {
+                    ParseDslError::new(
+                        source,
+                        DslErrorKind::UnexpectedToken {
+                            expected: "a smaller number".to_owned(),
+                            found: format!("`{digits}`"),
+                        },
+                        Span::new(start, position(&mut characters)),
+                    )
+                })?;
+                Token::Number(number)
+            }
+            '"' => {
+                let unterminated = || {
+                    ParseDslError::new(
+                        source,
+                        DslErrorKind::UnterminatedString,
+                        Span::new(start, source.len()),
+                    )
+                };
+                let mut string = String::new();
+                loop {
+                    match characters.next().ok_or_else(unterminated)? {
+                        (_, '"') => break,
+                        (_, '\\') => match characters.next().ok_or_else(unterminated)? {
+                            (_, 'n') => string.push('\n'),
+                            (_, 't') => string.push('\t'),
+                            (_, escaped) => string.push(escaped),
+                        },
+                        (_, character) => string.push(character),
+                    }
+                }
+                Token::String(string)
+            }
+            '-' if matches!(characters.peek(), Some((_, '>'))) => {
+                characters.next();
+                Token::Symbol("->")
+            }
+            '.' if matches!(characters.peek(), Some((_, '.'))) => {
+                characters.next();
+                Token::Symbol("..")
+            }
+            '{' => Token::Symbol("{"),
+            '}' => Token::Symbol("}"),
+            '[' => Token::Symbol("["),
+            ']' => Token::Symbol("]"),
+            ';' => Token::Symbol(";"),
+            ':' => Token::Symbol(":"),
+            ',' => Token::Symbol(","),
+            '.' => Token::Symbol("."),
+            '?' => Token::Symbol("?"),
+            '|' => Token::Symbol("|"),
+            '=' => Token::Symbol("="),
+            character => {
+                return Err(ParseDslError::new(
+                    source,
+                    DslErrorKind::UnexpectedCharacter(character),
+                    Span::new(start, start + character.len_utf8()),
+                ));
+            }
+        };
+        tokens.push((token, Span::new(start, position(&mut characters))));
+    }
+    tokens.push((Token::Eof, Span::new(source.len(), source.len())));
+    Ok(tokens)
+}
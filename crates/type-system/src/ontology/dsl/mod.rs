@@ -0,0 +1,301 @@
+//! A compact text format for authoring types.
+//!
+//! Writing types as JSON Schema is verbose, so types can be written in a DSL instead, which is
+//! compiled into the native types by [`compile`]. Existing types can be turned back into the DSL
+//! by [`to_dsl`].
+//!
+//! ```text
+//! namespace "https://blockprotocol.org/@alice";
+//! use "https://blockprotocol.org/@blockprotocol" as bp;
+//!
+//! /// Have (something) as one's own; possess
+//! link owns v2 plural "Owns" keywords "has", "own";
+//!
+//! property Name v1 = bp.Text v1;
+//! property ContactInformation v1 plural "Contact Information" = {
+//!     email: bp.Email v1;
+//!     phoneNumber?: bp.PhoneNumber v1;
+//! };
+//!
+//! entity Book v1 {
+//!     name: [Name v1];
+//!     blurb?: Blurb v1;
+//!     link writtenBy -> Person v1 required;
+//!     link contains -> [Page v1; 1..10] ordered;
+//! }
+//! ```
+//!
+//! - Types are declared with `property`, `link`, or `entity`, followed by their name and version.
+//!   The URI of a type is built from the namespace, the kind of the declaration, and the name, e.g.
+//!   `Name v1` becomes `https://blockprotocol.org/@alice/types/property-type/name/v/1`.
+//! - `namespace` sets the namespace of names without prefix, `use` imports another namespace under
+//!   an alias. Instead of a name, the full versioned URI can be given as string.
+//! - The title is derived from the name and the plural title from the title, both can be
+//!   overwritten with `title` and `plural`. `///` comments become the description, which is
+//!   required for link types. Link types can list their related `keywords`.
+//! - Property types consist of data types, objects (`{ ... }`) and arrays (`[ ... ]`) separated by
+//!   `|`. Arrays may be bounded by `; min..max`, where both bounds are optional.
+//! - Properties of objects are labelled with the `camelCase` name of the property type and are
+//!   marked optional with `?`. Links refer to a link type, whose version defaults to `1`, and can
+//!   be `required`. Arrays of links can be `ordered`.
+
+mod compile;
+mod error;
+mod lexer;
+mod parser;
+mod print;
+
+pub use self::{
+    error::{DslErrorKind, ParseDslError, Span},
+    print::to_dsl,
+};
+use crate::TypeBundle;
+
+/// Compiles the types declared in `source`.
+///
+/// # Errors
+///
+/// - [`ParseDslError`] if `source` is not a valid document or declares invalid types, the error
+///   points to the offending part of `source`
+pub fn compile(source: &str) -> Result<TypeBundle, ParseDslError> {
+    let tokens = lexer::tokenize(source)?;
+    let items = parser::parse(source, tokens)?;
+    compile::Compiler::new(source).compile(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        test_data, uri::VersionedUri, Compatibility, EntityType, LinkType, PropertyType,
+        ValidationError, ValueOrMaybeOrderedArray,
+    };
+
+    fn uri(uri: &str) -> VersionedUri {
+        VersionedUri::from_str(uri).expect("invalid URI")
+    }
+
+    #[test]
+    fn compiles_types() {
+        let bundle = compile(
+            r#"
+            namespace "https://blockprotocol.org/@alice";
+            use "https://blockprotocol.org/@blockprotocol" as bp;
+
+            /// Have (something) as one's own; possess
+            link owns v2 plural "Owns" keywords "has", "have", "possess", "own";
+
+            property ContactInformation v1 plural "Contact Information" = {
+                email: bp.Email v1;
+                phoneNumber?: bp.PhoneNumber v1;
+            };
+            property ContrivedProperty v1 = bp.Number v1 | [bp.Number v1; ..4];
+
+            entity Book v1 {
+                name: [Name v1]
+                blurb?: Blurb v1
+                publishedOn?: PublishedOn v1
+                link writtenBy -> Person v1 required
+            }
+            "#,
+        )
+        .expect("invalid document");
+
+        let owns = LinkType::from_str(test_data::link_type::OWNS_V2).expect("invalid link type");
+        assert_eq!(bundle.link_type(owns.id()), Some(&owns));
+
+        for property_type in [
+            test_data::property_type::CONTACT_INFORMATION_V1,
+            test_data::property_type::CONTRIVED_PROPERTY_V1,
+        ] {
+            let property_type =
+                PropertyType::from_str(property_type).expect("invalid property type");
+            assert_eq!(
+                bundle.property_type(property_type.id()),
+                Some(&property_type)
+            );
+        }
+
+        let book = EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid entity");
+        let compiled = bundle.entity_type(book.id()).expect("missing entity type");
+        assert!(compiled.is_equivalent_to(&book));
+        assert_eq!(compiled.title(), "Book");
+        assert_eq!(compiled.plural_title(), "Books");
+    }
+
+    #[test]
+    fn links() {
+        let bundle = compile(
+            r#"
+            namespace "https://example.com/@music";
+            entity Playlist v1 {
+                link contains -> [Song v1; 1..10] ordered
+                link "https://example.com/@other/types/link-type/curated-by/v/3" -> Person v1
+            }
+            "#,
+        )
+        .expect("invalid document");
+
+        let playlist = bundle
+            .entity_type(&uri(
+                "https://example.com/@music/types/entity-type/playlist/v/1",
+            ))
+            .expect("missing entity type");
+        let contains = uri("https://example.com/@music/types/link-type/contains/v/1");
+        match &playlist.links()[&contains] {
+            ValueOrMaybeOrderedArray::Array(array) => {
+                assert!(array.ordered());
+                assert_eq!(array.array().min_items(), Some(1));
+                assert_eq!(array.array().max_items(), Some(10));
+            }
+            ValueOrMaybeOrderedArray::Value(_) => panic!("expected an array"),
+        }
+        assert!(playlist.links().contains_key(&uri(
+            "https://example.com/@other/types/link-type/curated-by/v/3"
+        )));
+        assert!(playlist.required_links().is_empty());
+    }
+
+    #[test]
+    fn errors() {
+        let error = compile("entity Book v1 {\n    name: Name v1\n    link ? \n}")
+            .expect_err("parsed invalid document");
+        assert_eq!((error.line(), error.column()), (3, 10));
+        assert_eq!(error.span(), Span::new(44, 45));
+        assert_eq!(error.kind(), &DslErrorKind::UnexpectedToken {
+            expected: "the name or URI of a type".to_owned(),
+            found: "`?`".to_owned()
+        });
+
+        let error = compile("entity Book v1 {}").expect_err("compiled without namespace");
+        assert_eq!(error.kind(), &DslErrorKind::MissingNamespace);
+        assert_eq!(error.span(), Span::new(7, 14));
+
+        let error = compile(
+            "namespace \"https://example.com/@alice\";\nentity Book v1 { title: bp.Name v1 }",
+        )
+        .expect_err("compiled unknown namespace");
+        assert_eq!(
+            error.kind(),
+            &DslErrorKind::UnknownNamespace("bp".to_owned())
+        );
+        assert_eq!((error.line(), error.column()), (2, 25));
+
+        let error =
+            compile("namespace \"https://example.com/@alice\";\nentity Book v1 { title: Name v1 }")
+                .expect_err("compiled mismatched label");
+        assert_eq!(error.kind(), &DslErrorKind::MismatchedLabel {
+            label: "title".to_owned(),
+            slug: "name".to_owned()
+        });
+
+        let error = compile("namespace \"https://example.com/@alice\";\nlink owns v1;")
+            .expect_err("compiled link type without description");
+        assert_eq!(error.kind(), &DslErrorKind::MissingDescription);
+
+        let error = compile(
+            "namespace \"https://example.com/@alice\";\nproperty Name v1 = [Text v1; 3..1];",
+        )
+        .expect_err("compiled invalid bounds");
+        assert_eq!(
+            error.kind(),
+            &DslErrorKind::InvalidType(ValidationError::InvalidItemBounds {
+                min_items: 3,
+                max_items: 1
+            })
+        );
+
+        let error =
+            compile("property Name v1 = \"unterminated").expect_err("compiled unterminated string");
+        assert_eq!(error.kind(), &DslErrorKind::UnterminatedString);
+        assert_eq!(error.span(), Span::new(19, 32));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut bundle = TypeBundle::new();
+        bundle.insert_link_type(
+            LinkType::from_str(test_data::link_type::OWNS_V2).expect("invalid link type"),
+        );
+        for property_type in [
+            test_data::property_type::NAME_V1,
+            test_data::property_type::CONTACT_INFORMATION_V1,
+            test_data::property_type::CONTRIVED_PROPERTY_V1,
+            test_data::property_type::INTERESTS_V1,
+        ] {
+            bundle.insert_property_type(
+                PropertyType::from_str(property_type).expect("invalid property type"),
+            );
+        }
+        for entity_type in [
+            test_data::entity_type::BOOK_V1,
+            test_data::entity_type::PLAYLIST_V1,
+        ] {
+            bundle.insert_entity_type(
+                EntityType::from_str(entity_type).expect("invalid entity type"),
+            );
+        }
+
+        let dsl = to_dsl(&bundle);
+        let compiled = compile(&dsl).unwrap_or_else(|error| panic!("{error}:\n{dsl}"));
+        assert_eq!(compiled.len(), bundle.len());
+        for link_type in bundle.link_types() {
+            assert_eq!(compiled.link_type(link_type.id()), Some(link_type));
+        }
+        for property_type in bundle.property_types() {
+            let recompiled = compiled
+                .property_type(property_type.id())
+                .expect("missing property type");
+            assert!(recompiled.is_equivalent_to(property_type), "{dsl}");
+            assert_eq!(recompiled.title(), property_type.title());
+            assert_eq!(recompiled.plural_title(), property_type.plural_title());
+        }
+        for entity_type in bundle.entity_types() {
+            let recompiled = compiled
+                .entity_type(entity_type.id())
+                .expect("missing entity type");
+            assert!(recompiled.is_equivalent_to(entity_type), "{dsl}");
+            assert_eq!(recompiled.title(), entity_type.title());
+            assert_eq!(recompiled.plural_title(), entity_type.plural_title());
+        }
+    }
+
+    #[test]
+    fn prints_types() {
+        let mut bundle = TypeBundle::new();
+        bundle.insert_link_type(
+            LinkType::from_str(test_data::link_type::OWNS_V2).expect("invalid link type"),
+        );
+        bundle.insert_property_type(
+            PropertyType::from_str(test_data::property_type::CONTACT_INFORMATION_V1)
+                .expect("invalid property type"),
+        );
+        bundle.insert_entity_type(
+            EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid entity type"),
+        );
+
+        assert_eq!(
+            to_dsl(&bundle),
+            r#"namespace "https://blockprotocol.org/@alice";
+use "https://blockprotocol.org/@blockprotocol" as blockprotocol;
+
+/// Have (something) as one's own; possess
+link owns v2 plural "Owns" keywords "has", "have", "possess", "own";
+
+property ContactInformation v1 plural "Contact Information" = {
+    email: blockprotocol.Email v1;
+    phoneNumber?: blockprotocol.PhoneNumber v1;
+};
+
+entity Book v1 {
+    blurb?: Blurb v1;
+    name: [Name v1];
+    publishedOn?: PublishedOn v1;
+    link writtenBy -> Person v1 required;
+}
+"#
+        );
+    }
+}
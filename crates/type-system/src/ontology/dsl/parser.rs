@@ -0,0 +1,447 @@
+use super::{lexer::Token, DslErrorKind, ParseDslError, Span};
+
+/// The name of a type, either relative to a namespace or as URI.
+pub(super) enum Name {
+    Ident {
+        namespace: Option<(String, Span)>,
+        ident: String,
+    },
+    Uri(String),
+}
+
+pub(super) struct Reference {
+    pub(super) name: Name,
+    /// The version of the type, which is part of the URI if the name is a URI.
+    pub(super) version: Option<u32>,
+    pub(super) span: Span,
+}
+
+#[derive(Default)]
+pub(super) struct Bounds {
+    pub(super) min: Option<usize>,
+    pub(super) max: Option<usize>,
+}
+
+pub(super) struct Header {
+    pub(super) doc: Vec<String>,
+    pub(super) reference: Reference,
+    pub(super) title: Option<String>,
+    pub(super) plural: Option<String>,
+    pub(super) keywords: Vec<String>,
+    pub(super) span: Span,
+}
+
+pub(super) struct PropertyMember {
+    pub(super) label: String,
+    pub(super) optional: bool,
+    pub(super) reference: Reference,
+    pub(super) array: Option<Bounds>,
+    pub(super) span: Span,
+}
+
+pub(super) struct LinkMember {
+    pub(super) link: Reference,
+    pub(super) target: Reference,
+    pub(super) array: Option<Bounds>,
+    pub(super) ordered: bool,
+    pub(super) required: bool,
+    pub(super) span: Span,
+}
+
+pub(super) enum Value {
+    Reference(Reference),
+    Object(Vec<PropertyMember>, Span),
+    Array(Vec<Value>, Bounds, Span),
+}
+
+pub(super) enum Item {
+    Namespace {
+        uri: String,
+        span: Span,
+    },
+    Use {
+        uri: String,
+        alias: String,
+        span: Span,
+    },
+    Property {
+        header: Header,
+        values: Vec<Value>,
+    },
+    Link {
+        header: Header,
+    },
+    Entity {
+        header: Header,
+        properties: Vec<PropertyMember>,
+        links: Vec<LinkMember>,
+    },
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    tokens: Vec<(Token, Span)>,
+    position: usize,
+    /// The span of the last token returned by [`Self::next`].
+    last: Span,
+}
+
+pub(super) fn parse(source: &str, tokens: Vec<(Token, Span)>) -> Result<Vec<Item>, ParseDslError> {
+    let mut parser = Parser {
+        source,
+        tokens,
+        position: 0,
+        last: Span::new(0, 0),
+    };
+    let mut items = Vec::new();
+    while let Some(item) = parser.item()? {
+        items.push(item);
+    }
+    Ok(items)
+}
+
+impl Parser<'_> {
+    /// Doc comments are only meaningful in front of declarations and ignored everywhere else.
+    fn skip_docs(&mut self) {
+        while matches!(self.tokens[self.position].0, Token::Doc(_)) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> &Token {
+        self.skip_docs();
+        &self.tokens[self.position].0
+    }
+
+    fn next(&mut self) -> (Token, Span) {
+        self.skip_docs();
+        let (token, span) = self.tokens[self.position].clone();
+        if token != Token::Eof {
+            self.position += 1;
+        }
+        self.last = span;
+        (token, span)
+    }
+
+    fn unexpected(&self, token: &Token, span: Span, expected: &str) -> ParseDslError {
+        ParseDslError::new(
+            self.source,
+            DslErrorKind::UnexpectedToken {
+                expected: expected.to_owned(),
+                found: token.to_string(),
+            },
+            span,
+        )
+    }
+
+    fn error(&mut self, expected: &str) -> ParseDslError {
+        self.skip_docs();
+        let (token, span) = &self.tokens[self.position];
+        self.unexpected(token, *span, expected)
+    }
+
+    fn eat(&mut self, symbol: &str) -> bool {
+        if matches!(self.peek(), Token::Symbol(next) if *next == symbol) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if matches!(self.peek(), Token::Ident(next) if next == keyword) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, symbol: &str) -> Result<Span, ParseDslError> {
+        if self.eat(symbol) {
+            Ok(self.last)
+        } else {
+            Err(self.error(&format!("`{symbol}`")))
+        }
+    }
+
+    fn ident(&mut self, expected: &str) -> Result<(String, Span), ParseDslError> {
+        match self.next() {
+            (Token::Ident(ident), span) => Ok((ident, span)),
+            (token, span) => Err(self.unexpected(&token, span, expected)),
+        }
+    }
+
+    fn string(&mut self, expected: &str) -> Result<(String, Span), ParseDslError> {
+        match self.next() {
+            (Token::String(string), span) => Ok((string, span)),
+            (token, span) => Err(self.unexpected(&token, span, expected)),
+        }
+    }
+
+    fn number(&mut self) -> Option<usize> {
+        if let Token::Number(number) = *self.peek() {
+            self.next();
+            Some(number)
+        } else {
+            None
+        }
+    }
+
+    fn item(&mut self) -> Result<Option<Item>, ParseDslError> {
+        let mut doc = Vec::new();
+        while let Token::Doc(line) = &self.tokens[self.position].0 {
+            doc.push(line.clone());
+            self.position += 1;
+        }
+
+        let (token, start) = self.next();
+        let item = match &token {
+            Token::Eof => return Ok(None),
+            Token::Ident(keyword) if keyword == "namespace" => {
+                let (uri, _) = self.string("the URI of the namespace")?;
+                Item::Namespace {
+                    uri,
+                    span: start.to(self.last),
+                }
+            }
+            Token::Ident(keyword) if keyword == "use" => {
+                let (uri, _) = self.string("the URI of the namespace")?;
+                if !self.eat_keyword("as") {
+                    return Err(self.error("`as`"));
+                }
+                let (alias, _) = self.ident("the alias of the namespace")?;
+                Item::Use {
+                    uri,
+                    alias,
+                    span: start.to(self.last),
+                }
+            }
+            Token::Ident(keyword) if keyword == "property" => {
+                let header = self.header(doc, start, false)?;
+                self.expect("=")?;
+                Item::Property {
+                    header,
+                    values: self.values()?,
+                }
+            }
+            Token::Ident(keyword) if keyword == "link" => Item::Link {
+                header: self.header(doc, start, true)?,
+            },
+            Token::Ident(keyword) if keyword == "entity" => {
+                let header = self.header(doc, start, false)?;
+                let (properties, links) = self.entity_body()?;
+                Item::Entity {
+                    header,
+                    properties,
+                    links,
+                }
+            }
+            _ => {
+                return Err(self.unexpected(
+                    &token,
+                    start,
+                    "`namespace`, `use`, `property`, `link`, or `entity`",
+                ));
+            }
+        };
+        self.eat(";");
+        Ok(Some(item))
+    }
+
+    fn reference(&mut self, version_required: bool) -> Result<Reference, ParseDslError> {
+        let (token, start) = self.next();
+        let name = match token {
+            Token::String(uri) => {
+                return Ok(Reference {
+                    name: Name::Uri(uri),
+                    version: None,
+                    span: start,
+                });
+            }
+            Token::Ident(ident) => {
+                if self.eat(".") {
+                    Name::Ident {
+                        namespace: Some((ident, start)),
+                        ident: self.ident("the name of a type")?.0,
+                    }
+                } else {
+                    Name::Ident {
+                        namespace: None,
+                        ident,
+                    }
+                }
+            }
+            token => return Err(self.unexpected(&token, start, "the name or URI of a type")),
+        };
+
+        let version = if let Token::Version(version) = *self.peek() {
+            self.next();
+            Some(version)
+        } else if version_required {
+            return Err(self.error("a version like `v1`"));
+        } else {
+            None
+        };
+
+        Ok(Reference {
+            name,
+            version,
+            span: start.to(self.last),
+        })
+    }
+
+    fn header(
+        &mut self,
+        doc: Vec<String>,
+        start: Span,
+        link: bool,
+    ) -> Result<Header, ParseDslError> {
+        let reference = self.reference(true)?;
+        let mut title = None;
+        let mut plural = None;
+        let mut keywords = Vec::new();
+        loop {
+            if self.eat_keyword("title") {
+                title = Some(self.string("a title")?.0);
+            } else if self.eat_keyword("plural") {
+                plural = Some(self.string("a plural title")?.0);
+            } else if link && self.eat_keyword("keywords") {
+                keywords.push(self.string("a keyword")?.0);
+                while self.eat(",") {
+                    keywords.push(self.string("a keyword")?.0);
+                }
+            } else {
+                break;
+            }
+        }
+        Ok(Header {
+            doc,
+            reference,
+            title,
+            plural,
+            keywords,
+            span: start.to(self.last),
+        })
+    }
+
+    /// Parses the optional `; min..max` at the end of an array.
+    fn bounds(&mut self) -> Result<Bounds, ParseDslError> {
+        if !self.eat(";") {
+            return Ok(Bounds::default());
+        }
+        let min = self.number();
+        self.expect("..")?;
+        let max = self.number();
+        Ok(Bounds { min, max })
+    }
+
+    /// Parses either a single reference or an array `[reference; min..max]`.
+    fn target(
+        &mut self,
+        version_required: bool,
+    ) -> Result<(Reference, Option<Bounds>), ParseDslError> {
+        if self.eat("[") {
+            let reference = self.reference(version_required)?;
+            let bounds = self.bounds()?;
+            self.expect("]")?;
+            Ok((reference, Some(bounds)))
+        } else {
+            Ok((self.reference(version_required)?, None))
+        }
+    }
+
+    fn values(&mut self) -> Result<Vec<Value>, ParseDslError> {
+        let mut values = vec![self.value()?];
+        while self.eat("|") {
+            values.push(self.value()?);
+        }
+        Ok(values)
+    }
+
+    fn value(&mut self) -> Result<Value, ParseDslError> {
+        if self.eat("{") {
+            let start = self.last;
+            let mut members = Vec::new();
+            while !self.eat("}") {
+                members.push(self.property_member()?);
+                self.eat(";");
+            }
+            Ok(Value::Object(members, start.to(self.last)))
+        } else if self.eat("[") {
+            let start = self.last;
+            let values = self.values()?;
+            let bounds = self.bounds()?;
+            self.expect("]")?;
+            Ok(Value::Array(values, bounds, start.to(self.last)))
+        } else {
+            Ok(Value::Reference(self.reference(true)?))
+        }
+    }
+
+    fn entity_body(&mut self) -> Result<(Vec<PropertyMember>, Vec<LinkMember>), ParseDslError> {
+        self.expect("{")?;
+        let mut properties = Vec::new();
+        let mut links = Vec::new();
+        while !self.eat("}") {
+            if self.eat_keyword("link") {
+                links.push(self.link_member()?);
+            } else {
+                properties.push(self.property_member()?);
+            }
+            self.eat(";");
+        }
+        Ok((properties, links))
+    }
+
+    fn property_member(&mut self) -> Result<PropertyMember, ParseDslError> {
+        let (label, start) = match self.next() {
+            (Token::Ident(label) | Token::String(label), span) => (label, span),
+            (token, span) => return Err(self.unexpected(&token, span, "a property label or `}`")),
+        };
+        let optional = self.eat("?");
+        self.expect(":")?;
+        let (reference, array) = self.target(true)?;
+        Ok(PropertyMember {
+            label,
+            optional,
+            reference,
+            array,
+            span: start.to(self.last),
+        })
+    }
+
+    fn link_member(&mut self) -> Result<LinkMember, ParseDslError> {
+        let start = self.last;
+        let link = self.reference(false)?;
+        self.expect("->")?;
+        let (target, array) = self.target(true)?;
+        let mut ordered = false;
+        let mut required = false;
+        loop {
+            if self.eat_keyword("required") {
+                required = true;
+            } else if self.eat_keyword("ordered") {
+                if array.is_none() {
+                    return Err(self.unexpected(
+                        &Token::Ident("ordered".to_owned()),
+                        self.last,
+                        "`required`, only arrays of links can be ordered",
+                    ));
+                }
+                ordered = true;
+            } else {
+                break;
+            }
+        }
+        Ok(LinkMember {
+            link,
+            target,
+            array,
+            ordered,
+            required,
+            span: start.to(self.last),
+        })
+    }
+}
@@ -0,0 +1,437 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Write,
+};
+
+use super::lexer::{is_ident, KEYWORDS};
+use crate::{
+    ontology::shared::naming::{pascal_case, plural, slug, title, unique, words},
+    uri::{BaseUri, TypeUriComponents, VersionedUri},
+    EntityType, LinkType, OntologyTypeKind, PropertyType, PropertyTypeReference, PropertyValues,
+    TypeBundle, ValueOrArray, ValueOrMaybeOrderedArray,
+};
+
+/// A namespace identified by its host and name.
+type Namespace = (String, String);
+
+fn camel_case(words: &[String]) -> String {
+    let pascal = pascal_case(words);
+    let mut characters = pascal.chars();
+    characters.next().map_or_else(String::new, |first| {
+        first.to_lowercase().chain(characters).collect()
+    })
+}
+
+fn quote(string: &str) -> String {
+    let mut quoted = String::with_capacity(string.len() + 2);
+    quoted.push('"');
+    for character in string.chars() {
+        match character {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            character => quoted.push(character),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn bounds(min_items: Option<usize>, max_items: Option<usize>) -> String {
+    if min_items.is_none() && max_items.is_none() {
+        return String::new();
+    }
+    let bound = |bound: Option<usize>| bound.map(|bound| bound.to_string()).unwrap_or_default();
+    format!("; {}..{}", bound(min_items), bound(max_items))
+}
+
+/// Returns the namespace and the identifier of a type, if its URI can be written as identifier.
+///
+/// Link types are named in `camelCase`, all other types in `PascalCase`.
+fn name(uri: &VersionedUri, kind: OntologyTypeKind) -> Option<(Namespace, String)> {
+    let components = uri.base_uri().components().ok()?;
+    if components.kind() != kind {
+        return None;
+    }
+    let slug_words = words(components.slug());
+    let ident = if kind == OntologyTypeKind::LinkType {
+        camel_case(&slug_words)
+    } else {
+        pascal_case(&slug_words)
+    };
+    let rebuilt = TypeUriComponents::new(
+        components.host().to_owned(),
+        components.namespace().to_owned(),
+        kind,
+        slug(&words(&ident)),
+    )
+    .ok()?;
+    (is_ident(&ident) && rebuilt.to_base_uri() == *uri.base_uri()).then(|| {
+        (
+            (
+                components.host().to_owned(),
+                components.namespace().to_owned(),
+            ),
+            ident,
+        )
+    })
+}
+
+fn alias(namespace: &str) -> String {
+    let alias = words(namespace).join("_").to_lowercase();
+    if is_ident(&alias) && !KEYWORDS.contains(&alias.as_str()) {
+        alias
+    } else {
+        format!("ns_{alias}")
+    }
+}
+
+struct Printer {
+    default: Option<Namespace>,
+    aliases: HashMap<Namespace, String>,
+}
+
+impl Printer {
+    fn new(bundle: &TypeBundle) -> Self {
+        let declarations = bundle
+            .link_types()
+            .map(|link_type| (link_type.id(), OntologyTypeKind::LinkType))
+            .chain(
+                bundle
+                    .property_types()
+                    .map(|property_type| (property_type.id(), OntologyTypeKind::PropertyType)),
+            )
+            .chain(
+                bundle
+                    .entity_types()
+                    .map(|entity_type| (entity_type.id(), OntologyTypeKind::EntityType)),
+            );
+        let mut counts = BTreeMap::<Namespace, usize>::new();
+        for (uri, kind) in declarations {
+            if let Some((namespace, _)) = name(uri, kind) {
+                *counts.entry(namespace).or_default() += 1;
+            }
+        }
+        // `max_by_key` returns the last maximum, but the first most common namespace is preferred
+        let default = counts
+            .iter()
+            .rev()
+            .max_by_key(|(_, count)| **count)
+            .map(|(namespace, _)| namespace.clone());
+
+        Self {
+            default,
+            aliases: HashMap::new(),
+        }
+    }
+
+    /// Assigns aliases to every namespace referred to in the bundle, except the default one.
+    fn collect_aliases(&mut self, bundle: &TypeBundle) {
+        let mut references = Vec::new();
+        for link_type in bundle.link_types() {
+            references.push((link_type.id(), OntologyTypeKind::LinkType));
+        }
+        for property_type in bundle.property_types() {
+            references.push((property_type.id(), OntologyTypeKind::PropertyType));
+            for reference in property_type.data_type_references() {
+                references.push((reference.uri(), OntologyTypeKind::DataType));
+            }
+            for reference in property_type.property_type_references() {
+                references.push((reference.uri(), OntologyTypeKind::PropertyType));
+            }
+        }
+        for entity_type in bundle.entity_types() {
+            references.push((entity_type.id(), OntologyTypeKind::EntityType));
+            for reference in entity_type.property_type_references() {
+                references.push((reference.uri(), OntologyTypeKind::PropertyType));
+            }
+            for (link, target) in entity_type.link_type_references() {
+                references.push((link, OntologyTypeKind::LinkType));
+                references.push((target.uri(), OntologyTypeKind::EntityType));
+            }
+        }
+
+        let mut namespaces: Vec<_> = references
+            .into_iter()
+            .filter_map(|(uri, kind)| name(uri, kind))
+            .map(|(namespace, _)| namespace)
+            .filter(|namespace| Some(namespace) != self.default.as_ref())
+            .collect();
+        namespaces.sort();
+        namespaces.dedup();
+
+        let mut used = HashSet::new();
+        for namespace in namespaces {
+            let alias = unique(&mut used, &alias(&namespace.1), "");
+            self.aliases.insert(namespace, alias);
+        }
+    }
+
+    fn reference(&self, uri: &VersionedUri, kind: OntologyTypeKind, version: bool) -> String {
+        match name(uri, kind) {
+            Some((namespace, ident)) => {
+                let mut reference = match self.aliases.get(&namespace) {
+                    Some(alias) => format!("{alias}.{ident}"),
+                    None => ident,
+                };
+                if version || uri.version() != 1 {
+                    let _ = write!(reference, " v{}", uri.version());
+                }
+                reference
+            }
+            None => quote(&uri.to_string()),
+        }
+    }
+
+    fn header(
+        &self,
+        keyword: &str,
+        id: &VersionedUri,
+        kind: OntologyTypeKind,
+        title_value: &str,
+        plural_title: &str,
+        description: Option<&str>,
+    ) -> String {
+        let mut output = String::new();
+        if let Some(description) = description {
+            for line in description.lines() {
+                if line.is_empty() {
+                    output.push_str("///\n");
+                } else {
+                    let _ = writeln!(output, "/// {line}");
+                }
+            }
+        }
+        let _ = write!(output, "{keyword} {}", self.reference(id, kind, true));
+
+        let derived = match name(id, kind) {
+            Some((_, ident)) => Some(title(&words(&ident))),
+            None => id
+                .base_uri()
+                .components()
+                .ok()
+                .map(|components| title(&words(components.slug()))),
+        };
+        if derived.as_deref() != Some(title_value) {
+            let _ = write!(output, " title {}", quote(title_value));
+        }
+        if plural(title_value) != plural_title {
+            let _ = write!(output, " plural {}", quote(plural_title));
+        }
+        output
+    }
+
+    /// The label of a property, which has to match the slug of the property type.
+    fn label(uri: &VersionedUri) -> String {
+        let type_slug = uri.base_uri().components().map_or_else(
+            |_| "property".to_owned(),
+            |components| components.slug().to_owned(),
+        );
+        let label = camel_case(&words(&type_slug));
+        if is_ident(&label)
+            && !KEYWORDS.contains(&label.as_str())
+            && slug(&words(&label)) == type_slug
+        {
+            label
+        } else {
+            quote(&type_slug)
+        }
+    }
+
+    fn properties(
+        &self,
+        properties: &HashMap<BaseUri, ValueOrArray<PropertyTypeReference>>,
+        required: &[BaseUri],
+        indent: usize,
+    ) -> String {
+        let mut properties: Vec<_> = properties.iter().collect();
+        properties.sort_by_key(|(base_uri, _)| base_uri.as_str());
+
+        let mut output = "{\n".to_owned();
+        for (base_uri, value) in properties {
+            let (uri, target) = match value {
+                ValueOrArray::Value(reference) => (
+                    reference.uri(),
+                    self.reference(reference.uri(), OntologyTypeKind::PropertyType, true),
+                ),
+                ValueOrArray::Array(array) => (
+                    array.items().uri(),
+                    format!(
+                        "[{}{}]",
+                        self.reference(array.items().uri(), OntologyTypeKind::PropertyType, true),
+                        bounds(array.min_items(), array.max_items())
+                    ),
+                ),
+            };
+            let optional = if required.contains(base_uri) { "" } else { "?" };
+            let _ = writeln!(
+                output,
+                "{:indent$}{}{optional}: {target};",
+                "",
+                Self::label(uri),
+                indent = indent + 4
+            );
+        }
+        let _ = write!(output, "{:indent$}}}", "");
+        output
+    }
+
+    fn values(&self, values: &[PropertyValues], indent: usize) -> String {
+        values
+            .iter()
+            .map(|value| match value {
+                PropertyValues::DataTypeReference(reference) => {
+                    self.reference(reference.uri(), OntologyTypeKind::DataType, true)
+                }
+                PropertyValues::PropertyTypeObject(object) => {
+                    self.properties(object.properties(), object.required(), indent)
+                }
+                PropertyValues::ArrayOfPropertyValues(array) => format!(
+                    "[{}{}]",
+                    self.values(array.items().one_of(), indent),
+                    bounds(array.min_items(), array.max_items())
+                ),
+            })
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+
+    fn link_type(&self, output: &mut String, link_type: &LinkType) {
+        output.push_str(&self.header(
+            "link",
+            link_type.id(),
+            OntologyTypeKind::LinkType,
+            link_type.title(),
+            link_type.plural_title(),
+            Some(link_type.description()),
+        ));
+        if !link_type.related_keywords().is_empty() {
+            let keywords: Vec<_> = link_type
+                .related_keywords()
+                .iter()
+                .map(|keyword| quote(keyword))
+                .collect();
+            let _ = write!(output, " keywords {}", keywords.join(", "));
+        }
+        output.push_str(";\n");
+    }
+
+    fn property_type(&self, output: &mut String, property_type: &PropertyType) {
+        output.push_str(&self.header(
+            "property",
+            property_type.id(),
+            OntologyTypeKind::PropertyType,
+            property_type.title(),
+            property_type.plural_title(),
+            property_type.description(),
+        ));
+        let _ = writeln!(output, " = {};", self.values(property_type.one_of(), 0));
+    }
+
+    fn entity_type(&self, output: &mut String, entity_type: &EntityType) {
+        output.push_str(&self.header(
+            "entity",
+            entity_type.id(),
+            OntologyTypeKind::EntityType,
+            entity_type.title(),
+            entity_type.plural_title(),
+            entity_type.description(),
+        ));
+        if entity_type.properties().is_empty() && entity_type.links().is_empty() {
+            output.push_str(" {}\n");
+            return;
+        }
+
+        let mut body = self.properties(entity_type.properties(), entity_type.required(), 0);
+        body.pop();
+
+        let mut links: Vec<_> = entity_type.links().iter().collect();
+        links.sort_by_key(|(uri, _)| uri.to_string());
+        for (link, value) in links {
+            let target = match value {
+                ValueOrMaybeOrderedArray::Value(reference) => {
+                    self.reference(reference.uri(), OntologyTypeKind::EntityType, true)
+                }
+                ValueOrMaybeOrderedArray::Array(array) => format!(
+                    "[{}{}]{}",
+                    self.reference(
+                        array.array().items().uri(),
+                        OntologyTypeKind::EntityType,
+                        true
+                    ),
+                    bounds(array.array().min_items(), array.array().max_items()),
+                    if array.ordered() { " ordered" } else { "" }
+                ),
+            };
+            let required = if entity_type.required_links().contains(link) {
+                " required"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                body,
+                "    link {} -> {target}{required};",
+                self.reference(link, OntologyTypeKind::LinkType, false)
+            );
+        }
+        body.push('}');
+        let _ = writeln!(output, " {body}");
+    }
+}
+
+/// Prints the property types, link types, and entity types of `bundle` in the DSL.
+///
+/// The most common namespace of the types becomes the default namespace, all other namespaces are
+/// imported with an alias. Titles and plural titles are only printed if they differ from the ones
+/// which would be derived, and URIs which can't be expressed as a name are printed as string.
+///
+/// Data types, as well as the default values and examples of entity types, cannot be expressed
+/// in the DSL and are not printed.
+#[must_use]
+pub fn to_dsl(bundle: &TypeBundle) -> String {
+    let mut printer = Printer::new(bundle);
+    printer.collect_aliases(bundle);
+
+    let mut output = String::new();
+    if let Some((host, namespace)) = &printer.default {
+        let _ = writeln!(output, "namespace \"https://{host}/@{namespace}\";");
+    }
+    let mut aliases: Vec<_> = printer.aliases.iter().collect();
+    aliases.sort_by_key(|(_, alias)| alias.as_str());
+    for ((host, namespace), alias) in aliases {
+        let _ = writeln!(output, "use \"https://{host}/@{namespace}\" as {alias};");
+    }
+
+    let mut link_types: Vec<_> = bundle.link_types().collect();
+    link_types.sort_by_key(|link_type| link_type.id().to_string());
+    let mut property_types: Vec<_> = bundle.property_types().collect();
+    property_types.sort_by_key(|property_type| property_type.id().to_string());
+    let mut entity_types: Vec<_> = bundle.entity_types().collect();
+    entity_types.sort_by_key(|entity_type| entity_type.id().to_string());
+
+    let mut declarations = Vec::new();
+    for link_type in link_types {
+        let mut declaration = String::new();
+        printer.link_type(&mut declaration, link_type);
+        declarations.push(declaration);
+    }
+    for property_type in property_types {
+        let mut declaration = String::new();
+        printer.property_type(&mut declaration, property_type);
+        declarations.push(declaration);
+    }
+    for entity_type in entity_types {
+        let mut declaration = String::new();
+        printer.entity_type(&mut declaration, entity_type);
+        declarations.push(declaration);
+    }
+
+    for declaration in declarations {
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        output.push_str(&declaration);
+    }
+    output
+}
@@ -29,6 +29,8 @@ pub enum ParseLinksError {
 pub enum ParseEntityTypeReferenceArrayError {
     #[error("invalid entity type reference inside items: `{0}`")]
     InvalidReference(ParseVersionedUriError),
+    #[error("failed validation: `{0}`")]
+    ValidationError(ValidationError),
     #[error("error in JSON: `{0}`")]
     InvalidJson(String),
 }
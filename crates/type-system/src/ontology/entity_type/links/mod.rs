@@ -7,7 +7,7 @@ pub use error::{ParseEntityTypeReferenceArrayError, ParseLinksError};
 
 use crate::{
     uri::{BaseUri, VersionedUri},
-    Array, EntityTypeReference, ValidateUri, ValidationError,
+    Array, Compatibility, EntityTypeReference, ValidateUri, ValidationError,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -34,6 +34,7 @@ impl Links {
     /// # Errors
     ///
     /// - [`ValidationError::MissingRequiredLink`] if a required link is not a key in `links`.
+    /// - [`ValidationError::EmptyRequiredLink`] if a required link does not allow any items.
     pub fn new(
         links: HashMap<VersionedUri, ValueOrMaybeOrderedArray<EntityTypeReference>>,
         required: Vec<VersionedUri>,
@@ -45,8 +46,14 @@ impl Links {
 
     fn validate(&self) -> Result<(), ValidationError> {
         for link in self.required() {
-            if !self.links().contains_key(link) {
-                return Err(ValidationError::MissingRequiredLink(link.clone()));
+            match self.links().get(link) {
+                None => return Err(ValidationError::MissingRequiredLink(link.clone())),
+                Some(ValueOrMaybeOrderedArray::Array(array))
+                    if array.array().max_items() == Some(0) =>
+                {
+                    return Err(ValidationError::EmptyRequiredLink(link.clone()));
+                }
+                Some(_) => {}
             }
         }
         Ok(())
@@ -63,6 +70,35 @@ impl Links {
     pub fn required(&self) -> &[VersionedUri] {
         &self.required_links
     }
+
+    /// Sorts and deduplicates the required links and removes `minItems` constraints of `0`.
+    pub(crate) fn normalize(&mut self) {
+        self.required_links.sort_by_cached_key(ToString::to_string);
+        self.required_links.dedup();
+        for link in self.links.values_mut() {
+            if let ValueOrMaybeOrderedArray::Array(array) = link {
+                array.array.normalize_bounds();
+            }
+        }
+    }
+}
+
+impl Compatibility for Links {
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        let links_compatible = self.links.iter().all(|(link_type, link)| {
+            other
+                .links
+                .get(link_type)
+                .map_or(false, |other_link| link.is_compatible_with(other_link))
+        });
+
+        let required_compatible = other
+            .required_links
+            .iter()
+            .all(|link_type| self.required_links.contains(link_type));
+
+        links_compatible && required_compatible
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -72,19 +108,37 @@ pub struct MaybeOrderedArray<T> {
 }
 
 impl<T> MaybeOrderedArray<T> {
+    /// Creates a new `MaybeOrderedArray` without validating.
     #[must_use]
-    pub const fn new(
+    pub const fn new_unchecked(
         ordered: bool,
         items: T,
         min_items: Option<usize>,
         max_items: Option<usize>,
     ) -> Self {
         Self {
-            array: Array::new(items, min_items, max_items),
+            array: Array::new_unchecked(items, min_items, max_items),
             ordered,
         }
     }
 
+    /// Creates a new `MaybeOrderedArray` containing between `min_items` and `max_items` items.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidItemBounds`] if `min_items` is greater than `max_items`
+    pub fn new(
+        ordered: bool,
+        items: T,
+        min_items: Option<usize>,
+        max_items: Option<usize>,
+    ) -> Result<Self, ValidationError> {
+        Ok(Self {
+            array: Array::new(items, min_items, max_items)?,
+            ordered,
+        })
+    }
+
     #[must_use]
     pub const fn array(&self) -> &Array<T> {
         &self.array
@@ -96,6 +150,12 @@ impl<T> MaybeOrderedArray<T> {
     }
 }
 
+impl<T: Compatibility> Compatibility for MaybeOrderedArray<T> {
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        (!self.ordered || other.ordered) && self.array.is_compatible_with(&other.array)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ValueOrMaybeOrderedArray<T> {
     Value(T),
@@ -112,6 +172,16 @@ impl<T> ValueOrMaybeOrderedArray<T> {
     }
 }
 
+impl<T: Compatibility> Compatibility for ValueOrMaybeOrderedArray<T> {
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Value(value), Self::Value(other_value)) => value.is_compatible_with(other_value),
+            (Self::Array(array), Self::Array(other_array)) => array.is_compatible_with(other_array),
+            _ => false,
+        }
+    }
+}
+
 impl<T: ValidateUri> ValidateUri for ValueOrMaybeOrderedArray<T> {
     fn validate_uri(&self, base_uri: &BaseUri) -> Result<(), ValidationError> {
         match self {
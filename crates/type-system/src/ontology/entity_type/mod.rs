@@ -11,10 +11,14 @@ use std::{
 
 pub use error::ParseEntityTypeError;
 
+#[cfg(any(feature = "yaml", feature = "toml"))]
+use crate::format;
+#[cfg(feature = "toml")]
+use crate::format::SerializeTomlError;
 use crate::{
     uri::{BaseUri, ParseVersionedUriError, VersionedUri},
-    Links, Object, PropertyTypeReference, ValidateUri, ValidationError, ValueOrArray,
-    ValueOrMaybeOrderedArray,
+    Compatibility, Links, Object, PropertyTypeReference, ValidateUri, ValidationError,
+    ValueOrArray, ValueOrMaybeOrderedArray,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -125,6 +129,29 @@ impl EntityType {
             .map(|(link_type, entity_type)| (link_type, entity_type.inner()))
             .collect()
     }
+
+    /// Brings the entity type into a normalized form.
+    ///
+    /// The `required` and `requiredLinks` lists are sorted and deduplicated, and `minItems`
+    /// constraints of `0` are removed. Two normalized entity types are equal if they only differ in
+    /// these parts.
+    pub fn normalize(&mut self) {
+        self.property_object.normalize_required();
+        for property in self.property_object.properties_mut() {
+            property.normalize_bounds();
+        }
+        self.links.normalize();
+    }
+}
+
+impl Compatibility for EntityType {
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        self.id.base_uri() == other.id.base_uri()
+            && self
+                .property_object
+                .is_compatible_with(&other.property_object)
+            && self.links.is_compatible_with(&other.links)
+    }
 }
 
 impl FromStr for EntityType {
@@ -138,6 +165,57 @@ impl FromStr for EntityType {
     }
 }
 
+#[cfg(any(feature = "yaml", feature = "toml"))]
+impl EntityType {
+    /// Parses an entity type from its YAML representation.
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseEntityTypeError::InvalidYaml`] if `entity_type_yaml` is not a valid YAML
+    ///   representation of an entity type
+    /// - the same errors as parsing the JSON representation otherwise
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(entity_type_yaml: &str) -> Result<Self, ParseEntityTypeError> {
+        let entity_type_repr: repr::EntityType = serde_yaml::from_str(entity_type_yaml)
+            .map_err(|err| ParseEntityTypeError::InvalidYaml(err.to_string()))?;
+
+        Self::try_from(entity_type_repr)
+    }
+
+    /// Serializes the entity type as YAML.
+    #[cfg(feature = "yaml")]
+    #[must_use]
+    pub fn to_yaml(&self) -> String {
+        format::to_yaml(&self.clone().into())
+    }
+
+    /// Parses an entity type from its TOML representation.
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseEntityTypeError::InvalidToml`] if `entity_type_toml` is not a valid TOML
+    ///   representation of an entity type
+    /// - the same errors as parsing the JSON representation otherwise
+    #[cfg(feature = "toml")]
+    pub fn from_toml(entity_type_toml: &str) -> Result<Self, ParseEntityTypeError> {
+        let entity_type_repr: repr::EntityType = toml::from_str(entity_type_toml)
+            .map_err(|err| ParseEntityTypeError::InvalidToml(err.to_string()))?;
+
+        Self::try_from(entity_type_repr)
+    }
+
+    /// Serializes the entity type as TOML.
+    ///
+    /// # Errors
+    ///
+    /// - [`SerializeTomlError`] if the entity type contains a value which cannot be represented in
+    ///   TOML, e.g. `null`
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> Result<String, SerializeTomlError> {
+        format::to_toml(&self.clone().into())
+    }
+}
+
 impl TryFrom<serde_json::Value> for EntityType {
     type Error = ParseEntityTypeError;
 
@@ -188,6 +266,12 @@ impl ValidateUri for EntityTypeReference {
     }
 }
 
+impl Compatibility for EntityTypeReference {
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        self.uri == other.uri
+    }
+}
+
 impl TryFrom<serde_json::Value> for EntityTypeReference {
     type Error = ParseVersionedUriError;
 
@@ -212,8 +296,13 @@ impl From<EntityTypeReference> for serde_json::Value {
 mod tests {
     use std::str::FromStr;
 
+    use serde_json::json;
+
     use super::*;
-    use crate::{test_data, utils::tests::check_serialization_from_str};
+    use crate::{
+        test_data, utils::tests::check_serialization_from_str, ParseEntityTypeReferenceArrayError,
+        ParseLinksError,
+    };
 
     fn test_property_type_references(
         entity_type: &EntityType,
@@ -376,4 +465,192 @@ mod tests {
             ),
         ]);
     }
+
+    fn entity_type_from_value(value: serde_json::Value) -> EntityType {
+        EntityType::try_from(value).expect("invalid entity type")
+    }
+
+    fn book_entity_type(
+        properties: &serde_json::Value,
+        required: &serde_json::Value,
+        written_by: &serde_json::Value,
+    ) -> EntityType {
+        entity_type_from_value(json!({
+            "kind": "entityType",
+            "$id": "https://blockprotocol.org/@alice/types/entity-type/book/v/2",
+            "type": "object",
+            "title": "Book",
+            "pluralTitle": "Books",
+            "properties": properties,
+            "required": required,
+            "links": {
+                "https://blockprotocol.org/@alice/types/link-type/written-by/v/1": written_by
+            }
+        }))
+    }
+
+    const NAME: &str = "https://blockprotocol.org/@alice/types/property-type/name/";
+    const BLURB: &str = "https://blockprotocol.org/@alice/types/property-type/blurb/";
+
+    fn name_and_blurb() -> serde_json::Value {
+        json!({
+            NAME: { "$ref": format!("{NAME}v/1") },
+            BLURB: { "$ref": format!("{BLURB}v/1") }
+        })
+    }
+
+    fn person_reference() -> serde_json::Value {
+        json!({ "$ref": "https://blockprotocol.org/@alice/types/entity-type/person/v/1" })
+    }
+
+    #[test]
+    fn normalize_required() {
+        let mut lhs = book_entity_type(
+            &name_and_blurb(),
+            &json!([NAME, BLURB, NAME]),
+            &person_reference(),
+        );
+        let mut rhs = book_entity_type(
+            &name_and_blurb(),
+            &json!([BLURB, NAME]),
+            &person_reference(),
+        );
+        assert_ne!(lhs, rhs);
+        assert!(lhs.is_equivalent_to(&rhs));
+
+        lhs.normalize();
+        rhs.normalize();
+        assert_eq!(lhs, rhs);
+        assert_eq!(lhs.required().len(), 2);
+    }
+
+    #[test]
+    fn optional_property_compatibility() {
+        let name_only = book_entity_type(
+            &json!({ NAME: { "$ref": format!("{NAME}v/1") } }),
+            &json!([NAME]),
+            &person_reference(),
+        );
+        let with_blurb = book_entity_type(&name_and_blurb(), &json!([NAME]), &person_reference());
+
+        // Every book without a blurb is still a valid book when the blurb is optional
+        assert!(name_only.is_compatible_with(&with_blurb));
+        assert!(!with_blurb.is_compatible_with(&name_only));
+    }
+
+    #[test]
+    fn different_entity_types_are_incompatible() {
+        let book = book_entity_type(&name_and_blurb(), &json!([NAME]), &person_reference());
+        let mut magazine = book.clone();
+        magazine.id = VersionedUri::from_str(
+            "https://blockprotocol.org/@alice/types/entity-type/magazine/v/1",
+        )
+        .expect("invalid URI");
+
+        assert!(!book.is_compatible_with(&magazine));
+        assert!(!book.is_equivalent_to(&magazine));
+    }
+
+    #[test]
+    fn required_property_compatibility() {
+        let optional_blurb =
+            book_entity_type(&name_and_blurb(), &json!([NAME]), &person_reference());
+        let required_blurb = book_entity_type(
+            &name_and_blurb(),
+            &json!([NAME, BLURB]),
+            &person_reference(),
+        );
+
+        assert!(required_blurb.is_compatible_with(&optional_blurb));
+        assert!(!optional_blurb.is_compatible_with(&required_blurb));
+    }
+
+    #[test]
+    fn link_compatibility() {
+        let authors = |min_items: usize, ordered: bool| {
+            book_entity_type(
+                &name_and_blurb(),
+                &json!([]),
+                &json!({
+                    "type": "array",
+                    "items": person_reference(),
+                    "minItems": min_items,
+                    "ordered": ordered
+                }),
+            )
+        };
+
+        assert!(authors(0, false).is_equivalent_to(&authors(0, false)));
+        assert!(authors(1, false).is_compatible_with(&authors(0, false)));
+        assert!(!authors(0, false).is_compatible_with(&authors(1, false)));
+        assert!(authors(0, false).is_compatible_with(&authors(0, true)));
+        assert!(!authors(0, true).is_compatible_with(&authors(0, false)));
+        assert!(!authors(0, false).is_compatible_with(&book_entity_type(
+            &name_and_blurb(),
+            &json!([]),
+            &person_reference()
+        )));
+
+        let mut normalized = authors(0, false);
+        normalized.normalize();
+        assert_eq!(
+            normalized
+                .links()
+                .values()
+                .next()
+                .and_then(|link| match link {
+                    ValueOrMaybeOrderedArray::Array(array) => Some(array.array().min_items()),
+                    ValueOrMaybeOrderedArray::Value(_) => None,
+                }),
+            Some(None)
+        );
+    }
+
+    #[test]
+    fn link_constraints() {
+        let written_by = "https://blockprotocol.org/@alice/types/link-type/written-by/v/1";
+        let parse = |link: serde_json::Value, required_links: serde_json::Value| {
+            EntityType::try_from(json!({
+                "kind": "entityType",
+                "$id": "https://blockprotocol.org/@alice/types/entity-type/book/v/2",
+                "type": "object",
+                "title": "Book",
+                "pluralTitle": "Books",
+                "properties": name_and_blurb(),
+                "links": { written_by: link },
+                "requiredLinks": required_links
+            }))
+        };
+        let authors = |min_items: usize, max_items: usize| {
+            json!({
+                "type": "array",
+                "items": person_reference(),
+                "minItems": min_items,
+                "maxItems": max_items,
+                "ordered": false
+            })
+        };
+
+        assert!(parse(authors(0, 0), json!([])).is_ok());
+        assert!(parse(authors(1, 1), json!([written_by])).is_ok());
+        assert_eq!(
+            parse(authors(2, 1), json!([])),
+            Err(ParseEntityTypeError::InvalidLinks(
+                ParseLinksError::InvalidArray(ParseEntityTypeReferenceArrayError::ValidationError(
+                    ValidationError::InvalidItemBounds {
+                        min_items: 2,
+                        max_items: 1
+                    }
+                ))
+            ))
+        );
+        assert_eq!(
+            parse(authors(0, 0), json!([written_by])),
+            Err(ParseEntityTypeError::InvalidLinks(
+                ParseLinksError::ValidationError(ValidationError::EmptyRequiredLink(
+                    VersionedUri::from_str(written_by).expect("invalid URI")
+                ))
+            ))
+        );
+    }
 }
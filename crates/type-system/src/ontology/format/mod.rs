@@ -0,0 +1,467 @@
+//! YAML and TOML representations of types.
+//!
+//! With the `yaml` and `toml` features enabled, every type of the Type System as well as a
+//! [`TypeBundle`] can be parsed from and serialized into YAML and TOML, e.g. by
+//! [`EntityType::from_yaml`] and [`EntityType::to_toml`]. The documents have the same structure
+//! as the JSON representation of the types and are validated the same way, so parsing a document
+//! fails with the same errors as parsing the equivalent JSON.
+//!
+//! Serialized documents have their keys sorted, which makes the output deterministic. As the
+//! serializers don't know about comments, [`preserve_yaml_comments`] and
+//! [`preserve_toml_comments`] can be used to carry the comments of a document over to the
+//! document it was turned into:
+//!
+//! ```ignore
+//! let mut book = EntityType::from_yaml(&source)?;
+//! // ...
+//! let updated = preserve_yaml_comments(&source, &book.to_yaml());
+//! ```
+//!
+//! Comments are attached to the key following them, or to the key on the same line, and are
+//! dropped if that key does not exist anymore.
+//!
+//! [`TypeBundle`]: crate::TypeBundle
+//! [`EntityType::from_yaml`]: crate::EntityType::from_yaml
+//! [`EntityType::to_toml`]: crate::EntityType::to_toml
+
+mod error;
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "yaml")]
+mod yaml;
+
+use std::collections::HashMap;
+
+pub use self::error::SerializeTomlError;
+
+/// A line of a document as seen when preserving comments.
+enum Line<'a> {
+    /// An empty line, or a line which only contains the comment.
+    Comment(Option<&'a str>),
+    /// A line of the value at `path` followed by an optional comment.
+    ///
+    /// Lines inside of multi-line values don't have a path, as they can't carry comments.
+    Content {
+        path: Option<Vec<String>>,
+        comment: Option<&'a str>,
+    },
+}
+
+#[cfg(feature = "yaml")]
+pub(crate) fn to_yaml(value: &serde_json::Value) -> String {
+    serde_yaml::to_string(value).expect("JSON values can always be represented in YAML")
+}
+
+#[cfg(feature = "toml")]
+pub(crate) fn to_toml(value: &serde_json::Value) -> Result<String, SerializeTomlError> {
+    // Converting to a TOML value first ensures that keys with plain values are written before
+    // tables, which is required by TOML
+    ::toml::Value::try_from(value)
+        .and_then(|value| ::toml::to_string(&value))
+        .map_err(|error| SerializeTomlError::UnsupportedValue(error.to_string()))
+}
+
+/// Copies the comments from `original` into `updated`.
+///
+/// `original` is expected to be a YAML document written in block style, like the output of
+/// [`EntityType::to_yaml`].
+///
+/// [`EntityType::to_yaml`]: crate::EntityType::to_yaml
+#[cfg(feature = "yaml")]
+#[must_use]
+pub fn preserve_yaml_comments(original: &str, updated: &str) -> String {
+    preserve_comments(original, updated, yaml::lines)
+}
+
+/// Copies the comments from `original` into `updated`.
+///
+/// Comments inside of multi-line arrays and inline tables are not preserved.
+#[cfg(feature = "toml")]
+#[must_use]
+pub fn preserve_toml_comments(original: &str, updated: &str) -> String {
+    preserve_comments(original, updated, toml::lines)
+}
+
+fn preserve_comments(original: &str, updated: &str, lines: fn(&str) -> Vec<Line<'_>>) -> String {
+    // Comments in front of the first key are considered to belong to the whole document
+    let mut header = Vec::new();
+    let mut leading = HashMap::<_, Vec<_>>::new();
+    let mut trailing = HashMap::new();
+    let mut pending = Vec::new();
+    let mut in_header = true;
+    for line in lines(original) {
+        match line {
+            Line::Comment(comment) => pending.extend(comment),
+            Line::Content { path, comment } => {
+                if in_header {
+                    header.append(&mut pending);
+                    in_header = false;
+                }
+                if let Some(path) = path {
+                    if !pending.is_empty() {
+                        leading
+                            .entry(path.clone())
+                            .or_default()
+                            .append(&mut pending);
+                    }
+                    if let Some(comment) = comment {
+                        trailing.insert(path, comment);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut output = String::new();
+    for comment in header {
+        output.push_str(comment);
+        output.push('\n');
+    }
+    for (text, line) in updated.lines().zip(lines(updated)) {
+        if let Line::Content {
+            path: Some(path), ..
+        } = line
+        {
+            let indentation: String = text.chars().take_while(|c| c.is_whitespace()).collect();
+            for comment in leading.remove(&path).into_iter().flatten() {
+                output.push_str(&indentation);
+                output.push_str(comment);
+                output.push('\n');
+            }
+            output.push_str(text);
+            if let Some(comment) = trailing.remove(&path) {
+                output.push(' ');
+                output.push_str(comment);
+            }
+        } else {
+            output.push_str(text);
+        }
+        output.push('\n');
+    }
+    for comment in pending {
+        output.push_str(comment);
+        output.push('\n');
+    }
+    output
+}
+
+/// Splits a line at the start of its comment.
+///
+/// A comment starts with a `#` at the start of the line or after whitespace, which is not part of
+/// a string. Strings are started by quotes following one of `string_starts`, or at the start of
+/// the line.
+fn split_comment<'a>(line: &'a str, string_starts: &[char]) -> (&'a str, Option<&'a str>) {
+    let mut quote = None;
+    let mut escaped = false;
+    let mut previous = None;
+    for (index, character) in line.char_indices() {
+        match quote {
+            Some('"') if escaped => escaped = false,
+            Some('"') if character == '\\' => escaped = true,
+            Some(open) if character == open => quote = None,
+            None if character == '#' && previous.map_or(true, char::is_whitespace) => {
+                if let (Some(code), Some(comment)) = (line.get(..index), line.get(index..)) {
+                    return (code.trim_end(), Some(comment.trim_end()));
+                }
+            }
+            None if (character == '"' || character == '\'')
+                && previous.map_or(true, |previous| string_starts.contains(&previous)) =>
+            {
+                quote = Some(character);
+            }
+            Some(_) | None => {}
+        }
+        previous = Some(character);
+    }
+    (line.trim_end(), None)
+}
+
+/// Reads a quoted string at the start of `text` and returns its contents and the remaining text.
+///
+/// Escape sequences other than escaped quotes and backslashes are kept as they are.
+fn read_quoted(text: &str) -> Option<(String, &str)> {
+    let mut characters = text.char_indices();
+    let (_, quote) = characters.next()?;
+    let mut contents = String::new();
+    while let Some((index, character)) = characters.next() {
+        if character == quote {
+            // YAML escapes single quotes by doubling them
+            if quote == '\'' && text.get(index + 1..)?.starts_with('\'') {
+                characters.next();
+                contents.push('\'');
+                continue;
+            }
+            return Some((contents, text.get(index + 1..)?));
+        }
+        if character == '\\' && quote == '"' {
+            let (_, escaped) = characters.next()?;
+            if escaped != '"' && escaped != '\\' {
+                contents.push('\\');
+            }
+            contents.push(escaped);
+        } else {
+            contents.push(character);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{test_data, DataType, EntityType, LinkType, PropertyType, TypeBundle};
+
+    fn bundle() -> TypeBundle {
+        let mut bundle = TypeBundle::new();
+        bundle.insert_data_type(
+            DataType::from_str(test_data::data_type::TEXT_V1).expect("invalid data type"),
+        );
+        for property_type in [
+            test_data::property_type::CONTACT_INFORMATION_V1,
+            test_data::property_type::CONTRIVED_PROPERTY_V1,
+        ] {
+            bundle.insert_property_type(
+                PropertyType::from_str(property_type).expect("invalid property type"),
+            );
+        }
+        for entity_type in [
+            test_data::entity_type::BOOK_V1,
+            test_data::entity_type::PLAYLIST_V1,
+        ] {
+            bundle.insert_entity_type(
+                EntityType::from_str(entity_type).expect("invalid entity type"),
+            );
+        }
+        bundle.insert_link_type(
+            LinkType::from_str(test_data::link_type::OWNS_V2).expect("invalid link type"),
+        );
+        bundle
+    }
+
+    #[test]
+    #[cfg(feature = "yaml")]
+    fn yaml() {
+        let bundle = bundle();
+        for data_type in bundle.data_types() {
+            assert_eq!(
+                DataType::from_yaml(&data_type.to_yaml()).as_ref(),
+                Ok(data_type)
+            );
+        }
+        for property_type in bundle.property_types() {
+            assert_eq!(
+                PropertyType::from_yaml(&property_type.to_yaml()).as_ref(),
+                Ok(property_type)
+            );
+        }
+        for entity_type in bundle.entity_types() {
+            assert_eq!(
+                EntityType::from_yaml(&entity_type.to_yaml()).as_ref(),
+                Ok(entity_type)
+            );
+        }
+        for link_type in bundle.link_types() {
+            assert_eq!(
+                LinkType::from_yaml(&link_type.to_yaml()).as_ref(),
+                Ok(link_type)
+            );
+        }
+        assert_eq!(TypeBundle::from_yaml(&bundle.to_yaml()), Ok(bundle));
+
+        let owns = LinkType::from_yaml(
+            r"
+            kind: linkType
+            $id: https://blockprotocol.org/@alice/types/link-type/owns/v/2
+            title: Owns
+            pluralTitle: Owns
+            description: Have (something) as one's own; possess
+            relatedKeywords: [has, have, possess, own]
+            ",
+        );
+        assert_eq!(
+            owns,
+            Ok(LinkType::from_str(test_data::link_type::OWNS_V2).expect("invalid link type"))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    fn toml() {
+        let bundle = bundle();
+        for data_type in bundle.data_types() {
+            let toml = data_type.to_toml().expect("could not serialize data type");
+            assert_eq!(DataType::from_toml(&toml).as_ref(), Ok(data_type));
+        }
+        for property_type in bundle.property_types() {
+            let toml = property_type
+                .to_toml()
+                .expect("could not serialize property type");
+            assert_eq!(PropertyType::from_toml(&toml).as_ref(), Ok(property_type));
+        }
+        for entity_type in bundle.entity_types() {
+            let toml = entity_type
+                .to_toml()
+                .expect("could not serialize entity type");
+            assert_eq!(EntityType::from_toml(&toml).as_ref(), Ok(entity_type));
+        }
+        for link_type in bundle.link_types() {
+            let toml = link_type.to_toml().expect("could not serialize link type");
+            assert_eq!(LinkType::from_toml(&toml).as_ref(), Ok(link_type));
+        }
+        let toml = bundle.to_toml().expect("could not serialize bundle");
+        assert_eq!(TypeBundle::from_toml(&toml), Ok(bundle));
+
+        let owns = LinkType::from_toml(
+            r#"
+            kind = "linkType"
+            "$id" = "https://blockprotocol.org/@alice/types/link-type/owns/v/2"
+            title = "Owns"
+            pluralTitle = "Owns"
+            description = "Have (something) as one's own; possess"
+            relatedKeywords = ["has", "have", "possess", "own"]
+            "#,
+        );
+        assert_eq!(
+            owns,
+            Ok(LinkType::from_str(test_data::link_type::OWNS_V2).expect("invalid link type"))
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "yaml", feature = "toml"))]
+    fn errors_match_json() {
+        let json = r#"{
+            "kind": "linkType",
+            "$id": "https://blockprotocol.org/@alice/types/link-type/owns/",
+            "title": "Owns",
+            "pluralTitle": "Owns",
+            "description": "Have (something) as one's own; possess",
+            "relatedKeywords": []
+        }"#;
+        let yaml = r"
+            kind: linkType
+            $id: https://blockprotocol.org/@alice/types/link-type/owns/
+            title: Owns
+            pluralTitle: Owns
+            description: Have (something) as one's own; possess
+            relatedKeywords: []
+            ";
+        let toml = r#"
+            kind = "linkType"
+            "$id" = "https://blockprotocol.org/@alice/types/link-type/owns/"
+            title = "Owns"
+            pluralTitle = "Owns"
+            description = "Have (something) as one's own; possess"
+            relatedKeywords = []
+            "#;
+        assert!(matches!(
+            LinkType::from_str(json),
+            Err(crate::ParseLinkTypeError::InvalidVersionedUri(_))
+        ));
+        assert_eq!(LinkType::from_yaml(yaml), LinkType::from_str(json));
+        assert_eq!(LinkType::from_toml(toml), LinkType::from_str(json));
+
+        assert!(matches!(
+            EntityType::from_yaml("kind: [entityType"),
+            Err(crate::ParseEntityTypeError::InvalidYaml(_))
+        ));
+        assert!(matches!(
+            EntityType::from_toml("kind = "),
+            Err(crate::ParseEntityTypeError::InvalidToml(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    fn unsupported_toml_values() {
+        let data_type = DataType::try_from(serde_json::json!({
+            "kind": "dataType",
+            "$id": "https://blockprotocol.org/@alice/types/data-type/nothing/v/1",
+            "title": "Nothing",
+            "type": "null",
+            "default": null
+        }))
+        .expect("invalid data type");
+        assert!(matches!(
+            data_type.to_toml(),
+            Err(SerializeTomlError::UnsupportedValue(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "yaml")]
+    fn yaml_comments() {
+        let original = r"# The book as published by Alice
+kind: entityType
+$id: https://blockprotocol.org/@alice/types/entity-type/book/v/1
+title: Book # shown in the UI
+pluralTitle: Books
+type: object
+properties:
+  # Books may have several names
+  https://blockprotocol.org/@alice/types/property-type/name/:
+    type: array
+    items:
+      $ref: https://blockprotocol.org/@alice/types/property-type/name/v/1 # the current version
+required:
+# A book without a name cannot be found
+- https://blockprotocol.org/@alice/types/property-type/name/
+links: {}
+# end of the book
+";
+        let book = EntityType::from_yaml(original).expect("invalid entity type");
+        let updated = preserve_yaml_comments(original, &book.to_yaml());
+        assert_eq!(
+            updated,
+            r"# The book as published by Alice
+$id: https://blockprotocol.org/@alice/types/entity-type/book/v/1
+kind: entityType
+pluralTitle: Books
+properties:
+  # Books may have several names
+  https://blockprotocol.org/@alice/types/property-type/name/:
+    items:
+      $ref: https://blockprotocol.org/@alice/types/property-type/name/v/1 # the current version
+    type: array
+required:
+# A book without a name cannot be found
+- https://blockprotocol.org/@alice/types/property-type/name/
+title: Book # shown in the UI
+type: object
+# end of the book
+"
+        );
+        assert_eq!(EntityType::from_yaml(&updated), Ok(book));
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    fn toml_comments() {
+        let original = r#"# Owning things
+kind = "linkType"
+"$id" = "https://blockprotocol.org/@alice/types/link-type/owns/v/2"
+title = "Owns" # shown in the UI
+pluralTitle = "Owns"
+# "possess" is the dictionary definition
+description = "Have (something) as one's own; possess"
+relatedKeywords = ["has", "have", "possess", "own"] # used for search
+"#;
+        let owns = LinkType::from_toml(original).expect("invalid link type");
+        let updated = preserve_toml_comments(original, &owns.to_toml().expect("invalid TOML"));
+        assert_eq!(
+            updated,
+            r#"# Owning things
+"$id" = "https://blockprotocol.org/@alice/types/link-type/owns/v/2"
+# "possess" is the dictionary definition
+description = "Have (something) as one's own; possess"
+kind = "linkType"
+pluralTitle = "Owns"
+relatedKeywords = ["has", "have", "possess", "own"] # used for search
+title = "Owns" # shown in the UI
+"#
+        );
+        assert_eq!(LinkType::from_toml(&updated), Ok(owns));
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use super::{read_quoted, split_comment, Line};
+
+/// The characters after which a quote starts a string.
+const STRING_STARTS: [char; 7] = [' ', '\t', '=', '[', '{', ',', '.'];
+
+/// Parses a dotted key like `a."b.c".d` into its segments.
+fn parse_keys(text: &str) -> Option<Vec<String>> {
+    let mut keys = Vec::new();
+    let mut rest = text.trim();
+    loop {
+        if rest.starts_with('"') || rest.starts_with('\'') {
+            let (key, remaining) = read_quoted(rest)?;
+            keys.push(key);
+            rest = remaining.trim_start();
+        } else {
+            let end = rest
+                .find(|character: char| {
+                    !(character.is_ascii_alphanumeric() || character == '_' || character == '-')
+                })
+                .unwrap_or(rest.len());
+            if end == 0 {
+                return None;
+            }
+            keys.push(rest.get(..end)?.to_owned());
+            rest = rest.get(end..)?.trim_start();
+        }
+
+        if rest.is_empty() {
+            return Some(keys);
+        }
+        rest = rest.strip_prefix('.')?.trim_start();
+    }
+}
+
+/// Splits a `key = value` line at the first `=` which is not part of a quoted key.
+fn split_assignment(text: &str) -> Option<(&str, &str)> {
+    let mut quote = None;
+    for (index, character) in text.char_indices() {
+        match quote {
+            Some(open) if character == open => quote = None,
+            None if character == '"' || character == '\'' => quote = Some(character),
+            None if character == '=' => return Some((text.get(..index)?, text.get(index + 1..)?)),
+            Some(_) | None => {}
+        }
+    }
+    None
+}
+
+/// Returns the delimiter of a multi-line string which is opened but not closed in `value`.
+fn open_multiline_string(value: &str) -> Option<&'static str> {
+    ["\"\"\"", "'''"]
+        .into_iter()
+        .find(|delimiter| value.starts_with(delimiter) && value.matches(delimiter).count() == 1)
+}
+
+/// Returns how many more brackets and braces are opened than closed in `value`.
+fn bracket_depth(value: &str) -> isize {
+    let mut depth = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    for character in value.chars() {
+        match quote {
+            Some('"') if escaped => escaped = false,
+            Some('"') if character == '\\' => escaped = true,
+            Some(open) if character == open => quote = None,
+            Some(_) => {}
+            None => match character {
+                '"' | '\'' => quote = Some(character),
+                '[' | '{' => depth += 1,
+                ']' | '}' => depth -= 1,
+                _ => {}
+            },
+        }
+    }
+    depth
+}
+
+/// Determines the path of the value on every line of a TOML document.
+///
+/// Elements of arrays of tables are identified by their index, so the paths match the paths of
+/// the equivalent YAML document.
+pub(super) fn lines(document: &str) -> Vec<Line<'_>> {
+    let mut lines = Vec::new();
+    let mut table = Vec::new();
+    // The number of elements of every array of tables seen so far, keyed by its unresolved path
+    let mut arrays = HashMap::<Vec<String>, usize>::new();
+    let mut multiline_string = None;
+    let mut depth = 0;
+
+    let resolve = |keys: &[String], arrays: &HashMap<Vec<String>, usize>| {
+        let mut path = Vec::new();
+        for (index, key) in keys.iter().enumerate() {
+            path.push(key.clone());
+            if let Some(count) = arrays.get(&keys[..=index]) {
+                path.push((count - 1).to_string());
+            }
+        }
+        path
+    };
+
+    for line in document.lines() {
+        if let Some(delimiter) = multiline_string {
+            if line.contains(delimiter) {
+                multiline_string = None;
+            }
+            lines.push(Line::Content {
+                path: None,
+                comment: None,
+            });
+            continue;
+        }
+        if depth > 0 {
+            let (code, _) = split_comment(line.trim_start(), &STRING_STARTS);
+            depth += bracket_depth(code);
+            lines.push(Line::Content {
+                path: None,
+                comment: None,
+            });
+            continue;
+        }
+
+        let content = line.trim();
+        if content.is_empty() {
+            lines.push(Line::Comment(None));
+            continue;
+        }
+        if content.starts_with('#') {
+            lines.push(Line::Comment(Some(content)));
+            continue;
+        }
+
+        let (code, comment) = split_comment(content, &STRING_STARTS);
+        let path = if let Some(header) = code
+            .strip_prefix("[[")
+            .and_then(|header| header.strip_suffix("]]"))
+        {
+            parse_keys(header).map(|keys| {
+                *arrays.entry(keys.clone()).or_default() += 1;
+                // Tables of previous elements don't belong to the new element
+                arrays.retain(|array, _| array.len() <= keys.len() || !array.starts_with(&keys));
+                table = resolve(&keys, &arrays);
+                table.clone()
+            })
+        } else if let Some(header) = code
+            .strip_prefix('[')
+            .and_then(|header| header.strip_suffix(']'))
+        {
+            parse_keys(header).map(|keys| {
+                table = resolve(&keys, &arrays);
+                table.clone()
+            })
+        } else if let Some((keys, value)) = split_assignment(code) {
+            let value = value.trim();
+            multiline_string = open_multiline_string(value);
+            depth = bracket_depth(value);
+            parse_keys(keys).map(|keys| table.iter().cloned().chain(keys).collect())
+        } else {
+            None
+        };
+
+        lines.push(Line::Content { path, comment });
+    }
+    lines
+}
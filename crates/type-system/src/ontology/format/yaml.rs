@@ -0,0 +1,139 @@
+use super::{read_quoted, split_comment, Line};
+
+/// The characters after which a quote starts a string.
+///
+/// Single quotes are included as YAML escapes them by doubling them.
+const STRING_STARTS: [char; 6] = [' ', '\t', '[', '{', ',', '\''];
+
+/// A key or sequence item enclosing the current line.
+struct Parent {
+    indent: usize,
+    segment: String,
+    /// A key without value on its line, whose sequence items may be indented as far as the key.
+    open: bool,
+    items: usize,
+}
+
+/// Splits `text` into the key of a mapping entry and its value.
+fn split_key(text: &str) -> Option<(String, &str)> {
+    if text.starts_with('"') || text.starts_with('\'') {
+        let (key, rest) = read_quoted(text)?;
+        let value = rest.trim_start().strip_prefix(':')?;
+        return (value.is_empty() || value.starts_with(' ')).then_some((key, value));
+    }
+    if text.starts_with(['[', '{', '?', '|', '>']) {
+        return None;
+    }
+
+    let mut characters = text.char_indices().peekable();
+    while let Some((index, character)) = characters.next() {
+        if character == ':' && matches!(characters.peek(), None | Some((_, ' '))) {
+            return Some((text.get(..index)?.to_owned(), text.get(index + 1..)?));
+        }
+    }
+    None
+}
+
+/// Determines the path of the value on every line of a block style YAML document.
+pub(super) fn lines(document: &str) -> Vec<Line<'_>> {
+    let mut lines = Vec::new();
+    let mut parents: Vec<Parent> = Vec::new();
+    let mut root_items = 0;
+    // The indentation of the line starting a block scalar, whose contents are indented further
+    let mut block_scalar: Option<usize> = None;
+
+    for line in document.lines() {
+        let content = line.trim_start();
+        let mut indent = line.len() - content.len();
+        if let Some(block_indent) = block_scalar {
+            if content.is_empty() || indent > block_indent {
+                lines.push(Line::Content {
+                    path: None,
+                    comment: None,
+                });
+                continue;
+            }
+            block_scalar = None;
+        }
+
+        if content.is_empty() {
+            lines.push(Line::Comment(None));
+            continue;
+        }
+        if content.starts_with('#') {
+            lines.push(Line::Comment(Some(content.trim_end())));
+            continue;
+        }
+        if content.starts_with("---") || content.starts_with("...") {
+            lines.push(Line::Content {
+                path: None,
+                comment: None,
+            });
+            continue;
+        }
+
+        let (mut rest, comment) = split_comment(content, &STRING_STARTS);
+        let mut keyed = false;
+        while let Some(item) = rest
+            .strip_prefix('-')
+            .filter(|item| item.is_empty() || item.starts_with(' '))
+        {
+            while parents.last().map_or(false, |parent| {
+                parent.indent > indent || (parent.indent == indent && !parent.open)
+            }) {
+                parents.pop();
+            }
+            let items = parents
+                .last_mut()
+                .map_or(&mut root_items, |parent| &mut parent.items);
+            let index = *items;
+            *items += 1;
+            parents.push(Parent {
+                indent,
+                segment: index.to_string(),
+                open: false,
+                items: 0,
+            });
+            keyed = true;
+
+            let item_content = item.trim_start();
+            if item_content.starts_with(['|', '>']) {
+                block_scalar = Some(indent);
+            }
+            indent += rest.len() - item_content.len();
+            rest = item_content;
+        }
+
+        if let Some((key, value)) = split_key(rest) {
+            while parents
+                .last()
+                .map_or(false, |parent| parent.indent >= indent)
+            {
+                parents.pop();
+            }
+            let value = value.trim();
+            if value.starts_with(['|', '>']) {
+                block_scalar = Some(indent);
+            }
+            parents.push(Parent {
+                indent,
+                segment: key,
+                open: value.is_empty(),
+                items: 0,
+            });
+            keyed = true;
+        }
+
+        lines.push(Line::Content {
+            // Lines without key or item continue the value of a previous line
+            path: keyed.then(|| {
+                parents
+                    .iter()
+                    .map(|parent| parent.segment.clone())
+                    .collect()
+            }),
+            comment,
+        });
+    }
+    lines
+}
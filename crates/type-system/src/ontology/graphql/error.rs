@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::uri::VersionedUri;
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum GenerateSdlError {
+    #[error("the type `{0}` is referenced but not part of the bundle")]
+    MissingType(VersionedUri),
+    #[error("the title `{0}` cannot be turned into a GraphQL name")]
+    InvalidTitle(String),
+}
@@ -0,0 +1,649 @@
+//! Generating a GraphQL schema for entities.
+//!
+//! [`generate_sdl`] renders the entity types of a [`TypeBundle`] in the GraphQL Schema Definition
+//! Language:
+//!
+//! - Every [`EntityType`] becomes an object type with an `entityId` field, a field for each
+//!   property, and a field for each link. In addition, a `CreateInput` and an `UpdateInput` input
+//!   type are generated, in which only required properties are non-null on creation and no property
+//!   is non-null on update.
+//! - Fields are named after the titles of the property types, e.g. `Contact Information` becomes
+//!   `contactInformation`. If the names of multiple fields collide, a numeric suffix is appended.
+//!   The base URI of the property is kept in a `@property` directive.
+//! - Properties with a single variant are typed by it: data types become the built-in scalar
+//!   corresponding to their JSON type, objects become object types, and arrays become lists.
+//!   Properties with multiple variants become a union of object types, in which values which are
+//!   not objects are wrapped in an object type with a `value` field. As unions are not allowed in
+//!   input types, such properties are typed as `JSON` in inputs.
+//! - A link to a single entity becomes a field of the type of the target entity, while an array of
+//!   links becomes a connection field. The edges of connections for ordered links contain the
+//!   `position` of the link. The link type and the cardinality of the link are kept in a `@link`
+//!   directive.
+
+mod error;
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Write,
+};
+
+pub use error::GenerateSdlError;
+
+use crate::{
+    ontology::shared::naming::{identifier, unique, words},
+    uri::{BaseUri, VersionedUri},
+    EntityType, Object, PropertyTypeReference, PropertyValues, TypeBundle, ValueOrArray,
+    ValueOrMaybeOrderedArray,
+};
+
+const DIRECTIVES: &str = r"directive @entityType(id: String!) on OBJECT | INPUT_OBJECT
+directive @property(baseUri: String!) on FIELD_DEFINITION | INPUT_FIELD_DEFINITION
+directive @link(linkType: String!, ordered: Boolean, minItems: Int, maxItems: Int) on FIELD_DEFINITION
+";
+
+const PAGE_INFO: &str = "type PageInfo {
+  hasNextPage: Boolean!
+  hasPreviousPage: Boolean!
+  startCursor: String
+  endCursor: String
+}
+";
+
+/// Names which cannot be used for generated types.
+const RESERVED_TYPES: &[&str] = &[
+    "String",
+    "Int",
+    "Float",
+    "Boolean",
+    "ID",
+    "JSON",
+    "PageInfo",
+    "Query",
+    "Mutation",
+    "Subscription",
+];
+
+/// Converts a title into a GraphQL name, e.g. `Contact Information` into `ContactInformation`
+/// or `contactInformation`.
+fn name(title: &str, capitalize_first: bool) -> Result<String, GenerateSdlError> {
+    let mut name = String::new();
+    for (index, word) in words(title).iter().enumerate() {
+        let mut characters = word.chars();
+        if let Some(first) = characters.next() {
+            if index == 0 && !capitalize_first {
+                name.extend(first.to_lowercase());
+            } else {
+                name.extend(first.to_uppercase());
+            }
+            name.extend(characters.flat_map(char::to_lowercase));
+        }
+    }
+    name.retain(|character| character.is_ascii_alphanumeric());
+    identifier(name).ok_or_else(|| GenerateSdlError::InvalidTitle(title.to_owned()))
+}
+
+/// Writes `description` as a GraphQL block string.
+fn write_description(output: &mut String, description: Option<&str>) {
+    if let Some(description) = description {
+        let description = description.replace("\"\"\"", "\\\"\"\"");
+        let _ = write!(output, "\"\"\"\n{description}\n\"\"\"\n");
+    }
+}
+
+/// Whether a type is generated for output or input.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Usage {
+    Output,
+    Input,
+}
+
+/// The objects inside of the values of a property type, which are defined after the type of the
+/// values is known.
+type PendingObjects<'a> = Vec<(String, &'a Object<ValueOrArray<PropertyTypeReference>, 1>)>;
+
+struct Generator<'a> {
+    bundle: &'a TypeBundle,
+    names: HashSet<String>,
+    /// The definitions of all generated types, keyed by their name.
+    definitions: BTreeMap<String, String>,
+    /// The types of the values of property types, which are generated once per property type.
+    property_types: HashMap<(&'a VersionedUri, Usage), String>,
+    entity_types: HashMap<&'a VersionedUri, String>,
+    json: bool,
+    page_info: bool,
+}
+
+impl<'a> Generator<'a> {
+    fn scalar(&mut self, uri: &VersionedUri) -> Result<String, GenerateSdlError> {
+        let data_type = self
+            .bundle
+            .data_type(uri)
+            .ok_or_else(|| GenerateSdlError::MissingType(uri.clone()))?;
+        Ok(match data_type.json_type() {
+            "string" => "String",
+            "number" => "Float",
+            "integer" => "Int",
+            "boolean" => "Boolean",
+            _ => {
+                self.json = true;
+                "JSON"
+            }
+        }
+        .to_owned())
+    }
+
+    /// Returns the type of the values of the property type referenced by `reference`.
+    fn property_type(
+        &mut self,
+        reference: &'a PropertyTypeReference,
+        usage: Usage,
+    ) -> Result<(String, &'a str), GenerateSdlError> {
+        let property_type = self
+            .bundle
+            .property_type(reference.uri())
+            .ok_or_else(|| GenerateSdlError::MissingType(reference.uri().clone()))?;
+        let title = property_type.title();
+
+        if let Some(value_type) = self.property_types.get(&(reference.uri(), usage)) {
+            return Ok((value_type.clone(), title));
+        }
+        // The type is cached before its objects are defined, so a property type containing itself
+        // refers to the type instead of being generated again
+        let mut objects = Vec::new();
+        let value_type = self.values(title, property_type.one_of(), usage, &mut objects)?;
+        self.property_types
+            .insert((reference.uri(), usage), value_type.clone());
+        for (object_name, object) in objects {
+            self.object(&object_name, object, usage)?;
+        }
+        Ok((value_type, title))
+    }
+
+    fn values(
+        &mut self,
+        title: &str,
+        values: &'a [PropertyValues],
+        usage: Usage,
+        objects: &mut PendingObjects<'a>,
+    ) -> Result<String, GenerateSdlError> {
+        match (values, usage) {
+            ([value], _) => self.value(title, value, usage, objects),
+            (_, Usage::Input) => {
+                self.json = true;
+                Ok("JSON".to_owned())
+            }
+            (_, Usage::Output) => {
+                let base = name(title, true)?;
+                let union = unique(&mut self.names, &format!("{base}Value"), "");
+                let mut members = Vec::new();
+                for value in values {
+                    let member = if let PropertyValues::PropertyTypeObject(object) = value {
+                        let object_name = unique(&mut self.names, &format!("{base}Object"), "");
+                        objects.push((object_name.clone(), object));
+                        object_name
+                    } else {
+                        let value_type = self.value(title, value, usage, objects)?;
+                        let mut suffix: String = value_type
+                            .chars()
+                            .filter(char::is_ascii_alphanumeric)
+                            .collect();
+                        for _ in value_type.matches('[') {
+                            suffix.push_str("List");
+                        }
+                        let wrapper = unique(&mut self.names, &format!("{base}{suffix}"), "");
+                        self.definitions.insert(
+                            wrapper.clone(),
+                            format!("type {wrapper} {{\n  value: {value_type}!\n}}\n"),
+                        );
+                        wrapper
+                    };
+                    members.push(member);
+                }
+                self.definitions.insert(
+                    union.clone(),
+                    format!("union {union} = {}\n", members.join(" | ")),
+                );
+                Ok(union)
+            }
+        }
+    }
+
+    fn value(
+        &mut self,
+        title: &str,
+        value: &'a PropertyValues,
+        usage: Usage,
+        objects: &mut PendingObjects<'a>,
+    ) -> Result<String, GenerateSdlError> {
+        match value {
+            PropertyValues::DataTypeReference(reference) => self.scalar(reference.uri()),
+            PropertyValues::PropertyTypeObject(object) => {
+                let base = name(title, true)?;
+                let object_name = match usage {
+                    Usage::Output => unique(&mut self.names, &base, ""),
+                    Usage::Input => unique(&mut self.names, &format!("{base}Input"), ""),
+                };
+                objects.push((object_name.clone(), object));
+                Ok(object_name)
+            }
+            PropertyValues::ArrayOfPropertyValues(array) => Ok(format!(
+                "[{}!]",
+                self.values(title, array.items().one_of(), usage, objects)?
+            )),
+        }
+    }
+
+    /// Returns the field definitions for `properties`.
+    ///
+    /// `required` is `None` if no field is non-null.
+    fn fields(
+        &mut self,
+        names: &mut HashSet<String>,
+        properties: &'a HashMap<BaseUri, ValueOrArray<PropertyTypeReference>>,
+        required: Option<&[BaseUri]>,
+        usage: Usage,
+    ) -> Result<Vec<String>, GenerateSdlError> {
+        let mut properties: Vec<_> = properties.iter().collect();
+        properties.sort_by_key(|(base_uri, _)| base_uri.as_str());
+
+        let mut fields = Vec::new();
+        for (base_uri, property) in properties {
+            let (mut field_type, title) = match property {
+                ValueOrArray::Value(reference) => self.property_type(reference, usage)?,
+                ValueOrArray::Array(array) => {
+                    let (value_type, title) = self.property_type(array.items(), usage)?;
+                    (format!("[{value_type}!]"), title)
+                }
+            };
+            if required.map_or(false, |required| required.contains(base_uri)) {
+                field_type.push('!');
+            }
+            let field = unique(names, &name(title, false)?, "");
+            fields.push(format!(
+                "  {field}: {field_type} @property(baseUri: \"{base_uri}\")\n"
+            ));
+        }
+        Ok(fields)
+    }
+
+    fn object<const MIN: usize>(
+        &mut self,
+        object_name: &str,
+        object: &'a Object<ValueOrArray<PropertyTypeReference>, MIN>,
+        usage: Usage,
+    ) -> Result<(), GenerateSdlError> {
+        let fields = self.fields(
+            &mut HashSet::new(),
+            object.properties(),
+            Some(object.required()),
+            usage,
+        )?;
+
+        let keyword = match usage {
+            Usage::Output => "type",
+            Usage::Input => "input",
+        };
+        let mut definition = format!("{keyword} {object_name} {{\n");
+        definition.extend(fields);
+        definition.push_str("}\n");
+        self.definitions.insert(object_name.to_owned(), definition);
+        Ok(())
+    }
+
+    fn entity_type(&mut self, entity_type: &'a EntityType) -> Result<(), GenerateSdlError> {
+        let type_name = self.entity_types[entity_type.id()].clone();
+        let directive = format!("@entityType(id: \"{}\")", entity_type.id());
+
+        let mut names = HashSet::from(["entityId".to_owned()]);
+        let mut fields = vec!["  entityId: ID!\n".to_owned()];
+        fields.extend(self.fields(
+            &mut names,
+            entity_type.properties(),
+            Some(entity_type.required()),
+            Usage::Output,
+        )?);
+
+        let mut links: Vec<_> = entity_type.links().iter().collect();
+        links.sort_by_key(|(link_type, _)| link_type.to_string());
+        for (link_type, link) in links {
+            let title = self
+                .bundle
+                .link_type(link_type)
+                .ok_or_else(|| GenerateSdlError::MissingType(link_type.clone()))?
+                .title();
+            let target = link.inner().uri();
+            let target_name = self
+                .entity_types
+                .get(target)
+                .ok_or_else(|| GenerateSdlError::MissingType(target.clone()))?
+                .clone();
+            let field = unique(&mut names, &name(title, false)?, "");
+            let required = if entity_type.required_links().contains(link_type) {
+                "!"
+            } else {
+                ""
+            };
+
+            match link {
+                ValueOrMaybeOrderedArray::Value(_) => {
+                    fields.push(format!(
+                        "  {field}: {target_name}{required} @link(linkType: \"{link_type}\")\n"
+                    ));
+                }
+                ValueOrMaybeOrderedArray::Array(array) => {
+                    let connection = self.connection(
+                        &format!("{type_name}{}", name(title, true)?),
+                        &target_name,
+                        array.ordered(),
+                    );
+                    let mut directive = format!(
+                        "@link(linkType: \"{link_type}\", ordered: {}",
+                        array.ordered()
+                    );
+                    if let Some(min_items) = array.array().min_items() {
+                        let _ = write!(directive, ", minItems: {min_items}");
+                    }
+                    if let Some(max_items) = array.array().max_items() {
+                        let _ = write!(directive, ", maxItems: {max_items}");
+                    }
+                    fields.push(format!(
+                        "  {field}(first: Int, after: String): {connection}! {directive})\n"
+                    ));
+                }
+            }
+        }
+
+        let mut definition = String::new();
+        write_description(&mut definition, entity_type.description());
+        let _ = writeln!(definition, "type {type_name} {directive} {{");
+        definition.extend(fields);
+        definition.push_str("}\n");
+        self.definitions.insert(type_name.clone(), definition);
+
+        for (suffix, required) in [
+            ("CreateInput", Some(entity_type.required())),
+            ("UpdateInput", None),
+        ] {
+            let input_name = unique(&mut self.names, &format!("{type_name}{suffix}"), "");
+            let fields = self.fields(
+                &mut HashSet::new(),
+                entity_type.properties(),
+                required,
+                Usage::Input,
+            )?;
+            let mut definition = format!("input {input_name} {directive} {{\n");
+            definition.extend(fields);
+            definition.push_str("}\n");
+            self.definitions.insert(input_name, definition);
+        }
+        Ok(())
+    }
+
+    /// Defines a connection type to `target` and returns its name.
+    fn connection(&mut self, base: &str, target: &str, ordered: bool) -> String {
+        self.page_info = true;
+        let connection = unique(&mut self.names, &format!("{base}Connection"), "");
+        let edge = unique(&mut self.names, &format!("{base}Edge"), "");
+
+        let position = if ordered { "  position: Int!\n" } else { "" };
+        self.definitions.insert(
+            edge.clone(),
+            format!("type {edge} {{\n  cursor: String!\n  node: {target}!\n{position}}}\n"),
+        );
+        self.definitions.insert(
+            connection.clone(),
+            format!(
+                "type {connection} {{\n  edges: [{edge}!]!\n  pageInfo: PageInfo!\n  totalCount: \
+                 Int!\n}}\n"
+            ),
+        );
+        connection
+    }
+}
+
+/// Returns the GraphQL schema for the entity types inside of `bundle`.
+///
+/// The types referenced by the entity types are looked up in `bundle`.
+///
+/// # Errors
+///
+/// - [`GenerateSdlError::MissingType`] if a referenced type is not part of `bundle`
+/// - [`GenerateSdlError::InvalidTitle`] if a type or field cannot be named after a type
+pub fn generate_sdl(bundle: &TypeBundle) -> Result<String, GenerateSdlError> {
+    let mut generator = Generator {
+        bundle,
+        names: RESERVED_TYPES
+            .iter()
+            .map(|name| (*name).to_owned())
+            .collect(),
+        definitions: BTreeMap::new(),
+        property_types: HashMap::new(),
+        entity_types: HashMap::new(),
+        json: false,
+        page_info: false,
+    };
+
+    let mut entity_types: Vec<_> = bundle.entity_types().collect();
+    entity_types.sort_by_key(|entity_type| entity_type.id().to_string());
+    for entity_type in &entity_types {
+        let type_name = unique(&mut generator.names, &name(entity_type.title(), true)?, "");
+        generator.entity_types.insert(entity_type.id(), type_name);
+    }
+    for entity_type in entity_types {
+        generator.entity_type(entity_type)?;
+    }
+
+    let mut sdl = DIRECTIVES.to_owned();
+    if generator.json {
+        sdl.push_str("\nscalar JSON\n");
+    }
+    if generator.page_info {
+        sdl.push('\n');
+        sdl.push_str(PAGE_INFO);
+    }
+    for definition in generator.definitions.values() {
+        sdl.push('\n');
+        sdl.push_str(definition);
+    }
+    Ok(sdl)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::{test_data, DataType, LinkType, PropertyType};
+
+    fn bundle() -> TypeBundle {
+        let mut bundle = TypeBundle::new();
+        for data_type in [
+            test_data::data_type::TEXT_V1,
+            test_data::data_type::NUMBER_V1,
+        ] {
+            bundle.insert_data_type(DataType::from_str(data_type).expect("invalid data type"));
+        }
+        for property_type in [
+            test_data::property_type::NAME_V1,
+            test_data::property_type::CONTRIVED_PROPERTY_V1,
+        ] {
+            bundle.insert_property_type(
+                PropertyType::from_str(property_type).expect("invalid property type"),
+            );
+        }
+        for entity_type in [
+            test_data::entity_type::PLAYLIST_V1,
+            test_data::entity_type::SONG_V1,
+        ] {
+            bundle.insert_entity_type(
+                EntityType::from_str(entity_type).expect("invalid entity type"),
+            );
+        }
+        bundle.insert_link_type(
+            LinkType::try_from(json!({
+                "kind": "linkType",
+                "$id": "https://blockprotocol.org/@alice/types/link-type/contains/v/1",
+                "title": "Contains",
+                "pluralTitle": "Contains",
+                "description": "Have (something) inside"
+            }))
+            .expect("invalid link type"),
+        );
+        bundle
+    }
+
+    #[test]
+    fn entity_types() {
+        let sdl = generate_sdl(&bundle()).expect("could not generate schema");
+
+        assert!(sdl.contains(
+            "type Playlist @entityType(id: \"https://blockprotocol.org/@alice/types/entity-type/playlist/v/1\") {
+  entityId: ID!
+  name: String @property(baseUri: \"https://blockprotocol.org/@alice/types/property-type/name/\")
+  contains(first: Int, after: String): PlaylistContainsConnection! @link(linkType: \"https://blockprotocol.org/@alice/types/link-type/contains/v/1\", ordered: true)
+}"
+        ));
+        assert!(sdl.contains(
+            "type PlaylistContainsEdge {
+  cursor: String!
+  node: Song!
+  position: Int!
+}"
+        ));
+        assert!(sdl.contains("type PageInfo {"));
+        assert!(sdl.contains(
+            "input PlaylistCreateInput @entityType(id: \"https://blockprotocol.org/@alice/types/entity-type/playlist/v/1\") {
+  name: String @property(baseUri: \"https://blockprotocol.org/@alice/types/property-type/name/\")
+}"
+        ));
+        assert!(sdl.contains("input SongUpdateInput"));
+        assert!(!sdl.contains("scalar JSON"));
+    }
+
+    #[test]
+    fn properties() {
+        let mut bundle = bundle();
+        bundle.insert_property_type(
+            PropertyType::try_from(json!({
+                "kind": "propertyType",
+                "$id": "https://example.com/@alice/types/property-type/name/v/1",
+                "title": "Name",
+                "pluralTitle": "Names",
+                "oneOf": [{
+                    "type": "object",
+                    "properties": {
+                        "https://blockprotocol.org/@alice/types/property-type/name/": {
+                            "$ref": "https://blockprotocol.org/@alice/types/property-type/name/v/1"
+                        }
+                    },
+                    "required": ["https://blockprotocol.org/@alice/types/property-type/name/"]
+                }]
+            }))
+            .expect("invalid property type"),
+        );
+        bundle.insert_entity_type(
+            EntityType::try_from(json!({
+                "kind": "entityType",
+                "$id": "https://blockprotocol.org/@alice/types/entity-type/measurement/v/1",
+                "type": "object",
+                "title": "Measurement",
+                "pluralTitle": "Measurements",
+                "description": "A contrived measurement",
+                "properties": {
+                    "https://blockprotocol.org/@alice/types/property-type/name/": {
+                        "$ref": "https://blockprotocol.org/@alice/types/property-type/name/v/1"
+                    },
+                    "https://example.com/@alice/types/property-type/name/": {
+                        "$ref": "https://example.com/@alice/types/property-type/name/v/1"
+                    },
+                    "https://blockprotocol.org/@alice/types/property-type/contrived-property/": {
+                        "type": "array",
+                        "items": {
+                            "$ref": "https://blockprotocol.org/@alice/types/property-type/contrived-property/v/1"
+                        }
+                    }
+                },
+                "required": ["https://blockprotocol.org/@alice/types/property-type/contrived-property/"],
+                "links": {
+                    "https://blockprotocol.org/@alice/types/link-type/contains/v/1": {
+                        "$ref": "https://blockprotocol.org/@alice/types/entity-type/song/v/1"
+                    }
+                },
+                "requiredLinks": ["https://blockprotocol.org/@alice/types/link-type/contains/v/1"]
+            }))
+            .expect("invalid entity type"),
+        );
+
+        let sdl = generate_sdl(&bundle).expect("could not generate schema");
+        assert!(sdl.contains(
+            "\"\"\"
+A contrived measurement
+\"\"\"
+type Measurement @entityType(id: \"https://blockprotocol.org/@alice/types/entity-type/measurement/v/1\") {
+  entityId: ID!
+  contrivedProperty: [ContrivedPropertyValue!]! @property(baseUri: \"https://blockprotocol.org/@alice/types/property-type/contrived-property/\")
+  name: String @property(baseUri: \"https://blockprotocol.org/@alice/types/property-type/name/\")
+  name2: Name @property(baseUri: \"https://example.com/@alice/types/property-type/name/\")
+  contains: Song! @link(linkType: \"https://blockprotocol.org/@alice/types/link-type/contains/v/1\")
+}"
+        ));
+        assert!(sdl.contains(
+            "union ContrivedPropertyValue = ContrivedPropertyFloat | ContrivedPropertyFloatList"
+        ));
+        assert!(sdl.contains("type ContrivedPropertyFloatList {\n  value: [Float!]!\n}"));
+        assert!(sdl.contains(
+            "input MeasurementCreateInput @entityType(id: \"https://blockprotocol.org/@alice/types/entity-type/measurement/v/1\") {
+  contrivedProperty: [JSON!]! @property(baseUri: \"https://blockprotocol.org/@alice/types/property-type/contrived-property/\")
+  name: String @property(baseUri: \"https://blockprotocol.org/@alice/types/property-type/name/\")
+  name2: NameInput @property(baseUri: \"https://example.com/@alice/types/property-type/name/\")
+}"
+        ));
+        assert!(sdl.contains("input NameInput {\n  name: String! @property"));
+        assert!(sdl.contains(
+            "input MeasurementUpdateInput @entityType(id: \"https://blockprotocol.org/@alice/types/entity-type/measurement/v/1\") {
+  contrivedProperty: [JSON!] @property"
+        ));
+        assert!(sdl.contains("scalar JSON"));
+    }
+
+    #[test]
+    fn recursive_property_types() {
+        let mut bundle = bundle();
+        bundle.insert_property_type(
+            PropertyType::from_str(test_data::property_type::TREE_NODE_V1)
+                .expect("invalid property type"),
+        );
+        bundle.insert_entity_type(
+            EntityType::from_str(test_data::entity_type::TREE_V1).expect("invalid entity type"),
+        );
+
+        let sdl = generate_sdl(&bundle).expect("could not generate schema");
+        assert!(sdl.contains(
+            "type TreeNode {
+  name: String! @property(baseUri: \"https://blockprotocol.org/@alice/types/property-type/name/\")
+  treeNode: [TreeNode!] @property(baseUri: \"https://blockprotocol.org/@alice/types/property-type/tree-node/\")
+}"
+        ));
+        assert!(sdl.contains("treeNode: [TreeNodeInput!] @property"));
+    }
+
+    #[test]
+    fn missing_types() {
+        let mut bundle = bundle();
+        bundle.insert_entity_type(
+            EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid entity type"),
+        );
+
+        assert_eq!(
+            generate_sdl(&bundle),
+            Err(GenerateSdlError::MissingType(
+                VersionedUri::from_str(
+                    "https://blockprotocol.org/@alice/types/property-type/blurb/v/1"
+                )
+                .expect("invalid URI")
+            ))
+        );
+    }
+}
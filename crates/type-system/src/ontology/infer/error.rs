@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::{uri::ParseTypeUriComponentsError, ValidationError};
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum InferTypesError {
+    #[error("no examples were provided")]
+    NoExamples,
+    #[error("example {0} is not a JSON object")]
+    NotAnObject(usize),
+    #[error("the key `{0}` cannot be turned into a type URI")]
+    InvalidKey(String),
+    #[error("invalid type URI: `{0}`")]
+    InvalidUri(ParseTypeUriComponentsError),
+    #[error("the inferred type is invalid: `{0}`")]
+    InvalidType(ValidationError),
+}
+
+impl From<ParseTypeUriComponentsError> for InferTypesError {
+    fn from(error: ParseTypeUriComponentsError) -> Self {
+        Self::InvalidUri(error)
+    }
+}
+
+impl From<ValidationError> for InferTypesError {
+    fn from(error: ValidationError) -> Self {
+        Self::InvalidType(error)
+    }
+}
@@ -0,0 +1,656 @@
+//! Inferring draft types from example entities.
+//!
+//! When migrating existing data into the Block Protocol, there are often plenty of entities but no
+//! types describing them. An [`EntityTypeInference`] proposes an [`EntityType`] and the
+//! [`PropertyType`]s it uses by looking at the values of example entities:
+//!
+//! - Every primitive value is assigned the corresponding Block Protocol [`DataType`], e.g. a string
+//!   is assigned the `Text` data type.
+//! - Every property, which does not refer to an existing property type, becomes a [`PropertyType`]
+//!   which has a `oneOf` variant for each kind of value observed for the property. Nested objects
+//!   become property type objects and nested arrays become arrays of property values.
+//! - A property which is an array in every example is an array of the property type inside of the
+//!   object containing it, limited to the observed number of items.
+//! - A property is required if it's present in a configurable percentage of the examples.
+//!
+//! The inferred types are drafts which are meant to be reviewed, e.g. they don't have a
+//! description and their plural titles are guessed.
+//!
+//! [`DataType`]: crate::DataType
+
+mod error;
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+pub use error::InferTypesError;
+use serde_json::{Map, Value};
+
+use crate::{
+    ontology::shared::naming::{plural, slug, title, words, Primitive},
+    uri::{BaseUri, TypeUriComponents, VersionedUri},
+    Array, EntityType, Links, Object, OneOf, OntologyTypeKind, PropertyType, PropertyTypeReference,
+    PropertyValues, TypeBundle, ValidationError, ValueOrArray,
+};
+
+/// The values observed for a property type.
+#[derive(Debug, Default)]
+struct ValueObservations {
+    primitives: BTreeSet<Primitive>,
+    object: bool,
+    array: Option<Box<ArrayObservations>>,
+}
+
+#[derive(Debug)]
+struct ArrayObservations {
+    items: ValueObservations,
+    min_items: usize,
+    max_items: usize,
+}
+
+impl ValueObservations {
+    /// Records `value` and collects the non-empty objects inside of it into `objects`.
+    fn record<'a>(&mut self, value: &'a Value, objects: &mut Vec<&'a Map<String, Value>>) {
+        match value {
+            Value::String(_) => {
+                self.primitives.insert(Primitive::Text);
+            }
+            Value::Number(_) => {
+                self.primitives.insert(Primitive::Number);
+            }
+            Value::Bool(_) => {
+                self.primitives.insert(Primitive::Boolean);
+            }
+            Value::Null => {
+                self.primitives.insert(Primitive::Null);
+            }
+            Value::Object(object) if object.is_empty() => {
+                self.primitives.insert(Primitive::Object);
+            }
+            Value::Object(object) => {
+                self.object = true;
+                objects.push(object);
+            }
+            Value::Array(items) => {
+                let array = self.array.get_or_insert_with(|| {
+                    Box::new(ArrayObservations {
+                        items: Self::default(),
+                        min_items: items.len(),
+                        max_items: items.len(),
+                    })
+                });
+                array.min_items = array.min_items.min(items.len());
+                array.max_items = array.max_items.max(items.len());
+                for item in items {
+                    array.items.record(item, objects);
+                }
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.primitives.is_empty() && !self.object && self.array.is_none()
+    }
+}
+
+/// How often a property occurred inside of an object and whether it was an array.
+#[derive(Debug, Default)]
+struct PropertyOccurrences {
+    count: usize,
+    arrays: usize,
+    items: usize,
+    min_items: Option<usize>,
+    max_items: usize,
+}
+
+impl PropertyOccurrences {
+    fn record(&mut self, value: &Value) {
+        self.count += 1;
+        if let Value::Array(items) = value {
+            self.arrays += 1;
+            self.items += items.len();
+            self.min_items = Some(
+                self.min_items
+                    .map_or(items.len(), |min| min.min(items.len())),
+            );
+            self.max_items = self.max_items.max(items.len());
+        }
+    }
+
+    /// A property is an array of its property type if it has always been a non-empty array.
+    const fn is_array(&self) -> bool {
+        self.arrays == self.count && self.items > 0
+    }
+}
+
+/// The properties observed inside of the objects at one position, i.e. either the examples
+/// themselves or the object values of a property type.
+#[derive(Debug, Default)]
+struct ObjectObservations {
+    count: usize,
+    properties: BTreeMap<String, PropertyOccurrences>,
+}
+
+#[derive(Debug)]
+struct PropertyDraft {
+    base_uri: BaseUri,
+    /// Whether the property type already exists, in which case it's only referenced.
+    existing: bool,
+    title: String,
+    values: ValueObservations,
+}
+
+/// Proposes draft types for example entities.
+///
+/// The keys of the example entities are either base URIs of property types, or plain keys. Plain
+/// keys are either mapped to a base URI by using [`with_key`], or a new property type is created
+/// for them inside of the configured namespace. Only the new property types are drafted, the
+/// others are referenced as they are.
+///
+/// [`with_key`]: Self::with_key
+#[derive(Debug, Clone)]
+pub struct EntityTypeInference {
+    host: String,
+    namespace: String,
+    keys: HashMap<String, BaseUri>,
+    required_percentage: u8,
+}
+
+impl EntityTypeInference {
+    /// Creates an inference which creates new types at `https://<host>/@<namespace>/types/`.
+    ///
+    /// By default, properties are required if they are present in every example.
+    #[must_use]
+    pub fn new(host: impl Into<String>, namespace: impl Into<String>) -> Self {
+        Self {
+            host: host.into(),
+            namespace: namespace.into(),
+            keys: HashMap::new(),
+            required_percentage: 100,
+        }
+    }
+
+    /// Uses the property type identified by `base_uri` for the plain key `key`.
+    #[must_use]
+    pub fn with_key(mut self, key: impl Into<String>, base_uri: BaseUri) -> Self {
+        self.keys.insert(key.into(), base_uri);
+        self
+    }
+
+    /// Marks properties as required if they are present in at least `percentage` percent of the
+    /// objects containing them.
+    ///
+    /// A percentage above `100` results in no property being required.
+    #[must_use]
+    pub const fn with_required_percentage(mut self, percentage: u8) -> Self {
+        self.required_percentage = percentage;
+        self
+    }
+
+    /// Returns the base URI of the property type used for `key` and whether the property type
+    /// already exists.
+    fn base_uri(&self, key: &str) -> Result<(BaseUri, bool), InferTypesError> {
+        if let Some(base_uri) = self.keys.get(key) {
+            return Ok((base_uri.clone(), true));
+        }
+        if let Ok(base_uri) = BaseUri::new(key.to_owned()) {
+            return Ok((base_uri, true));
+        }
+
+        let slug = slug(&words(key));
+        if slug.is_empty() {
+            return Err(InferTypesError::InvalidKey(key.to_owned()));
+        }
+        let base_uri = TypeUriComponents::new(
+            self.host.clone(),
+            self.namespace.clone(),
+            OntologyTypeKind::PropertyType,
+            slug,
+        )?
+        .to_base_uri();
+        Ok((base_uri, false))
+    }
+
+    /// Proposes an [`EntityType`] with the given `title` for `examples` alongside the
+    /// [`PropertyType`]s it uses.
+    ///
+    /// # Errors
+    ///
+    /// - [`InferTypesError::NoExamples`] if `examples` is empty
+    /// - [`InferTypesError::NotAnObject`] if an example is not a JSON object
+    /// - [`InferTypesError::InvalidKey`] if no URI can be created for a key
+    /// - [`InferTypesError::InvalidUri`] if a URI for a new type cannot be created from the
+    ///   configured host and namespace
+    pub fn infer(&self, title: &str, examples: &[Value]) -> Result<DraftTypes, InferTypesError> {
+        if examples.is_empty() {
+            return Err(InferTypesError::NoExamples);
+        }
+        let examples = examples
+            .iter()
+            .enumerate()
+            .map(|(index, example)| {
+                example
+                    .as_object()
+                    .ok_or(InferTypesError::NotAnObject(index))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut inference = Inference {
+            config: self,
+            objects: BTreeMap::new(),
+            properties: BTreeMap::new(),
+        };
+        for example in &examples {
+            inference.observe_object(None, example)?;
+        }
+        for example in &examples {
+            inference.record_object(None, example)?;
+        }
+
+        let id = VersionedUri::new(
+            TypeUriComponents::new(
+                self.host.clone(),
+                self.namespace.clone(),
+                OntologyTypeKind::EntityType,
+                slug(&words(title)),
+            )?
+            .to_base_uri(),
+            1,
+        );
+        let entity_type = EntityType::new(
+            id,
+            title.to_owned(),
+            plural(title),
+            None,
+            inference.object(None)?,
+            Links::new(HashMap::new(), Vec::new())?,
+            HashMap::new(),
+            Vec::new(),
+        );
+        let property_types = inference
+            .properties
+            .values()
+            .filter(|draft| !draft.existing)
+            .map(|draft| inference.property_type(draft))
+            .collect::<Result<_, _>>()?;
+
+        Ok(DraftTypes {
+            entity_type,
+            property_types,
+        })
+    }
+}
+
+/// The state of a single call to [`EntityTypeInference::infer`].
+///
+/// The examples are traversed twice: the first pass determines whether a property is an array of
+/// its property type, which is required to know which values the second pass has to record for
+/// the property type.
+struct Inference<'a> {
+    config: &'a EntityTypeInference,
+    /// The objects containing properties, keyed by the property type they are a value of.
+    objects: BTreeMap<Option<String>, ObjectObservations>,
+    properties: BTreeMap<String, PropertyDraft>,
+}
+
+impl Inference<'_> {
+    fn observe_object(
+        &mut self,
+        position: Option<&str>,
+        object: &Map<String, Value>,
+    ) -> Result<(), InferTypesError> {
+        let observations = self
+            .objects
+            .entry(position.map(ToOwned::to_owned))
+            .or_default();
+        observations.count += 1;
+
+        let mut nested = Vec::new();
+        for (key, value) in object {
+            let (base_uri, existing) = self.config.base_uri(key)?;
+            let property = base_uri.to_string();
+            observations
+                .properties
+                .entry(property.clone())
+                .or_default()
+                .record(value);
+
+            self.properties.entry(property.clone()).or_insert_with(|| {
+                let words = base_uri
+                    .components()
+                    .map_or_else(|_| words(key), |components| words(components.slug()));
+                PropertyDraft {
+                    base_uri,
+                    existing,
+                    title: title(&words),
+                    values: ValueObservations::default(),
+                }
+            });
+            if existing {
+                continue;
+            }
+
+            let mut objects = Vec::new();
+            ValueObservations::default().record(value, &mut objects);
+            nested.extend(objects.into_iter().map(|object| (property.clone(), object)));
+        }
+
+        for (property, object) in nested {
+            self.observe_object(Some(&property), object)?;
+        }
+        Ok(())
+    }
+
+    fn record_object(
+        &mut self,
+        position: Option<&str>,
+        object: &Map<String, Value>,
+    ) -> Result<(), InferTypesError> {
+        let mut nested = Vec::new();
+        for (key, value) in object {
+            let (base_uri, existing) = self.config.base_uri(key)?;
+            if existing {
+                continue;
+            }
+            let property = base_uri.to_string();
+            let is_array =
+                self.objects[&position.map(ToOwned::to_owned)].properties[&property].is_array();
+            let values = &mut self
+                .properties
+                .get_mut(&property)
+                .expect("all properties were observed")
+                .values;
+
+            let mut objects = Vec::new();
+            match value {
+                Value::Array(items) if is_array => {
+                    for item in items {
+                        values.record(item, &mut objects);
+                    }
+                }
+                _ => values.record(value, &mut objects),
+            }
+            nested.extend(objects.into_iter().map(|object| (property.clone(), object)));
+        }
+
+        for (property, object) in nested {
+            self.record_object(Some(&property), object)?;
+        }
+        Ok(())
+    }
+
+    fn object<const MIN: usize>(
+        &self,
+        position: Option<&str>,
+    ) -> Result<Object<ValueOrArray<PropertyTypeReference>, MIN>, ValidationError> {
+        let observations = &self.objects[&position.map(ToOwned::to_owned)];
+
+        let mut properties = HashMap::new();
+        let mut required = Vec::new();
+        for (property, occurrences) in &observations.properties {
+            let base_uri = self.properties[property].base_uri.clone();
+            let reference = PropertyTypeReference::new(VersionedUri::new(base_uri.clone(), 1));
+            let value = if occurrences.is_array() {
+                ValueOrArray::Array(Array::new(
+                    reference,
+                    occurrences.min_items,
+                    Some(occurrences.max_items),
+                )?)
+            } else {
+                ValueOrArray::Value(reference)
+            };
+
+            if occurrences.count * 100
+                >= observations.count * usize::from(self.config.required_percentage)
+            {
+                required.push(base_uri.clone());
+            }
+            properties.insert(base_uri, value);
+        }
+
+        Object::new(properties, required)
+    }
+
+    fn values(
+        &self,
+        property: &str,
+        values: &ValueObservations,
+    ) -> Result<Vec<PropertyValues>, ValidationError> {
+        let mut primitives = values.primitives.clone();
+        let mut array = None;
+        if let Some(observations) = &values.array {
+            if observations.items.is_empty() {
+                // Only empty arrays were observed, so nothing is known about their items
+                primitives.insert(Primitive::EmptyList);
+            } else {
+                array = Some(PropertyValues::ArrayOfPropertyValues(Array::new(
+                    OneOf::new(self.values(property, &observations.items)?)?,
+                    Some(observations.min_items),
+                    Some(observations.max_items),
+                )?));
+            }
+        }
+
+        let mut variants: Vec<_> = primitives
+            .into_iter()
+            .map(|primitive| PropertyValues::DataTypeReference(primitive.reference()))
+            .collect();
+        if values.object {
+            variants.push(PropertyValues::PropertyTypeObject(
+                self.object(Some(property))?,
+            ));
+        }
+        variants.extend(array);
+        Ok(variants)
+    }
+
+    fn property_type(&self, draft: &PropertyDraft) -> Result<PropertyType, InferTypesError> {
+        let property = draft.base_uri.to_string();
+        Ok(PropertyType::new(
+            VersionedUri::new(draft.base_uri.clone(), 1),
+            draft.title.clone(),
+            plural(&draft.title),
+            None,
+            OneOf::new(self.values(&property, &draft.values)?)?,
+        ))
+    }
+}
+
+/// The types proposed by an [`EntityTypeInference`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DraftTypes {
+    entity_type: EntityType,
+    property_types: Vec<PropertyType>,
+}
+
+impl DraftTypes {
+    #[must_use]
+    pub const fn entity_type(&self) -> &EntityType {
+        &self.entity_type
+    }
+
+    /// Returns the drafted property types used by the entity type, sorted by their
+    /// [`VersionedUri`].
+    #[must_use]
+    pub fn property_types(&self) -> &[PropertyType] {
+        &self.property_types
+    }
+}
+
+impl From<DraftTypes> for TypeBundle {
+    fn from(types: DraftTypes) -> Self {
+        let mut bundle = Self::new();
+        bundle.insert_entity_type(types.entity_type);
+        for property_type in types.property_types {
+            bundle.insert_property_type(property_type);
+        }
+        bundle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::Compatibility;
+
+    fn base_uri(slug: &str) -> BaseUri {
+        BaseUri::new(format!(
+            "https://example.com/@migration/types/property-type/{slug}/"
+        ))
+        .expect("invalid base URI")
+    }
+
+    #[test]
+    fn infer() {
+        let name = "https://blockprotocol.org/@alice/types/property-type/name/";
+        let examples = [
+            json!({
+                name: "Alice",
+                "age": 42,
+                "tags": ["a", "b"],
+                "address": { "street": "Main Street", "number": 1 }
+            }),
+            json!({
+                name: "Bob",
+                "age": null,
+                "tags": ["c"],
+                "address": { "street": "High Street" },
+                "nickname": "bobby"
+            }),
+        ];
+
+        let types = EntityTypeInference::new("example.com", "migration")
+            .infer("Person", &examples)
+            .expect("failed to infer types");
+
+        let expected_entity_type = EntityType::try_from(json!({
+            "kind": "entityType",
+            "$id": "https://example.com/@migration/types/entity-type/person/v/1",
+            "type": "object",
+            "title": "Person",
+            "pluralTitle": "Persons",
+            "properties": {
+                name: { "$ref": format!("{name}v/1") },
+                base_uri("age").to_string(): { "$ref": format!("{}v/1", base_uri("age")) },
+                base_uri("tags").to_string(): {
+                    "type": "array",
+                    "items": { "$ref": format!("{}v/1", base_uri("tags")) },
+                    "minItems": 1,
+                    "maxItems": 2
+                },
+                base_uri("address").to_string(): { "$ref": format!("{}v/1", base_uri("address")) },
+                base_uri("nickname").to_string(): { "$ref": format!("{}v/1", base_uri("nickname")) }
+            },
+            "required": [name, base_uri("age"), base_uri("tags"), base_uri("address")]
+        }))
+        .expect("invalid entity type");
+        assert!(types.entity_type().is_equivalent_to(&expected_entity_type));
+
+        let property_type = |slug: &str| {
+            types
+                .property_types()
+                .iter()
+                .find(|property_type| property_type.id().base_uri() == &base_uri(slug))
+                .map(|property_type| serde_json::Value::from(property_type.clone()))
+                .expect("missing property type")
+        };
+        let data_type = |slug: &str| {
+            json!({
+                "$ref":
+                    format!("https://blockprotocol.org/@blockprotocol/types/data-type/{slug}/v/1")
+            })
+        };
+
+        // The existing `name` property type is only referenced
+        assert_eq!(types.property_types().len(), 6);
+        assert_eq!(
+            property_type("age")["oneOf"],
+            json!([data_type("number"), data_type("null")])
+        );
+        assert_eq!(property_type("tags")["oneOf"], json!([data_type("text")]));
+        assert_eq!(
+            property_type("address")["oneOf"],
+            json!([{
+                "type": "object",
+                "properties": {
+                    base_uri("number").to_string(): { "$ref": format!("{}v/1", base_uri("number")) },
+                    base_uri("street").to_string(): { "$ref": format!("{}v/1", base_uri("street")) }
+                },
+                "required": [base_uri("street")]
+            }])
+        );
+        assert_eq!(TypeBundle::from(types).check_satisfiability(), Ok(()));
+    }
+
+    #[test]
+    fn mixed_shapes() {
+        let examples = [
+            json!({ "value": [[1, 2], []], "list": [] }),
+            json!({ "value": "text", "list": [] }),
+        ];
+
+        let types = EntityTypeInference::new("example.com", "migration")
+            .with_key("list", base_uri("items"))
+            .with_required_percentage(50)
+            .infer("Mixed", &examples)
+            .expect("failed to infer types");
+        assert_eq!(types.entity_type().required().len(), 2);
+        assert!(
+            types
+                .entity_type()
+                .properties()
+                .contains_key(&base_uri("items"))
+        );
+
+        // The mapped `list` key refers to an existing property type, so only `value` is drafted
+        let [value] = types.property_types() else {
+            panic!("expected a single property type");
+        };
+        let value = value
+            .one_of()
+            .iter()
+            .cloned()
+            .map(serde_json::Value::from)
+            .collect::<Vec<_>>();
+        assert_eq!(value, [
+            json!({ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" }),
+            json!({
+                "type": "array",
+                "items": {
+                    "oneOf": [{
+                        "type": "array",
+                        "items": {
+                            "oneOf": [{ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1" }]
+                        },
+                        "minItems": 0,
+                        "maxItems": 2
+                    }]
+                },
+                "minItems": 2,
+                "maxItems": 2
+            }),
+        ]);
+    }
+
+    #[test]
+    fn invalid_examples() {
+        let inference = EntityTypeInference::new("example.com", "migration");
+        assert_eq!(
+            inference.infer("Empty", &[]),
+            Err(InferTypesError::NoExamples)
+        );
+        assert_eq!(
+            inference.infer("Empty", &[json!({}), json!([])]),
+            Err(InferTypesError::NotAnObject(1))
+        );
+        assert_eq!(
+            inference.infer("Empty", &[json!({ "???": 1 })]),
+            Err(InferTypesError::InvalidKey("???".to_owned()))
+        );
+        assert!(matches!(
+            EntityTypeInference::new("example.com", "a/b").infer("Empty", &[json!({})]),
+            Err(InferTypesError::InvalidUri(_))
+        ));
+    }
+}
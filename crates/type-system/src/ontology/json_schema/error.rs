@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::{
+    uri::{ParseTypeUriComponentsError, VersionedUri},
+    ValidationError,
+};
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum ImportJsonSchemaError {
+    #[error("the value at `{0}` is not a JSON Schema")]
+    InvalidSchema(String),
+    #[error("the schema does not describe an object")]
+    NotAnObjectSchema,
+    #[error("the schema does not have a title")]
+    MissingTitle,
+    #[error("the property `{0}` cannot be turned into a type URI")]
+    InvalidKey(String),
+    #[error("invalid type URI: `{0}`")]
+    InvalidUri(ParseTypeUriComponentsError),
+    #[error("the imported type is invalid: `{0}`")]
+    InvalidType(ValidationError),
+    #[error("a different type with the URI `{0}` already exists")]
+    Conflict(VersionedUri),
+}
+
+impl From<ParseTypeUriComponentsError> for ImportJsonSchemaError {
+    fn from(error: ParseTypeUriComponentsError) -> Self {
+        Self::InvalidUri(error)
+    }
+}
+
+impl From<ValidationError> for ImportJsonSchemaError {
+    fn from(error: ValidationError) -> Self {
+        Self::InvalidType(error)
+    }
+}
@@ -0,0 +1,913 @@
+//! Importing plain JSON Schemas as types of the Type System.
+//!
+//! A [`JsonSchemaImport`] converts a JSON Schema describing an object into an [`EntityType`]:
+//!
+//! - Every property of an object becomes a [`PropertyType`] whose `oneOf` contains a variant for
+//!   each type the property schema allows. Nested objects become property type objects, and `oneOf`
+//!   is merged into the variants.
+//! - A property which is an array becomes an array of its property type inside of the object
+//!   containing it. Arrays nested inside of property values become arrays of property values.
+//! - Unconstrained primitives are assigned the corresponding Block Protocol [`DataType`]. A
+//!   primitive with constraints, e.g. `format` or `maxLength`, becomes a new [`DataType`] carrying
+//!   the constraints.
+//!
+//! Constructs which cannot be represented by the Type System, e.g. `additionalProperties: true`,
+//! `patternProperties`, or `$ref`, are skipped and reported as [`UnsupportedConstruct`]s.
+
+mod error;
+
+use std::{collections::HashMap, fmt};
+
+pub use error::ImportJsonSchemaError;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{
+    ontology::shared::{
+        naming::{plural, slug, title, words, Primitive},
+        pointer::child,
+    },
+    repository::TypeResolver,
+    uri::{BaseUri, TypeUriComponents, VersionedUri},
+    Array, Compatibility, DataType, DataTypeReference, EntityType, LinkType, Links, Object, OneOf,
+    OntologyType, OntologyTypeKind, PropertyType, PropertyTypeReference, PropertyValues,
+    TypeBundle, ValueOrArray,
+};
+
+/// Keywords which cannot be represented by the Type System regardless of the type of the value.
+const UNSUPPORTED: &[&str] = &[
+    "$ref",
+    "$dynamicRef",
+    "allOf",
+    "anyOf",
+    "not",
+    "if",
+    "then",
+    "else",
+];
+
+/// Keywords constraining objects which cannot be represented by the Type System.
+///
+/// `additionalProperties` and `unevaluatedProperties` are only supported if they are `false`, as
+/// objects of the Type System don't allow additional properties.
+const UNSUPPORTED_OBJECT: &[&str] = &[
+    "patternProperties",
+    "propertyNames",
+    "minProperties",
+    "maxProperties",
+    "dependentRequired",
+    "dependentSchemas",
+];
+
+/// Keywords constraining arrays which cannot be represented by the Type System.
+const UNSUPPORTED_ARRAY: &[&str] = &["prefixItems", "contains", "uniqueItems", "unevaluatedItems"];
+
+/// Keywords constraining primitives, which are kept on the [`DataType`] created for them.
+const STRING_CONSTRAINTS: &[&str] = &[
+    "minLength",
+    "maxLength",
+    "pattern",
+    "format",
+    "contentEncoding",
+    "contentMediaType",
+];
+const NUMBER_CONSTRAINTS: &[&str] = &[
+    "minimum",
+    "maximum",
+    "exclusiveMinimum",
+    "exclusiveMaximum",
+    "multipleOf",
+];
+const VALUE_CONSTRAINTS: &[&str] = &["enum", "const"];
+
+/// A construct of a JSON Schema which cannot be represented by the Type System and was skipped.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsupportedConstruct {
+    path: String,
+    keyword: String,
+}
+
+impl UnsupportedConstruct {
+    /// The JSON Pointer to the schema containing the construct.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The keyword of the construct, or the schema itself if it's a boolean schema.
+    #[must_use]
+    pub fn keyword(&self) -> &str {
+        &self.keyword
+    }
+}
+
+impl fmt::Display for UnsupportedConstruct {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "`{}` at `{}` cannot be represented and was skipped",
+            self.keyword, self.path
+        )
+    }
+}
+
+/// Converts JSON Schemas into types inside of a namespace.
+///
+/// New types are created at `https://<host>/@<namespace>/types/` with version `1`. Properties
+/// whose name is a base URI use the property type identified by it instead.
+#[derive(Debug, Clone)]
+pub struct JsonSchemaImport {
+    host: String,
+    namespace: String,
+}
+
+impl JsonSchemaImport {
+    #[must_use]
+    pub fn new(host: impl Into<String>, namespace: impl Into<String>) -> Self {
+        Self {
+            host: host.into(),
+            namespace: namespace.into(),
+        }
+    }
+
+    fn uri(&self, kind: OntologyTypeKind, name: &str) -> Result<BaseUri, ImportJsonSchemaError> {
+        let slug = slug(&words(name));
+        if slug.is_empty() {
+            return Err(ImportJsonSchemaError::InvalidKey(name.to_owned()));
+        }
+        Ok(
+            TypeUriComponents::new(self.host.clone(), self.namespace.clone(), kind, slug)?
+                .to_base_uri(),
+        )
+    }
+
+    /// Converts `schema` into an [`EntityType`] and the types it uses.
+    ///
+    /// # Errors
+    ///
+    /// See [`import_with_resolver`].
+    ///
+    /// [`import_with_resolver`]: Self::import_with_resolver
+    pub fn import(&self, schema: &Value) -> Result<ImportedTypes, ImportJsonSchemaError> {
+        self.import_with_resolver(schema, &Unresolvable)
+    }
+
+    /// Converts `schema` into an [`EntityType`] and the types it uses, reusing the types provided
+    /// by `resolver`.
+    ///
+    /// Before a type is created, `resolver` is asked for a type with the same [`VersionedUri`]. If
+    /// it's equivalent to the type which would be created, it's reused instead. A type which
+    /// cannot be resolved is created.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidSchema`] if a value inside of `schema` is not a schema
+    /// - [`NotAnObjectSchema`] if `schema` does not describe an object
+    /// - [`MissingTitle`] if `schema` does not have a title
+    /// - [`InvalidKey`] if no URI can be created for a property
+    /// - [`InvalidUri`] if a URI for a new type cannot be created from the configured host and
+    ///   namespace
+    /// - [`InvalidType`] if a created type is not valid
+    /// - [`Conflict`] if two different types would be created with the same URI, or `resolver`
+    ///   provides a different type with the URI of a created type
+    ///
+    /// [`InvalidSchema`]: ImportJsonSchemaError::InvalidSchema
+    /// [`NotAnObjectSchema`]: ImportJsonSchemaError::NotAnObjectSchema
+    /// [`MissingTitle`]: ImportJsonSchemaError::MissingTitle
+    /// [`InvalidKey`]: ImportJsonSchemaError::InvalidKey
+    /// [`InvalidUri`]: ImportJsonSchemaError::InvalidUri
+    /// [`InvalidType`]: ImportJsonSchemaError::InvalidType
+    /// [`Conflict`]: ImportJsonSchemaError::Conflict
+    pub fn import_with_resolver<R: TypeResolver>(
+        &self,
+        schema: &Value,
+        resolver: &R,
+    ) -> Result<ImportedTypes, ImportJsonSchemaError> {
+        let schema = schema
+            .as_object()
+            .ok_or_else(|| ImportJsonSchemaError::InvalidSchema(String::new()))?;
+        let is_object = schema.get("type").map_or_else(
+            || schema.contains_key("properties"),
+            |json_type| json_type == "object",
+        );
+        if !is_object {
+            return Err(ImportJsonSchemaError::NotAnObjectSchema);
+        }
+        let title = schema
+            .get("title")
+            .and_then(Value::as_str)
+            .ok_or(ImportJsonSchemaError::MissingTitle)?;
+
+        let mut importer = Importer {
+            config: self,
+            resolver,
+            types: TypeBundle::new(),
+            reused: Vec::new(),
+            unsupported: Vec::new(),
+        };
+        importer.check_keywords("", schema, UNSUPPORTED);
+        let entity_type = EntityType::new(
+            VersionedUri::new(self.uri(OntologyTypeKind::EntityType, title)?, 1),
+            title.to_owned(),
+            plural(title),
+            description(schema),
+            importer.object("", schema)?,
+            Links::new(HashMap::new(), Vec::new())?,
+            HashMap::new(),
+            Vec::new(),
+        );
+        importer.add(entity_type.clone())?;
+
+        importer.reused.sort_by_key(ToString::to_string);
+        importer.reused.dedup();
+        Ok(ImportedTypes {
+            entity_type,
+            types: importer.types,
+            reused: importer.reused,
+            unsupported: importer.unsupported,
+        })
+    }
+}
+
+fn description(schema: &Map<String, Value>) -> Option<String> {
+    schema
+        .get("description")
+        .and_then(Value::as_str)
+        .map(ToOwned::to_owned)
+}
+
+/// A [`TypeResolver`] which doesn't provide any types.
+struct Unresolvable;
+
+impl TypeResolver for Unresolvable {
+    type Error = ();
+
+    fn resolve_data_type(&self, _: &VersionedUri) -> Result<DataType, Self::Error> {
+        Err(())
+    }
+
+    fn resolve_property_type(&self, _: &VersionedUri) -> Result<PropertyType, Self::Error> {
+        Err(())
+    }
+
+    fn resolve_entity_type(&self, _: &VersionedUri) -> Result<EntityType, Self::Error> {
+        Err(())
+    }
+
+    fn resolve_link_type(&self, _: &VersionedUri) -> Result<LinkType, Self::Error> {
+        Err(())
+    }
+}
+
+/// A type which can be created by an import.
+trait ImportedType: OntologyType + Compatibility + PartialEq + Sized {
+    fn resolve<R: TypeResolver>(resolver: &R, uri: &VersionedUri) -> Option<Self>;
+
+    fn get<'b>(bundle: &'b TypeBundle, uri: &VersionedUri) -> Option<&'b Self>;
+
+    fn insert(self, bundle: &mut TypeBundle);
+}
+
+impl ImportedType for DataType {
+    fn resolve<R: TypeResolver>(resolver: &R, uri: &VersionedUri) -> Option<Self> {
+        resolver.resolve_data_type(uri).ok()
+    }
+
+    fn get<'b>(bundle: &'b TypeBundle, uri: &VersionedUri) -> Option<&'b Self> {
+        bundle.data_type(uri)
+    }
+
+    fn insert(self, bundle: &mut TypeBundle) {
+        bundle.insert_data_type(self);
+    }
+}
+
+impl ImportedType for PropertyType {
+    fn resolve<R: TypeResolver>(resolver: &R, uri: &VersionedUri) -> Option<Self> {
+        resolver.resolve_property_type(uri).ok()
+    }
+
+    fn get<'b>(bundle: &'b TypeBundle, uri: &VersionedUri) -> Option<&'b Self> {
+        bundle.property_type(uri)
+    }
+
+    fn insert(self, bundle: &mut TypeBundle) {
+        bundle.insert_property_type(self);
+    }
+}
+
+impl ImportedType for EntityType {
+    fn resolve<R: TypeResolver>(resolver: &R, uri: &VersionedUri) -> Option<Self> {
+        resolver.resolve_entity_type(uri).ok()
+    }
+
+    fn get<'b>(bundle: &'b TypeBundle, uri: &VersionedUri) -> Option<&'b Self> {
+        bundle.entity_type(uri)
+    }
+
+    fn insert(self, bundle: &mut TypeBundle) {
+        bundle.insert_entity_type(self);
+    }
+}
+
+/// The state of a single call to [`JsonSchemaImport::import_with_resolver`].
+struct Importer<'a, R> {
+    config: &'a JsonSchemaImport,
+    resolver: &'a R,
+    types: TypeBundle,
+    reused: Vec<VersionedUri>,
+    unsupported: Vec<UnsupportedConstruct>,
+}
+
+impl<R: TypeResolver> Importer<'_, R> {
+    fn report(&mut self, path: &str, keyword: impl Into<String>) {
+        self.unsupported.push(UnsupportedConstruct {
+            path: path.to_owned(),
+            keyword: keyword.into(),
+        });
+    }
+
+    fn check_keywords(&mut self, path: &str, schema: &Map<String, Value>, keywords: &[&str]) {
+        for keyword in keywords {
+            if schema.contains_key(*keyword) {
+                self.report(path, *keyword);
+            }
+        }
+    }
+
+    /// Adds `imported` to the created types unless an equivalent type can be reused.
+    fn add<T: ImportedType>(&mut self, imported: T) -> Result<(), ImportJsonSchemaError> {
+        let id = imported.versioned_uri().clone();
+        if let Some(existing) = T::get(&self.types, &id) {
+            return if *existing == imported {
+                Ok(())
+            } else {
+                Err(ImportJsonSchemaError::Conflict(id))
+            };
+        }
+
+        match T::resolve(self.resolver, &id) {
+            Some(existing) if existing.is_equivalent_to(&imported) => self.reused.push(id),
+            Some(_) => return Err(ImportJsonSchemaError::Conflict(id)),
+            None => imported.insert(&mut self.types),
+        }
+        Ok(())
+    }
+
+    fn object<const MIN: usize>(
+        &mut self,
+        path: &str,
+        schema: &Map<String, Value>,
+    ) -> Result<Object<ValueOrArray<PropertyTypeReference>, MIN>, ImportJsonSchemaError> {
+        self.check_keywords(path, schema, UNSUPPORTED_OBJECT);
+        for keyword in ["additionalProperties", "unevaluatedProperties"] {
+            if schema.get(keyword).map_or(false, |value| value != false) {
+                self.report(path, keyword);
+            }
+        }
+
+        let mut properties = HashMap::new();
+        let mut names = HashMap::new();
+        if let Some(schemas) = schema.get("properties") {
+            let schemas = schemas
+                .as_object()
+                .ok_or_else(|| ImportJsonSchemaError::InvalidSchema(child(path, "properties")))?;
+            for (name, property_schema) in schemas {
+                let property_path = child(&child(path, "properties"), name);
+                let base_uri = match BaseUri::new(name.clone()) {
+                    Ok(base_uri) => base_uri,
+                    Err(_) => self.config.uri(OntologyTypeKind::PropertyType, name)?,
+                };
+                if let Some(property) =
+                    self.property(&property_path, &base_uri, name, property_schema)?
+                {
+                    names.insert(name.as_str(), base_uri.clone());
+                    properties.insert(base_uri, property);
+                }
+            }
+        }
+
+        let mut required = Vec::new();
+        if let Some(names_required) = schema.get("required") {
+            let names_required = names_required
+                .as_array()
+                .ok_or_else(|| ImportJsonSchemaError::InvalidSchema(child(path, "required")))?;
+            for (index, name) in names_required.iter().enumerate() {
+                match name.as_str().and_then(|name| names.get(name)) {
+                    Some(base_uri) => required.push(base_uri.clone()),
+                    None => self.report(&child(&child(path, "required"), index), "required"),
+                }
+            }
+        }
+
+        Ok(Object::new(properties, required)?)
+    }
+
+    /// Converts the schema of the property `name` into a property type.
+    ///
+    /// Returns `None` if none of the values allowed by the schema can be represented.
+    fn property(
+        &mut self,
+        path: &str,
+        base_uri: &BaseUri,
+        name: &str,
+        schema: &Value,
+    ) -> Result<Option<ValueOrArray<PropertyTypeReference>>, ImportJsonSchemaError> {
+        let object = schema.as_object().filter(|object| {
+            object
+                .get("type")
+                .map_or(false, |json_type| json_type == "array")
+        });
+        let (values_path, values_schema, array) =
+            match object.map(|object| (object, object.get("items"))) {
+                Some((object, Some(items))) if items.is_object() => {
+                    self.check_keywords(path, object, UNSUPPORTED);
+                    self.check_keywords(path, object, UNSUPPORTED_ARRAY);
+                    (child(path, "items"), items, Some(object))
+                }
+                _ => (path.to_owned(), schema, None),
+            };
+
+        let title = values_schema
+            .get("title")
+            .or_else(|| array.and_then(|array| array.get("title")))
+            .and_then(Value::as_str)
+            .map_or_else(
+                || {
+                    base_uri.components().map_or_else(
+                        |_| title(&words(name)),
+                        |components| title(&words(components.slug())),
+                    )
+                },
+                ToOwned::to_owned,
+            );
+        let values = self.values(&values_path, &title, values_schema)?;
+        if values.is_empty() {
+            return Ok(None);
+        }
+
+        let description = values_schema
+            .as_object()
+            .and_then(description)
+            .or_else(|| array.and_then(description));
+        let id = VersionedUri::new(base_uri.clone(), 1);
+        self.add(PropertyType::new(
+            id.clone(),
+            title.clone(),
+            plural(&title),
+            description,
+            OneOf::new(values)?,
+        ))?;
+
+        let reference = PropertyTypeReference::new(id);
+        Ok(Some(match array {
+            Some(array) => ValueOrArray::Array(Array::new(
+                reference,
+                item_bound(array, "minItems"),
+                item_bound(array, "maxItems"),
+            )?),
+            None => ValueOrArray::Value(reference),
+        }))
+    }
+
+    /// Converts `schema` into the variants of a property type.
+    fn values(
+        &mut self,
+        path: &str,
+        title: &str,
+        schema: &Value,
+    ) -> Result<Vec<PropertyValues>, ImportJsonSchemaError> {
+        let schema = match schema {
+            Value::Object(schema) => schema,
+            Value::Bool(_) => {
+                self.report(path, schema.to_string());
+                return Ok(Vec::new());
+            }
+            _ => return Err(ImportJsonSchemaError::InvalidSchema(path.to_owned())),
+        };
+        self.check_keywords(path, schema, UNSUPPORTED);
+
+        let mut values = Vec::new();
+        if let Some(one_of) = schema.get("oneOf") {
+            let one_of = one_of
+                .as_array()
+                .ok_or_else(|| ImportJsonSchemaError::InvalidSchema(child(path, "oneOf")))?;
+            for (index, variant) in one_of.iter().enumerate() {
+                values.extend(self.values(&child(&child(path, "oneOf"), index), title, variant)?);
+            }
+        }
+
+        let json_types = match schema.get("type") {
+            Some(Value::String(json_type)) => vec![json_type.as_str()],
+            Some(Value::Array(json_types)) => json_types
+                .iter()
+                .map(|json_type| {
+                    json_type
+                        .as_str()
+                        .ok_or_else(|| ImportJsonSchemaError::InvalidSchema(child(path, "type")))
+                })
+                .collect::<Result<_, _>>()?,
+            Some(_) => return Err(ImportJsonSchemaError::InvalidSchema(child(path, "type"))),
+            None if schema.contains_key("properties") => vec!["object"],
+            None if schema.contains_key("items") => vec!["array"],
+            None if schema.contains_key("oneOf") => Vec::new(),
+            None => {
+                self.report(path, "type");
+                Vec::new()
+            }
+        };
+
+        let suffix = json_types.len() > 1 || schema.contains_key("oneOf");
+        for json_type in json_types {
+            match json_type {
+                "object" if schema.contains_key("properties") => {
+                    values.push(PropertyValues::PropertyTypeObject(
+                        self.object(path, schema)?,
+                    ));
+                }
+                "object" => {
+                    self.object::<0>(path, schema)?;
+                    values.push(PropertyValues::DataTypeReference(
+                        Primitive::Object.reference(),
+                    ));
+                }
+                "array" => values.extend(self.array(path, title, schema)?),
+                "string" | "number" | "integer" | "boolean" | "null" => {
+                    let reference = self.data_type(title, json_type, schema, suffix)?;
+                    values.push(PropertyValues::DataTypeReference(reference));
+                }
+                _ => self.report(&child(path, "type"), json_type),
+            }
+        }
+        Ok(values)
+    }
+
+    fn array(
+        &mut self,
+        path: &str,
+        title: &str,
+        schema: &Map<String, Value>,
+    ) -> Result<Option<PropertyValues>, ImportJsonSchemaError> {
+        self.check_keywords(path, schema, UNSUPPORTED_ARRAY);
+        let (min_items, max_items) = (
+            item_bound(schema, "minItems"),
+            item_bound(schema, "maxItems"),
+        );
+
+        match schema.get("items") {
+            Some(items) if items.is_object() || items.is_boolean() => {
+                let values = self.values(&child(path, "items"), title, items)?;
+                if values.is_empty() {
+                    return Ok(None);
+                }
+                Ok(Some(PropertyValues::ArrayOfPropertyValues(Array::new(
+                    OneOf::new(values)?,
+                    min_items,
+                    max_items,
+                )?)))
+            }
+            None if max_items == Some(0) => Ok(Some(PropertyValues::DataTypeReference(
+                Primitive::EmptyList.reference(),
+            ))),
+            _ => {
+                self.report(path, "items");
+                Ok(None)
+            }
+        }
+    }
+
+    /// Returns the data type for primitive values of `json_type`.
+    ///
+    /// If the schema constrains the values, a new data type is created, otherwise the Block
+    /// Protocol data type is used.
+    fn data_type(
+        &mut self,
+        title: &str,
+        json_type: &str,
+        schema: &Map<String, Value>,
+        suffix: bool,
+    ) -> Result<DataTypeReference, ImportJsonSchemaError> {
+        let (primitive, constraints) = match json_type {
+            "string" => (Some(Primitive::Text), STRING_CONSTRAINTS),
+            "number" => (Some(Primitive::Number), NUMBER_CONSTRAINTS),
+            "integer" => (None, NUMBER_CONSTRAINTS),
+            "boolean" => (Some(Primitive::Boolean), &[][..]),
+            _ => (Some(Primitive::Null), &[][..]),
+        };
+        let constraints: HashMap<_, _> = schema
+            .iter()
+            .filter(|(keyword, _)| {
+                constraints.contains(&keyword.as_str())
+                    || VALUE_CONSTRAINTS.contains(&keyword.as_str())
+            })
+            .map(|(keyword, value)| (keyword.clone(), value.clone()))
+            .collect();
+        if let (Some(primitive), true) = (primitive, constraints.is_empty()) {
+            return Ok(primitive.reference());
+        }
+
+        let title = if suffix {
+            let kind = primitive.map_or("Integer", |primitive| match primitive {
+                Primitive::Text => "Text",
+                Primitive::Number => "Number",
+                Primitive::Boolean => "Boolean",
+                _ => "Null",
+            });
+            format!("{title} {kind}")
+        } else {
+            title.to_owned()
+        };
+        let id = VersionedUri::new(self.config.uri(OntologyTypeKind::DataType, &title)?, 1);
+        self.add(DataType::new(
+            id.clone(),
+            title,
+            description(schema),
+            json_type.to_owned(),
+            constraints,
+        ))?;
+        Ok(DataTypeReference::new(id))
+    }
+}
+
+fn item_bound(schema: &Map<String, Value>, keyword: &str) -> Option<usize> {
+    schema
+        .get(keyword)
+        .and_then(Value::as_u64)
+        .and_then(|bound| usize::try_from(bound).ok())
+}
+
+/// The types created by a [`JsonSchemaImport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedTypes {
+    entity_type: EntityType,
+    types: TypeBundle,
+    reused: Vec<VersionedUri>,
+    unsupported: Vec<UnsupportedConstruct>,
+}
+
+impl ImportedTypes {
+    /// The entity type the imported schema was converted into.
+    #[must_use]
+    pub const fn entity_type(&self) -> &EntityType {
+        &self.entity_type
+    }
+
+    /// The types which were created by the import, including the [`entity_type`] unless it was
+    /// reused.
+    ///
+    /// [`entity_type`]: Self::entity_type
+    #[must_use]
+    pub const fn types(&self) -> &TypeBundle {
+        &self.types
+    }
+
+    /// The types provided by the resolver which were reused, sorted by their URI.
+    #[must_use]
+    pub fn reused(&self) -> &[VersionedUri] {
+        &self.reused
+    }
+
+    /// The constructs of the schema which were skipped, in the order they were encountered.
+    #[must_use]
+    pub fn unsupported(&self) -> &[UnsupportedConstruct] {
+        &self.unsupported
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::repository::FsTypeRepository;
+
+    fn uri(kind: &str, slug: &str) -> VersionedUri {
+        VersionedUri::new(
+            BaseUri::new(format!("https://example.com/@partner/types/{kind}/{slug}/"))
+                .expect("invalid base URI"),
+            1,
+        )
+    }
+
+    fn person() -> Value {
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "Person",
+            "description": "A human being",
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "email": { "type": "string", "format": "email", "description": "An email address" },
+                "age": { "type": ["integer", "null"], "minimum": 0 },
+                "tags": { "type": "array", "items": { "type": "string" }, "minItems": 1 },
+                "address": {
+                    "type": "object",
+                    "properties": { "street": { "type": "string" } },
+                    "required": ["street"],
+                    "additionalProperties": false
+                },
+                "contact": {
+                    "oneOf": [
+                        { "type": "string" },
+                        { "type": "array", "items": { "type": "number" }, "maxItems": 3 }
+                    ]
+                }
+            },
+            "required": ["name", "email"]
+        })
+    }
+
+    #[test]
+    fn import() {
+        let imported = JsonSchemaImport::new("example.com", "partner")
+            .import(&person())
+            .expect("failed to import schema");
+        assert!(imported.unsupported().is_empty());
+        assert!(imported.reused().is_empty());
+
+        let entity_type = imported.entity_type();
+        assert_eq!(entity_type.id(), &uri("entity-type", "person"));
+        assert_eq!(entity_type.plural_title(), "Persons");
+        assert_eq!(entity_type.description(), Some("A human being"));
+        assert_eq!(entity_type.properties().len(), 6);
+        assert_eq!(entity_type.required(), [
+            uri("property-type", "name").base_uri().clone(),
+            uri("property-type", "email").base_uri().clone()
+        ]);
+        assert_eq!(
+            entity_type.properties()[uri("property-type", "tags").base_uri()],
+            ValueOrArray::Array(Array::new_unchecked(
+                PropertyTypeReference::new(uri("property-type", "tags")),
+                Some(1),
+                None
+            ))
+        );
+
+        // The entity type, 7 property types, and 2 data types
+        let types = imported.types();
+        assert_eq!(types.len(), 10);
+
+        let email = types
+            .data_type(&uri("data-type", "email"))
+            .expect("missing data type");
+        assert_eq!(email.json_type(), "string");
+        assert_eq!(email.description(), Some("An email address"));
+        assert_eq!(email.additional_properties()["format"], "email");
+
+        let one_of = |slug: &str| {
+            let property_type = types
+                .property_type(&uri("property-type", slug))
+                .expect("missing property type");
+            serde_json::Value::from(property_type.clone())["oneOf"].clone()
+        };
+        let data_type = |slug: &str| {
+            json!({
+                "$ref":
+                    format!("https://blockprotocol.org/@blockprotocol/types/data-type/{slug}/v/1")
+            })
+        };
+        assert_eq!(one_of("name"), json!([data_type("text")]));
+        assert_eq!(one_of("tags"), json!([data_type("text")]));
+        assert_eq!(
+            one_of("age"),
+            json!([
+                { "$ref": uri("data-type", "age-integer").to_string() },
+                data_type("null")
+            ])
+        );
+        assert_eq!(
+            types
+                .data_type(&uri("data-type", "age-integer"))
+                .expect("missing data type")
+                .additional_properties()["minimum"],
+            0
+        );
+        assert_eq!(
+            one_of("address"),
+            json!([{
+                "type": "object",
+                "properties": {
+                    uri("property-type", "street").base_uri().to_string(): {
+                        "$ref": uri("property-type", "street").to_string()
+                    }
+                },
+                "required": [uri("property-type", "street").base_uri()]
+            }])
+        );
+        assert_eq!(
+            one_of("contact"),
+            json!([
+                data_type("text"),
+                {
+                    "type": "array",
+                    "items": { "oneOf": [data_type("number")] },
+                    "maxItems": 3
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn reuse() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = FsTypeRepository::new(directory.path());
+        let import = JsonSchemaImport::new("example.com", "partner");
+
+        let imported = import.import(&person()).expect("failed to import schema");
+        for data_type in imported.types().data_types() {
+            repository.store(data_type).expect("failed to store type");
+        }
+        for property_type in imported.types().property_types() {
+            repository
+                .store(property_type)
+                .expect("failed to store type");
+        }
+
+        let reimported = import
+            .import_with_resolver(&person(), &repository)
+            .expect("failed to import schema");
+        assert_eq!(reimported.entity_type(), imported.entity_type());
+        assert_eq!(reimported.types().len(), 1);
+        assert_eq!(reimported.reused().len(), 9);
+        assert!(reimported.reused().contains(&uri("data-type", "email")));
+
+        let mut changed = person();
+        changed["properties"]["email"]["format"] = json!("idn-email");
+        assert_eq!(
+            import.import_with_resolver(&changed, &repository),
+            Err(ImportJsonSchemaError::Conflict(uri("data-type", "email")))
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        let imported = JsonSchemaImport::new("example.com", "partner")
+            .import(&json!({
+                "title": "Unsupported",
+                "properties": {
+                    "name": { "type": "string", "anyOf": [{ "maxLength": 1 }] },
+                    "anything": true,
+                    "list": { "type": "array" },
+                    "reference": { "$ref": "#/$defs/reference" },
+                    "object": { "type": "object", "patternProperties": { "^x-": {} } }
+                },
+                "required": ["name", "missing"],
+                "additionalProperties": true
+            }))
+            .expect("failed to import schema");
+
+        let unsupported = imported
+            .unsupported()
+            .iter()
+            .map(|construct| (construct.path(), construct.keyword()))
+            .collect::<Vec<_>>();
+        assert_eq!(unsupported, [
+            ("", "additionalProperties"),
+            ("/properties/anything", "true"),
+            ("/properties/list", "items"),
+            ("/properties/name", "anyOf"),
+            ("/properties/object", "patternProperties"),
+            ("/properties/reference", "$ref"),
+            ("/properties/reference", "type"),
+            ("/required/1", "required"),
+        ]);
+        assert_eq!(imported.entity_type().properties().len(), 2);
+        assert_eq!(imported.entity_type().required().len(), 1);
+    }
+
+    #[test]
+    fn invalid_schemas() {
+        let import = JsonSchemaImport::new("example.com", "partner");
+        assert_eq!(
+            import.import(&json!({ "title": "Text", "type": "string" })),
+            Err(ImportJsonSchemaError::NotAnObjectSchema)
+        );
+        assert_eq!(
+            import.import(&json!({ "type": "object" })),
+            Err(ImportJsonSchemaError::MissingTitle)
+        );
+        assert_eq!(
+            import.import(&json!({ "title": "Invalid", "properties": { "a": 1 } })),
+            Err(ImportJsonSchemaError::InvalidSchema(
+                "/properties/a".to_owned()
+            ))
+        );
+        assert_eq!(
+            import.import(&json!({
+                "title": "Conflicting",
+                "properties": {
+                    "name": { "type": "string" },
+                    "nested": {
+                        "type": "object",
+                        "properties": { "name": { "type": "number" } }
+                    }
+                }
+            })),
+            Err(ImportJsonSchemaError::Conflict(uri(
+                "property-type",
+                "name"
+            )))
+        );
+    }
+}
@@ -13,4 +13,8 @@ pub enum ParseLinkTypeError {
     InvalidVersionedUri(ParseVersionedUriError),
     #[error("error in JSON: `{0}`")]
     InvalidJson(String),
+    #[error("error in YAML: `{0}`")]
+    InvalidYaml(String),
+    #[error("error in TOML: `{0}`")]
+    InvalidToml(String),
 }
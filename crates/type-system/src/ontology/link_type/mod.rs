@@ -1,6 +1,10 @@
-use std::str::FromStr;
+use std::{collections::HashSet, str::FromStr};
 
-use crate::uri::VersionedUri;
+#[cfg(any(feature = "yaml", feature = "toml"))]
+use crate::format;
+#[cfg(feature = "toml")]
+use crate::format::SerializeTomlError;
+use crate::{uri::VersionedUri, Compatibility};
 
 mod error;
 pub(in crate::ontology) mod repr;
@@ -61,6 +65,21 @@ impl LinkType {
     pub fn related_keywords(&self) -> &[String] {
         &self.related_keywords
     }
+
+    /// Removes duplicated entries from the related keywords while keeping their order.
+    pub fn normalize(&mut self) {
+        let mut seen = HashSet::new();
+        self.related_keywords
+            .retain(|keyword| seen.insert(keyword.clone()));
+    }
+}
+
+impl Compatibility for LinkType {
+    /// Link types can only be changed through semantic annotations, so every version of a link
+    /// type is compatible with every other version of it.
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        self.id.base_uri() == other.id.base_uri()
+    }
 }
 
 impl FromStr for LinkType {
@@ -74,6 +93,57 @@ impl FromStr for LinkType {
     }
 }
 
+#[cfg(any(feature = "yaml", feature = "toml"))]
+impl LinkType {
+    /// Parses a link type from its YAML representation.
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseLinkTypeError::InvalidYaml`] if `link_type_yaml` is not a valid YAML representation
+    ///   of a link type
+    /// - the same errors as parsing the JSON representation otherwise
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(link_type_yaml: &str) -> Result<Self, ParseLinkTypeError> {
+        let link_type_repr: repr::LinkType = serde_yaml::from_str(link_type_yaml)
+            .map_err(|err| ParseLinkTypeError::InvalidYaml(err.to_string()))?;
+
+        Self::try_from(link_type_repr)
+    }
+
+    /// Serializes the link type as YAML.
+    #[cfg(feature = "yaml")]
+    #[must_use]
+    pub fn to_yaml(&self) -> String {
+        format::to_yaml(&self.clone().into())
+    }
+
+    /// Parses a link type from its TOML representation.
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseLinkTypeError::InvalidToml`] if `link_type_toml` is not a valid TOML representation
+    ///   of a link type
+    /// - the same errors as parsing the JSON representation otherwise
+    #[cfg(feature = "toml")]
+    pub fn from_toml(link_type_toml: &str) -> Result<Self, ParseLinkTypeError> {
+        let link_type_repr: repr::LinkType = toml::from_str(link_type_toml)
+            .map_err(|err| ParseLinkTypeError::InvalidToml(err.to_string()))?;
+
+        Self::try_from(link_type_repr)
+    }
+
+    /// Serializes the link type as TOML.
+    ///
+    /// # Errors
+    ///
+    /// - [`SerializeTomlError`] if the link type contains a value which cannot be represented in
+    ///   TOML, e.g. `null`
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> Result<String, SerializeTomlError> {
+        format::to_toml(&self.clone().into())
+    }
+}
+
 impl TryFrom<serde_json::Value> for LinkType {
     type Error = ParseLinkTypeError;
 
@@ -107,4 +177,30 @@ mod tests {
     fn submitted_by() {
         check_serialization_from_str::<LinkType>(test_data::link_type::SUBMITTED_BY_V1, None);
     }
+
+    #[test]
+    fn normalize() {
+        let mut link_type =
+            LinkType::from_str(test_data::link_type::OWNS_V2).expect("invalid link type");
+        link_type.related_keywords.push("has".to_owned());
+
+        link_type.normalize();
+        assert_eq!(link_type.related_keywords(), [
+            "has", "have", "possess", "own"
+        ]);
+    }
+
+    #[test]
+    fn versions_are_compatible() {
+        let owns_v2 = LinkType::from_str(test_data::link_type::OWNS_V2).expect("invalid link type");
+        let mut owns_v3 = owns_v2.clone();
+        owns_v3.id = VersionedUri::new(owns_v2.id().base_uri().clone(), 3);
+        owns_v3.title = "Possesses".to_owned();
+
+        let submitted_by =
+            LinkType::from_str(test_data::link_type::SUBMITTED_BY_V1).expect("invalid link type");
+
+        assert!(owns_v2.is_equivalent_to(&owns_v3));
+        assert!(!owns_v2.is_compatible_with(&submitted_by));
+    }
 }
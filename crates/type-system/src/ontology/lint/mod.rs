@@ -0,0 +1,603 @@
+//! Checking types of the Type System against authoring conventions.
+//!
+//! A type can be valid while still being poorly authored, e.g. because its `pluralTitle` is equal
+//! to its `title` or because it lacks a `description`. The [`Lint`] trait checks a type against a
+//! set of named [`LintRule`]s and reports a [`LintDiagnostic`] for every violation.
+//!
+//! Which rules are checked, their [`Severity`], and the kinds of types they apply to is configured
+//! by a [`LintConfig`], which is also able to suppress rules for individual types. A configuration
+//! can be parsed from JSON:
+//!
+//! ```json
+//! {
+//!   "rules": {
+//!     "plural-title-equals-title": "off",
+//!     "missing-description": { "severity": "error", "kinds": ["propertyType", "entityType"] }
+//!   },
+//!   "suppressions": {
+//!     "https://blockprotocol.org/@alice/types/entity-type/book/": ["slug-mismatch"]
+//!   }
+//! }
+//! ```
+
+mod error;
+mod rules;
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    str::FromStr,
+};
+
+pub use error::ParseLintConfigError;
+use serde::{Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::{
+    uri::{BaseUri, VersionedUri},
+    OntologyTypeKind, TypeBundle,
+};
+
+/// A named check for an authoring convention.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LintRule {
+    /// The `pluralTitle` of a type is equal to its `title`.
+    PluralTitleEqualsTitle,
+    /// A type has no `description`, or its `description` is empty.
+    MissingDescription,
+    /// A keyword is listed more than once in the `relatedKeywords` of a link type.
+    DuplicateRelatedKeyword,
+    /// The slug inside of the URI of a type does not match its `title`.
+    SlugMismatch,
+    /// An entry is listed more than once in `required` or `requiredLinks`.
+    DuplicateRequired,
+}
+
+impl LintRule {
+    pub const ALL: [Self; 5] = [
+        Self::PluralTitleEqualsTitle,
+        Self::MissingDescription,
+        Self::DuplicateRelatedKeyword,
+        Self::SlugMismatch,
+        Self::DuplicateRequired,
+    ];
+
+    /// Returns the name of the rule as used inside of a [`LintConfig`].
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::PluralTitleEqualsTitle => "plural-title-equals-title",
+            Self::MissingDescription => "missing-description",
+            Self::DuplicateRelatedKeyword => "duplicate-related-keyword",
+            Self::SlugMismatch => "slug-mismatch",
+            Self::DuplicateRequired => "duplicate-required",
+        }
+    }
+
+    /// Returns the [`Severity`] of the rule if it's not configured otherwise.
+    #[must_use]
+    pub const fn default_severity(self) -> Severity {
+        match self {
+            Self::PluralTitleEqualsTitle
+            | Self::MissingDescription
+            | Self::DuplicateRelatedKeyword
+            | Self::SlugMismatch
+            | Self::DuplicateRequired => Severity::Warning,
+        }
+    }
+
+    /// Returns the kinds of types the rule is checked for if it's not configured otherwise.
+    ///
+    /// Rules are never checked for kinds of types which cannot violate them, e.g. a
+    /// [`DataType`] is never checked for [`Self::PluralTitleEqualsTitle`] as it has no
+    /// `pluralTitle`.
+    ///
+    /// [`DataType`]: crate::DataType
+    #[must_use]
+    pub const fn default_kinds(self) -> &'static [OntologyTypeKind] {
+        match self {
+            Self::MissingDescription => &[OntologyTypeKind::PropertyType],
+            Self::PluralTitleEqualsTitle => &[
+                OntologyTypeKind::PropertyType,
+                OntologyTypeKind::EntityType,
+                OntologyTypeKind::LinkType,
+            ],
+            Self::DuplicateRelatedKeyword => &[OntologyTypeKind::LinkType],
+            Self::SlugMismatch => &[
+                OntologyTypeKind::DataType,
+                OntologyTypeKind::PropertyType,
+                OntologyTypeKind::EntityType,
+                OntologyTypeKind::LinkType,
+            ],
+            Self::DuplicateRequired => {
+                &[OntologyTypeKind::PropertyType, OntologyTypeKind::EntityType]
+            }
+        }
+    }
+}
+
+impl fmt::Display for LintRule {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(self.name())
+    }
+}
+
+/// How a violation of a [`LintRule`] is reported.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Severity {
+    /// The rule is not checked.
+    Off,
+    Warning,
+    Error,
+}
+
+/// The configuration of a single [`LintRule`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleConfig {
+    severity: Severity,
+    kinds: HashSet<OntologyTypeKind>,
+}
+
+impl RuleConfig {
+    /// Creates a configuration reporting violations with `severity` for types of the given
+    /// `kinds`.
+    #[must_use]
+    pub fn new(severity: Severity, kinds: impl IntoIterator<Item = OntologyTypeKind>) -> Self {
+        Self {
+            severity,
+            kinds: kinds.into_iter().collect(),
+        }
+    }
+
+    fn default_for(rule: LintRule) -> Self {
+        Self::new(
+            rule.default_severity(),
+            rule.default_kinds().iter().copied(),
+        )
+    }
+
+    #[must_use]
+    pub const fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    #[must_use]
+    pub const fn kinds(&self) -> &HashSet<OntologyTypeKind> {
+        &self.kinds
+    }
+}
+
+/// Configures which [`LintRule`]s are checked and how their violations are reported.
+///
+/// By default, every rule is checked with its [`default_severity`] for its [`default_kinds`].
+///
+/// [`default_severity`]: LintRule::default_severity
+/// [`default_kinds`]: LintRule::default_kinds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintConfig {
+    rules: HashMap<LintRule, RuleConfig>,
+    suppressions: HashMap<BaseUri, HashSet<LintRule>>,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            rules: LintRule::ALL
+                .into_iter()
+                .map(|rule| (rule, RuleConfig::default_for(rule)))
+                .collect(),
+            suppressions: HashMap::new(),
+        }
+    }
+}
+
+impl LintConfig {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn rule(&self, rule: LintRule) -> &RuleConfig {
+        &self.rules[&rule]
+    }
+
+    /// Replaces the configuration of `rule`.
+    pub fn set_rule(&mut self, rule: LintRule, config: RuleConfig) {
+        self.rules.insert(rule, config);
+    }
+
+    /// Changes the [`Severity`] of `rule` while keeping the kinds of types it's checked for.
+    pub fn set_severity(&mut self, rule: LintRule, severity: Severity) {
+        self.rules
+            .entry(rule)
+            .or_insert_with(|| RuleConfig::default_for(rule))
+            .severity = severity;
+    }
+
+    /// Stops checking `rule` for all versions of the type identified by `base_uri`.
+    pub fn suppress(&mut self, base_uri: BaseUri, rule: LintRule) {
+        self.suppressions.entry(base_uri).or_default().insert(rule);
+    }
+
+    /// Returns `true` if `rule` is suppressed for the type identified by `base_uri`.
+    #[must_use]
+    pub fn is_suppressed(&self, base_uri: &BaseUri, rule: LintRule) -> bool {
+        self.suppressions
+            .get(base_uri)
+            .map_or(false, |rules| rules.contains(&rule))
+    }
+
+    /// Returns the [`Severity`] violations of `rule` are reported with for the type identified by
+    /// `id`.
+    fn effective_severity(
+        &self,
+        rule: LintRule,
+        kind: OntologyTypeKind,
+        id: &VersionedUri,
+    ) -> Severity {
+        let config = self.rule(rule);
+        if !config.kinds.contains(&kind) || self.is_suppressed(id.base_uri(), rule) {
+            Severity::Off
+        } else {
+            config.severity
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct LintConfigRepr {
+    #[serde(default)]
+    rules: HashMap<LintRule, RuleConfigRepr>,
+    #[serde(default)]
+    suppressions: HashMap<BaseUri, Vec<LintRule>>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RuleConfigRepr {
+    Severity(Severity),
+    Options(RuleOptionsRepr),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleOptionsRepr {
+    severity: Option<Severity>,
+    kinds: Option<Vec<OntologyTypeKind>>,
+}
+
+impl FromStr for LintConfig {
+    type Err = ParseLintConfigError;
+
+    fn from_str(config: &str) -> Result<Self, ParseLintConfigError> {
+        let repr: LintConfigRepr = serde_json::from_str(config)
+            .map_err(|error| ParseLintConfigError::InvalidJson(error.to_string()))?;
+
+        let mut config = Self::default();
+        for (rule, rule_config) in repr.rules {
+            match rule_config {
+                RuleConfigRepr::Severity(severity) => config.set_severity(rule, severity),
+                RuleConfigRepr::Options(options) => {
+                    let default = RuleConfig::default_for(rule);
+                    config.set_rule(rule, RuleConfig {
+                        severity: options.severity.unwrap_or(default.severity),
+                        kinds: options
+                            .kinds
+                            .map_or(default.kinds, |kinds| kinds.into_iter().collect()),
+                    });
+                }
+            }
+        }
+        for (base_uri, rules) in repr.suppressions {
+            for rule in rules {
+                config.suppress(base_uri.clone(), rule);
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// A violation of a [`LintRule`].
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintDiagnostic {
+    rule: LintRule,
+    severity: Severity,
+    type_id: VersionedUri,
+    path: String,
+    message: String,
+}
+
+impl LintDiagnostic {
+    #[must_use]
+    pub const fn rule(&self) -> LintRule {
+        self.rule
+    }
+
+    #[must_use]
+    pub const fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns the [`VersionedUri`] of the type violating the rule.
+    #[must_use]
+    pub const fn type_id(&self) -> &VersionedUri {
+        &self.type_id
+    }
+
+    /// Returns a [JSON Pointer] to the violating part of the JSON representation of the type.
+    ///
+    /// [JSON Pointer]: https://www.rfc-editor.org/rfc/rfc6901
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// Checks types against the [`LintRule`]s enabled in a [`LintConfig`].
+pub trait Lint {
+    /// Returns a [`LintDiagnostic`] for every violation of an enabled rule, ordered by their path.
+    #[must_use]
+    fn lint(&self, config: &LintConfig) -> Vec<LintDiagnostic>;
+}
+
+impl Lint for TypeBundle {
+    /// Lints every type inside of the bundle. The diagnostics are ordered by the [`VersionedUri`]
+    /// of the type they refer to.
+    fn lint(&self, config: &LintConfig) -> Vec<LintDiagnostic> {
+        let mut diagnostics: Vec<_> = self
+            .data_types()
+            .flat_map(|data_type| data_type.lint(config))
+            .chain(
+                self.property_types()
+                    .flat_map(|property_type| property_type.lint(config)),
+            )
+            .chain(
+                self.entity_types()
+                    .flat_map(|entity_type| entity_type.lint(config)),
+            )
+            .chain(
+                self.link_types()
+                    .flat_map(|link_type| link_type.lint(config)),
+            )
+            .collect();
+        // The sort is stable, so the diagnostics of a single type stay ordered by their path
+        diagnostics.sort_by_cached_key(|diagnostic| diagnostic.type_id.to_string());
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{test_data, DataType, EntityType, LinkType, PropertyType};
+
+    fn rules(diagnostics: &[LintDiagnostic]) -> Vec<(LintRule, Severity, &str)> {
+        diagnostics
+            .iter()
+            .map(|diagnostic| (diagnostic.rule(), diagnostic.severity(), diagnostic.path()))
+            .collect()
+    }
+
+    #[test]
+    fn conventional_types() {
+        let config = LintConfig::default();
+
+        let text = DataType::from_str(test_data::data_type::TEXT_V1).expect("invalid data type");
+        let book = EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid type");
+        assert_eq!(text.lint(&config), []);
+        assert_eq!(book.lint(&config), []);
+    }
+
+    #[test]
+    fn property_type() {
+        let property_type = PropertyType::try_from(json!({
+            "kind": "propertyType",
+            "$id": "https://blockprotocol.org/@alice/types/property-type/favorite-songs/v/1",
+            "title": "Favourite Songs",
+            "pluralTitle": "Favourite Songs",
+            "oneOf": [
+                {
+                    "type": "array",
+                    "items": {
+                        "oneOf": [
+                            { "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" }
+                        ]
+                    },
+                    "minItems": 1
+                }
+            ]
+        }))
+        .expect("invalid property type");
+
+        assert_eq!(rules(&property_type.lint(&LintConfig::default())), [
+            (LintRule::SlugMismatch, Severity::Warning, "/$id"),
+            (
+                LintRule::MissingDescription,
+                Severity::Warning,
+                "/description"
+            ),
+            (
+                LintRule::PluralTitleEqualsTitle,
+                Severity::Warning,
+                "/pluralTitle"
+            ),
+        ]);
+    }
+
+    #[test]
+    fn entity_type() {
+        let name = "https://blockprotocol.org/@alice/types/property-type/name/";
+        let owns = "https://blockprotocol.org/@alice/types/link-type/owns/v/2";
+        let entity_type = EntityType::try_from(json!({
+            "kind": "entityType",
+            "$id": "https://blockprotocol.org/@alice/types/entity-type/person/v/1",
+            "type": "object",
+            "title": "Person",
+            "pluralTitle": "People",
+            "properties": {
+                name: { "$ref": format!("{name}v/1") }
+            },
+            "required": [name, name],
+            "links": {
+                owns: {
+                    "type": "array",
+                    "items": { "$ref": "https://blockprotocol.org/@alice/types/entity-type/book/v/1" },
+                    "ordered": false
+                }
+            },
+            "requiredLinks": [owns, owns, owns]
+        }))
+        .expect("invalid entity type");
+
+        assert_eq!(rules(&entity_type.lint(&LintConfig::default())), [
+            (
+                LintRule::DuplicateRequired,
+                Severity::Warning,
+                "/required/1"
+            ),
+            (
+                LintRule::DuplicateRequired,
+                Severity::Warning,
+                "/requiredLinks/1"
+            ),
+            (
+                LintRule::DuplicateRequired,
+                Severity::Warning,
+                "/requiredLinks/2"
+            ),
+        ]);
+    }
+
+    #[test]
+    fn link_type() {
+        let owns = LinkType::from_str(test_data::link_type::OWNS_V2).expect("invalid link type");
+        assert_eq!(rules(&owns.lint(&LintConfig::default())), [(
+            LintRule::PluralTitleEqualsTitle,
+            Severity::Warning,
+            "/pluralTitle"
+        )]);
+
+        let link_type = LinkType::try_from(json!({
+            "kind": "linkType",
+            "$id": "https://blockprotocol.org/@alice/types/link-type/friend-of/v/1",
+            "title": "Friend Of",
+            "pluralTitle": "Friends Of",
+            "description": "",
+            "relatedKeywords": ["friend", "buddy", "Friend"]
+        }))
+        .expect("invalid link type");
+
+        let mut config = LintConfig::default();
+        assert_eq!(rules(&link_type.lint(&config)), [(
+            LintRule::DuplicateRelatedKeyword,
+            Severity::Warning,
+            "/relatedKeywords/2"
+        )]);
+
+        config.set_rule(
+            LintRule::MissingDescription,
+            RuleConfig::new(Severity::Error, [OntologyTypeKind::LinkType]),
+        );
+        assert_eq!(rules(&link_type.lint(&config)), [
+            (
+                LintRule::MissingDescription,
+                Severity::Error,
+                "/description"
+            ),
+            (
+                LintRule::DuplicateRelatedKeyword,
+                Severity::Warning,
+                "/relatedKeywords/2"
+            )
+        ]);
+    }
+
+    #[test]
+    fn configuration() {
+        let config = LintConfig::from_str(
+            &json!({
+                "rules": {
+                    "plural-title-equals-title": "off",
+                    "missing-description": { "kinds": ["dataType", "propertyType"] },
+                    "slug-mismatch": { "severity": "error" }
+                },
+                "suppressions": {
+                    "https://blockprotocol.org/@alice/types/property-type/interests/": ["missing-description"]
+                }
+            })
+            .to_string(),
+        )
+        .expect("invalid lint configuration");
+
+        assert_eq!(
+            config.rule(LintRule::PluralTitleEqualsTitle).severity(),
+            Severity::Off
+        );
+        assert_eq!(
+            config.rule(LintRule::MissingDescription),
+            &RuleConfig::new(Severity::Warning, [
+                OntologyTypeKind::DataType,
+                OntologyTypeKind::PropertyType
+            ])
+        );
+        assert_eq!(
+            config.rule(LintRule::SlugMismatch).kinds().len(),
+            LintRule::SlugMismatch.default_kinds().len()
+        );
+
+        let interests = PropertyType::from_str(test_data::property_type::INTERESTS_V1)
+            .expect("invalid property type");
+        assert!(!interests.lint(&LintConfig::default()).is_empty());
+        assert!(config.is_suppressed(interests.id().base_uri(), LintRule::MissingDescription));
+        assert_eq!(interests.lint(&config), []);
+
+        assert!(matches!(
+            LintConfig::from_str(r#"{ "rules": { "unknown-rule": "error" } }"#),
+            Err(ParseLintConfigError::InvalidJson(_))
+        ));
+        assert!(matches!(
+            LintConfig::from_str(r#"{ "rules": { "slug-mismatch": { "level": "error" } } }"#),
+            Err(ParseLintConfigError::InvalidJson(_))
+        ));
+    }
+
+    #[test]
+    fn bundle() {
+        let mut bundle = TypeBundle::new();
+        bundle.insert_link_type(
+            LinkType::from_str(test_data::link_type::OWNS_V2).expect("invalid link type"),
+        );
+        bundle.insert_link_type(
+            LinkType::from_str(test_data::link_type::SUBMITTED_BY_V1).expect("invalid link type"),
+        );
+
+        let diagnostics = bundle.lint(&LintConfig::default());
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.type_id().to_string())
+                .collect::<Vec<_>>(),
+            [
+                "https://blockprotocol.org/@alice/types/link-type/owns/v/2",
+                "https://blockprotocol.org/@alice/types/link-type/submitted-by/v/1",
+            ]
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use std::fmt;
+
+use crate::{
+    lint::{Lint, LintConfig, LintDiagnostic, LintRule, Severity},
+    ontology::shared::pointer::child,
+    DataType, EntityType, LinkType, OntologyType, PropertyType, PropertyValues,
+};
+
+/// Converts a title into the slug it's expected to be published under, e.g. `UK Address` into
+/// `uk-address`.
+fn slugify(title: &str) -> String {
+    let mut slug = String::with_capacity(title.len());
+    for character in title.chars() {
+        if character.is_alphanumeric() {
+            slug.extend(character.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    if slug.ends_with('-') {
+        slug.pop();
+    }
+    slug
+}
+
+/// Collects the diagnostics for a single type.
+struct Linter<'a, T> {
+    config: &'a LintConfig,
+    ontology_type: &'a T,
+    diagnostics: Vec<LintDiagnostic>,
+}
+
+impl<'a, T: OntologyType> Linter<'a, T> {
+    const fn new(config: &'a LintConfig, ontology_type: &'a T) -> Self {
+        Self {
+            config,
+            ontology_type,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    fn report(&mut self, rule: LintRule, path: String, message: String) {
+        let id = self.ontology_type.versioned_uri();
+        let severity = self.config.effective_severity(rule, T::KIND, id);
+        if severity != Severity::Off {
+            self.diagnostics.push(LintDiagnostic {
+                rule,
+                severity,
+                type_id: id.clone(),
+                path,
+                message,
+            });
+        }
+    }
+
+    fn finish(mut self) -> Vec<LintDiagnostic> {
+        self.diagnostics
+            .sort_by(|lhs, rhs| (&lhs.path, lhs.rule).cmp(&(&rhs.path, rhs.rule)));
+        self.diagnostics
+    }
+
+    fn plural_title(&mut self, title: &str, plural_title: &str) {
+        if title == plural_title {
+            self.report(
+                LintRule::PluralTitleEqualsTitle,
+                "/pluralTitle".to_owned(),
+                format!("the plural title is the same as the title `{title}`"),
+            );
+        }
+    }
+
+    fn description(&mut self, description: Option<&str>) {
+        if description.map_or(true, |description| description.trim().is_empty()) {
+            self.report(
+                LintRule::MissingDescription,
+                "/description".to_owned(),
+                "the type has no description".to_owned(),
+            );
+        }
+    }
+
+    fn slug(&mut self, title: &str) {
+        // Types which don't follow the URI convention are not published under a slug
+        if let Ok(components) = self.ontology_type.versioned_uri().base_uri().components() {
+            let expected = slugify(title);
+            if components.slug() != expected {
+                self.report(
+                    LintRule::SlugMismatch,
+                    "/$id".to_owned(),
+                    format!(
+                        "the slug `{}` does not match the title `{title}`, expected `{expected}`",
+                        components.slug()
+                    ),
+                );
+            }
+        }
+    }
+
+    fn related_keywords(&mut self, keywords: &[String]) {
+        for (index, keyword) in keywords.iter().enumerate() {
+            let normalized = keyword.to_lowercase();
+            if keywords[..index]
+                .iter()
+                .any(|previous| previous.to_lowercase() == normalized)
+            {
+                self.report(
+                    LintRule::DuplicateRelatedKeyword,
+                    child("/relatedKeywords", index),
+                    format!("the keyword `{keyword}` is listed more than once"),
+                );
+            }
+        }
+    }
+
+    fn required<R: PartialEq + fmt::Display>(&mut self, path: &str, required: &[R]) {
+        for (index, entry) in required.iter().enumerate() {
+            if required[..index].contains(entry) {
+                self.report(
+                    LintRule::DuplicateRequired,
+                    child(path, index),
+                    format!("`{entry}` is required more than once"),
+                );
+            }
+        }
+    }
+
+    fn property_values(&mut self, path: &str, values: &[PropertyValues]) {
+        for (index, value) in values.iter().enumerate() {
+            let path = child(&child(path, "oneOf"), index);
+            match value {
+                PropertyValues::DataTypeReference(_) => {}
+                PropertyValues::PropertyTypeObject(object) => {
+                    self.required(&child(&path, "required"), object.required());
+                }
+                PropertyValues::ArrayOfPropertyValues(array) => {
+                    self.property_values(&child(&path, "items"), array.items().one_of());
+                }
+            }
+        }
+    }
+}
+
+impl Lint for DataType {
+    fn lint(&self, config: &LintConfig) -> Vec<LintDiagnostic> {
+        let mut linter = Linter::new(config, self);
+        linter.description(self.description());
+        linter.slug(self.title());
+        linter.finish()
+    }
+}
+
+impl Lint for PropertyType {
+    fn lint(&self, config: &LintConfig) -> Vec<LintDiagnostic> {
+        let mut linter = Linter::new(config, self);
+        linter.plural_title(self.title(), self.plural_title());
+        linter.description(self.description());
+        linter.slug(self.title());
+        linter.property_values("", self.one_of());
+        linter.finish()
+    }
+}
+
+impl Lint for EntityType {
+    fn lint(&self, config: &LintConfig) -> Vec<LintDiagnostic> {
+        let mut linter = Linter::new(config, self);
+        linter.plural_title(self.title(), self.plural_title());
+        linter.description(self.description());
+        linter.slug(self.title());
+        linter.required("/required", self.required());
+        linter.required("/requiredLinks", self.required_links());
+        linter.finish()
+    }
+}
+
+impl Lint for LinkType {
+    fn lint(&self, config: &LintConfig) -> Vec<LintDiagnostic> {
+        let mut linter = Linter::new(config, self);
+        linter.plural_title(self.title(), self.plural_title());
+        linter.description(Some(self.description()));
+        linter.slug(self.title());
+        linter.related_keywords(self.related_keywords());
+        linter.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugs() {
+        assert_eq!(slugify("UK Address"), "uk-address");
+        assert_eq!(slugify("User ID"), "user-id");
+        assert_eq!(slugify("  Submitted   By (v2) "), "submitted-by-v2");
+        assert_eq!(slugify("Écrit par"), "écrit-par");
+    }
+
+    #[test]
+    fn pointers() {
+        assert_eq!(
+            child("/properties", "https://a/b~c/"),
+            "/properties/https:~1~1a~1b~0c~1"
+        );
+    }
+}
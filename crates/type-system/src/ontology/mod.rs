@@ -6,16 +6,35 @@
 //! aid with the de/serialization, intermediary structs and helpers are defined across various
 //! submodules.
 
+mod bundle;
+pub mod canonical;
 mod data_type;
+pub mod diagram;
+pub mod dsl;
 mod entity_type;
+#[cfg(any(feature = "yaml", feature = "toml"))]
+pub mod format;
+pub mod graphql;
+pub mod infer;
+pub mod json_schema;
 mod link_type;
+pub mod lint;
+mod ontology_type;
+pub mod openapi;
+pub mod patch;
 mod property_type;
+pub mod rdf;
+pub mod repository;
+pub mod rewrite;
+pub mod sql;
 // TODO: reconsider calling these URIs in the spec, it seems to be a redundant term nowadays and
 //  we should probably just go with URL
 pub mod uri;
+pub mod wire;
 
 mod shared;
 
+pub use bundle::{ParseTypeBundleError, TypeBundle};
 pub use data_type::{DataType, DataTypeReference, ParseDataTypeError};
 pub use entity_type::{
     links::{
@@ -25,6 +44,7 @@ pub use entity_type::{
     EntityType, EntityTypeReference, ParseEntityTypeError,
 };
 pub use link_type::{LinkType, ParseLinkTypeError};
+pub use ontology_type::{OntologyType, OntologyTypeKind, ParseOntologyTypeError};
 pub use property_type::{
     ParsePropertyTypeError, PropertyType, PropertyTypeReference, PropertyValues,
 };
@@ -33,6 +53,7 @@ pub use shared::{
         error::{ParseOneOfArrayError, ParsePropertyTypeReferenceArrayError},
         Array, ValueOrArray,
     },
+    compatibility::Compatibility,
     object::{error::ParsePropertyTypeObjectError, Object},
     one_of::{error::ParseOneOfError, OneOf},
     validate::{ValidateUri, ValidationError},
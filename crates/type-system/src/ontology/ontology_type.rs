@@ -0,0 +1,323 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::{
+    uri::{TypeUriComponents, VersionedUri},
+    DataType, EntityType, LinkType, ParseDataTypeError, ParseEntityTypeError, ParseLinkTypeError,
+    ParsePropertyTypeError, PropertyType, ValidationError,
+};
+
+/// The different kinds of types within the Type System.
+///
+/// Serializes to the value of the `kind` field of a type, e.g. `"entityType"`.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OntologyTypeKind {
+    DataType,
+    PropertyType,
+    EntityType,
+    LinkType,
+}
+
+impl OntologyTypeKind {
+    /// Returns the segment used for this kind inside of a type URI, e.g. `"entity-type"`.
+    #[must_use]
+    pub const fn uri_segment(self) -> &'static str {
+        match self {
+            Self::DataType => "data-type",
+            Self::PropertyType => "property-type",
+            Self::EntityType => "entity-type",
+            Self::LinkType => "link-type",
+        }
+    }
+
+    /// Returns the kind whose [`uri_segment`] is equal to `segment`.
+    ///
+    /// [`uri_segment`]: Self::uri_segment
+    #[must_use]
+    pub fn from_uri_segment(segment: &str) -> Option<Self> {
+        [
+            Self::DataType,
+            Self::PropertyType,
+            Self::EntityType,
+            Self::LinkType,
+        ]
+        .into_iter()
+        .find(|kind| kind.uri_segment() == segment)
+    }
+
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::DataType => "dataType",
+            Self::PropertyType => "propertyType",
+            Self::EntityType => "entityType",
+            Self::LinkType => "linkType",
+        }
+    }
+}
+
+impl fmt::Display for OntologyTypeKind {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
+/// An error which occurred while parsing any kind of type.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum ParseOntologyTypeError {
+    #[error("invalid data type: `{0}`")]
+    DataType(ParseDataTypeError),
+    #[error("invalid property type: `{0}`")]
+    PropertyType(ParsePropertyTypeError),
+    #[error("invalid entity type: `{0}`")]
+    EntityType(ParseEntityTypeError),
+    #[error("invalid link type: `{0}`")]
+    LinkType(ParseLinkTypeError),
+}
+
+impl From<ParseDataTypeError> for ParseOntologyTypeError {
+    fn from(error: ParseDataTypeError) -> Self {
+        Self::DataType(error)
+    }
+}
+
+impl From<ParsePropertyTypeError> for ParseOntologyTypeError {
+    fn from(error: ParsePropertyTypeError) -> Self {
+        Self::PropertyType(error)
+    }
+}
+
+impl From<ParseEntityTypeError> for ParseOntologyTypeError {
+    fn from(error: ParseEntityTypeError) -> Self {
+        Self::EntityType(error)
+    }
+}
+
+impl From<ParseLinkTypeError> for ParseOntologyTypeError {
+    fn from(error: ParseLinkTypeError) -> Self {
+        Self::LinkType(error)
+    }
+}
+
+/// Functionality shared by [`DataType`]s, [`PropertyType`]s, [`EntityType`]s, and [`LinkType`]s.
+pub trait OntologyType {
+    /// The kind of this type, which is the value of its `kind` field.
+    const KIND: OntologyTypeKind;
+
+    /// Returns the [`VersionedUri`] identifying this type, which is the value of its `$id` field.
+    fn versioned_uri(&self) -> &VersionedUri;
+
+    /// Returns the kinds and [`VersionedUri`]s of all types directly referenced by this type,
+    /// sorted by kind and URI without duplicates.
+    fn referenced_types(&self) -> Vec<(OntologyTypeKind, &VersionedUri)> {
+        Vec::new()
+    }
+
+    /// Checks that the URI of this type follows the Block Protocol URI convention and that the
+    /// kind of type specified in the URI matches the kind of this type.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::UnconventionalUri`] if the URI does not follow the convention
+    /// - [`ValidationError::MismatchedKind`] if the URI refers to a different kind of type
+    fn validate_kind(&self) -> Result<(), ValidationError> {
+        let components = TypeUriComponents::from_base_uri(self.versioned_uri().base_uri())
+            .map_err(ValidationError::UnconventionalUri)?;
+
+        if components.kind() == Self::KIND {
+            Ok(())
+        } else {
+            Err(ValidationError::MismatchedKind {
+                expected: Self::KIND,
+                actual: components.kind(),
+            })
+        }
+    }
+}
+
+impl OntologyType for DataType {
+    const KIND: OntologyTypeKind = OntologyTypeKind::DataType;
+
+    fn versioned_uri(&self) -> &VersionedUri {
+        self.id()
+    }
+}
+
+impl OntologyType for PropertyType {
+    const KIND: OntologyTypeKind = OntologyTypeKind::PropertyType;
+
+    fn versioned_uri(&self) -> &VersionedUri {
+        self.id()
+    }
+
+    fn referenced_types(&self) -> Vec<(OntologyTypeKind, &VersionedUri)> {
+        let data_types = self
+            .data_type_references()
+            .into_iter()
+            .map(|reference| (OntologyTypeKind::DataType, reference.uri()));
+        let property_types = self
+            .property_type_references()
+            .into_iter()
+            .map(|reference| (OntologyTypeKind::PropertyType, reference.uri()));
+
+        sorted_references(data_types.chain(property_types))
+    }
+}
+
+impl OntologyType for EntityType {
+    const KIND: OntologyTypeKind = OntologyTypeKind::EntityType;
+
+    fn versioned_uri(&self) -> &VersionedUri {
+        self.id()
+    }
+
+    fn referenced_types(&self) -> Vec<(OntologyTypeKind, &VersionedUri)> {
+        let property_types = self
+            .property_type_references()
+            .into_iter()
+            .map(|reference| (OntologyTypeKind::PropertyType, reference.uri()));
+        let links = self
+            .link_type_references()
+            .into_iter()
+            .flat_map(|(link_type, entity_type)| {
+                [
+                    (OntologyTypeKind::LinkType, link_type),
+                    (OntologyTypeKind::EntityType, entity_type.uri()),
+                ]
+            });
+
+        sorted_references(property_types.chain(links))
+    }
+}
+
+impl OntologyType for LinkType {
+    const KIND: OntologyTypeKind = OntologyTypeKind::LinkType;
+
+    fn versioned_uri(&self) -> &VersionedUri {
+        self.id()
+    }
+}
+
+fn sorted_references<'a>(
+    references: impl IntoIterator<Item = (OntologyTypeKind, &'a VersionedUri)>,
+) -> Vec<(OntologyTypeKind, &'a VersionedUri)> {
+    let mut references: Vec<_> = references.into_iter().collect();
+    references.sort_by_cached_key(|(kind, uri)| (*kind, uri.to_string()));
+    references.dedup();
+    references
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::{test_data, uri::ParseTypeUriComponentsError};
+
+    #[test]
+    fn kind_serialization() {
+        assert_eq!(
+            serde_json::to_value(OntologyTypeKind::EntityType).expect("failed to serialize"),
+            json!("entityType")
+        );
+        assert_eq!(OntologyTypeKind::LinkType.to_string(), "linkType");
+        assert_eq!(
+            OntologyTypeKind::from_uri_segment("property-type"),
+            Some(OntologyTypeKind::PropertyType)
+        );
+        assert_eq!(OntologyTypeKind::from_uri_segment("propertyType"), None);
+    }
+
+    #[test]
+    fn matching_kind() {
+        let entity_type =
+            EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid entity type");
+
+        entity_type
+            .validate_kind()
+            .expect("kind should match the URI");
+    }
+
+    #[test]
+    fn referenced_types() {
+        let entity_type =
+            EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid entity type");
+        let references: Vec<_> = entity_type
+            .referenced_types()
+            .into_iter()
+            .map(|(kind, uri)| (kind, uri.to_string()))
+            .collect();
+
+        assert_eq!(references, [
+            (
+                OntologyTypeKind::PropertyType,
+                "https://blockprotocol.org/@alice/types/property-type/blurb/v/1".to_owned()
+            ),
+            (
+                OntologyTypeKind::PropertyType,
+                "https://blockprotocol.org/@alice/types/property-type/name/v/1".to_owned()
+            ),
+            (
+                OntologyTypeKind::PropertyType,
+                "https://blockprotocol.org/@alice/types/property-type/published-on/v/1".to_owned()
+            ),
+            (
+                OntologyTypeKind::EntityType,
+                "https://blockprotocol.org/@alice/types/entity-type/person/v/1".to_owned()
+            ),
+            (
+                OntologyTypeKind::LinkType,
+                "https://blockprotocol.org/@alice/types/link-type/written-by/v/1".to_owned()
+            ),
+        ]);
+    }
+
+    #[test]
+    fn mismatched_kind() {
+        let property_type = PropertyType::try_from(json!({
+            "kind": "propertyType",
+            "$id": "https://blockprotocol.org/@alice/types/data-type/age/v/1",
+            "title": "Age",
+            "pluralTitle": "Ages",
+            "oneOf": [
+                { "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1" }
+            ]
+        }))
+        .expect("invalid property type");
+
+        assert_eq!(
+            property_type.validate_kind(),
+            Err(ValidationError::MismatchedKind {
+                expected: OntologyTypeKind::PropertyType,
+                actual: OntologyTypeKind::DataType,
+            })
+        );
+    }
+
+    #[test]
+    fn unconventional_uri() {
+        let data_type = DataType::try_from(json!({
+            "kind": "dataType",
+            "$id": "https://example.com/text/v/1",
+            "title": "Text",
+            "type": "string"
+        }))
+        .expect("invalid data type");
+
+        assert_eq!(
+            data_type.validate_kind(),
+            Err(ValidationError::UnconventionalUri(
+                ParseTypeUriComponentsError::MissingNamespace
+            ))
+        );
+    }
+}
@@ -0,0 +1,538 @@
+//! Generating `OpenAPI` documents for entities.
+//!
+//! An [`OpenApiGenerator`] creates an [OpenAPI 3.1](https://spec.openapis.org/oas/v3.1.0) document
+//! for a list of entity types:
+//!
+//! - The entity types, and all property types and data types used by them, become schemas inside of
+//!   `components.schemas`, which are named after the titles of the types, e.g. `Contact
+//!   Information` becomes `ContactInformation`. Property types and data types which are shared by
+//!   multiple entity types are only added once.
+//! - The schemas are the JSON Schemas of the types, in which references to other types are replaced
+//!   by references to their components. As `$id` would change how these references are resolved,
+//!   the URI of a type is stored in `x-blockprotocol-id` instead. Links are not part of the schemas
+//!   of entity types.
+//! - Optionally, paths to list, create, read, update, and delete the entities of each entity type
+//!   are added, e.g. `/books` and `/books/{entityId}` for `Book`. The paths only contain the
+//!   operations and their schemas and are meant to be filled in by the API.
+
+mod error;
+
+use std::collections::{hash_map::Entry, BTreeMap, HashMap, HashSet};
+
+pub use error::GenerateOpenApiError;
+use serde_json::{json, Map, Value};
+
+use crate::{
+    ontology::shared::naming::{pascal_case, slug, unique, words, UnresolvedTypeError},
+    repository::TypeResolver,
+    uri::VersionedUri,
+    DataType, EntityType, PropertyType, PropertyTypeReference, PropertyValues, ValueOrArray,
+};
+
+/// Converts a title into the name of a component, e.g. `Contact Information` into
+/// `ContactInformation`.
+fn component_name(title: &str) -> Result<String, GenerateOpenApiError> {
+    let name = pascal_case(&words(title));
+    if name.is_empty() {
+        Err(GenerateOpenApiError::InvalidTitle(title.to_owned()))
+    } else {
+        Ok(name)
+    }
+}
+
+/// Replaces every `$ref` to a type in `value` by a reference to the component of the type.
+fn replace_references(value: &mut Value, components: &HashMap<String, String>) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::String(reference)) = object.get_mut("$ref") {
+                if let Some(name) = components.get(reference.as_str()) {
+                    *reference = format!("#/components/schemas/{name}");
+                }
+            }
+            for value in object.values_mut() {
+                replace_references(value, components);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                replace_references(value, components);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Turns the JSON representation of a type into the schema of its component.
+fn schema(mut value: Value, id: &VersionedUri, components: &HashMap<String, String>) -> Value {
+    if let Value::Object(object) = &mut value {
+        for keyword in ["kind", "$id", "links", "requiredLinks"] {
+            object.remove(keyword);
+        }
+        object.insert("x-blockprotocol-id".to_owned(), json!(id.to_string()));
+    }
+    replace_references(&mut value, components);
+    value
+}
+
+/// Collects the property types and data types used by the entity types.
+struct Dependencies<'a, R> {
+    resolver: &'a R,
+    property_types: BTreeMap<String, PropertyType>,
+    data_types: BTreeMap<String, DataType>,
+}
+
+impl<R> Dependencies<'_, R>
+where
+    R: TypeResolver,
+    R::Error: std::fmt::Display,
+{
+    fn properties<'p>(
+        &mut self,
+        properties: impl Iterator<Item = &'p ValueOrArray<PropertyTypeReference>>,
+    ) -> Result<(), GenerateOpenApiError> {
+        for property in properties {
+            let reference = match property {
+                ValueOrArray::Value(reference) => reference,
+                ValueOrArray::Array(array) => array.items(),
+            };
+            let key = reference.uri().to_string();
+            if self.property_types.contains_key(&key) {
+                continue;
+            }
+
+            let property_type = self
+                .resolver
+                .resolve_property_type(reference.uri())
+                .map_err(|error| GenerateOpenApiError::unresolved(reference.uri(), &error))?;
+            self.property_types.insert(key, property_type.clone());
+            self.values(property_type.one_of())?;
+        }
+        Ok(())
+    }
+
+    fn values(&mut self, values: &[PropertyValues]) -> Result<(), GenerateOpenApiError> {
+        for value in values {
+            match value {
+                PropertyValues::DataTypeReference(reference) => {
+                    let key = reference.uri().to_string();
+                    if !self.data_types.contains_key(&key) {
+                        let data_type =
+                            self.resolver
+                                .resolve_data_type(reference.uri())
+                                .map_err(|error| {
+                                    GenerateOpenApiError::unresolved(reference.uri(), &error)
+                                })?;
+                        self.data_types.insert(key, data_type);
+                    }
+                }
+                PropertyValues::PropertyTypeObject(object) => {
+                    self.properties(object.properties().values())?;
+                }
+                PropertyValues::ArrayOfPropertyValues(array) => {
+                    self.values(array.items().one_of())?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Generates `OpenAPI` documents for entity types.
+#[derive(Debug, Clone)]
+pub struct OpenApiGenerator {
+    title: String,
+    version: String,
+    paths: bool,
+}
+
+impl OpenApiGenerator {
+    /// Creates a generator for documents with the given `info.title` and `info.version`, which
+    /// only contain components.
+    #[must_use]
+    pub fn new(title: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            version: version.into(),
+            paths: false,
+        }
+    }
+
+    /// Sets whether paths for the entities of each entity type are generated.
+    #[must_use]
+    pub const fn with_paths(mut self, paths: bool) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    /// Returns the `OpenAPI` document for `entity_types`, whose referenced types are resolved by
+    /// `resolver`.
+    ///
+    /// # Errors
+    ///
+    /// - [`GenerateOpenApiError::UnresolvedType`] if a type used by the entity types cannot be
+    ///   resolved
+    /// - [`GenerateOpenApiError::InvalidTitle`] if a component or path cannot be named after a type
+    pub fn generate<R>(
+        &self,
+        entity_types: &[EntityType],
+        resolver: &R,
+    ) -> Result<Value, GenerateOpenApiError>
+    where
+        R: TypeResolver,
+        R::Error: std::fmt::Display,
+    {
+        let mut dependencies = Dependencies {
+            resolver,
+            property_types: BTreeMap::new(),
+            data_types: BTreeMap::new(),
+        };
+        for entity_type in entity_types {
+            dependencies.properties(entity_type.properties().values())?;
+        }
+
+        // Entity types are named first, so they are the least likely to require a suffix
+        let mut names = HashSet::new();
+        let mut components = HashMap::new();
+        for (id, title) in entity_types
+            .iter()
+            .map(|entity_type| (entity_type.id(), entity_type.title()))
+            .chain(
+                dependencies
+                    .property_types
+                    .values()
+                    .map(|property_type| (property_type.id(), property_type.title())),
+            )
+            .chain(
+                dependencies
+                    .data_types
+                    .values()
+                    .map(|data_type| (data_type.id(), data_type.title())),
+            )
+        {
+            if let Entry::Vacant(entry) = components.entry(id.to_string()) {
+                entry.insert(unique(&mut names, &component_name(title)?, ""));
+            }
+        }
+
+        let mut schemas = Map::new();
+        for entity_type in entity_types {
+            schemas.insert(
+                components[&entity_type.id().to_string()].clone(),
+                schema(entity_type.clone().into(), entity_type.id(), &components),
+            );
+        }
+        for property_type in dependencies.property_types.into_values() {
+            let id = property_type.id().clone();
+            schemas.insert(
+                components[&id.to_string()].clone(),
+                schema(property_type.into(), &id, &components),
+            );
+        }
+        for data_type in dependencies.data_types.into_values() {
+            let id = data_type.id().clone();
+            schemas.insert(
+                components[&id.to_string()].clone(),
+                schema(data_type.into(), &id, &components),
+            );
+        }
+
+        let mut document = json!({
+            "openapi": "3.1.0",
+            "info": { "title": self.title, "version": self.version },
+        });
+        if self.paths {
+            let mut paths = Map::new();
+            let mut collections = HashSet::new();
+            let mut lists = HashSet::new();
+            for entity_type in entity_types {
+                let name = &components[&entity_type.id().to_string()];
+                // The operations share the suffix of the component, so they are unique as well
+                let suffix = name
+                    .strip_prefix(component_name(entity_type.title())?.as_str())
+                    .unwrap_or_default();
+                let collection = slug(&words(entity_type.plural_title()));
+                if collection.is_empty() {
+                    return Err(GenerateOpenApiError::InvalidTitle(
+                        entity_type.plural_title().to_owned(),
+                    ));
+                }
+                let collection = unique(&mut collections, &collection, "-");
+                let plural = unique(
+                    &mut lists,
+                    &format!(
+                        "{}{suffix}",
+                        pascal_case(&words(entity_type.plural_title()))
+                    ),
+                    "",
+                );
+                operations(&mut paths, &collection, name, &plural, entity_type.title());
+            }
+            document["paths"] = Value::Object(paths);
+        }
+        document["components"] = json!({ "schemas": schemas });
+        Ok(document)
+    }
+}
+
+/// Adds the paths for the entities of an entity type to `paths`.
+fn operations(
+    paths: &mut Map<String, Value>,
+    collection: &str,
+    name: &str,
+    plural: &str,
+    title: &str,
+) {
+    let entity = json!({
+        "content": {
+            "application/json": {
+                "schema": { "$ref": format!("#/components/schemas/{name}") }
+            }
+        }
+    });
+    let response = |description: String| {
+        let mut response = entity.clone();
+        response["description"] = json!(description);
+        response
+    };
+    let not_found = json!({ "description": format!("The {title} does not exist") });
+    let mut request = entity.clone();
+    request["required"] = json!(true);
+
+    paths.insert(
+        format!("/{collection}"),
+        json!({
+            "get": {
+                "operationId": format!("list{plural}"),
+                "tags": [name],
+                "responses": {
+                    "200": {
+                        "description": format!("All {title} entities"),
+                        "content": {
+                            "application/json": {
+                                "schema": {
+                                    "type": "array",
+                                    "items": { "$ref": format!("#/components/schemas/{name}") }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "post": {
+                "operationId": format!("create{name}"),
+                "tags": [name],
+                "requestBody": request,
+                "responses": { "201": response(format!("The created {title}")) }
+            }
+        }),
+    );
+    paths.insert(
+        format!("/{collection}/{{entityId}}"),
+        json!({
+            "parameters": [{
+                "name": "entityId",
+                "in": "path",
+                "required": true,
+                "schema": { "type": "string" }
+            }],
+            "get": {
+                "operationId": format!("get{name}"),
+                "tags": [name],
+                "responses": {
+                    "200": response(format!("The {title}")),
+                    "404": not_found
+                }
+            },
+            "put": {
+                "operationId": format!("update{name}"),
+                "tags": [name],
+                "requestBody": request,
+                "responses": {
+                    "200": response(format!("The updated {title}")),
+                    "404": not_found
+                }
+            },
+            "delete": {
+                "operationId": format!("delete{name}"),
+                "tags": [name],
+                "responses": {
+                    "204": { "description": format!("The {title} was deleted") },
+                    "404": not_found
+                }
+            }
+        }),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        repository::{
+            fixtures::{self, store},
+            FsTypeRepository,
+        },
+        test_data,
+    };
+
+    fn repository(directory: &tempfile::TempDir) -> FsTypeRepository {
+        let repository = fixtures::repository(directory);
+        store(
+            &repository,
+            &PropertyType::try_from(json!({
+                "kind": "propertyType",
+                "$id": "https://blockprotocol.org/@alice/types/property-type/address/v/1",
+                "title": "Address",
+                "pluralTitle": "Addresses",
+                "oneOf": [{
+                    "type": "object",
+                    "properties": {
+                        "https://blockprotocol.org/@alice/types/property-type/name/": {
+                            "$ref": "https://blockprotocol.org/@alice/types/property-type/name/v/1"
+                        }
+                    }
+                }]
+            }))
+            .expect("invalid type"),
+        );
+        repository
+    }
+
+    fn entity_types() -> Vec<EntityType> {
+        vec![
+            EntityType::try_from(json!({
+                "kind": "entityType",
+                "$id": "https://blockprotocol.org/@alice/types/entity-type/person/v/1",
+                "type": "object",
+                "title": "Person",
+                "pluralTitle": "People",
+                "properties": {
+                    "https://blockprotocol.org/@alice/types/property-type/age/": {
+                        "$ref": "https://blockprotocol.org/@alice/types/property-type/age/v/1"
+                    },
+                    "https://blockprotocol.org/@alice/types/property-type/address/": {
+                        "type": "array",
+                        "items": {
+                            "$ref": "https://blockprotocol.org/@alice/types/property-type/address/v/1"
+                        }
+                    }
+                },
+                "links": {
+                    "https://blockprotocol.org/@alice/types/link-type/owns/v/2": {
+                        "$ref": "https://blockprotocol.org/@alice/types/entity-type/song/v/1"
+                    }
+                }
+            }))
+            .expect("invalid entity type"),
+            EntityType::from_str(test_data::entity_type::SONG_V1).expect("invalid entity type"),
+        ]
+    }
+
+    #[test]
+    fn components() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let document = OpenApiGenerator::new("Music", "1.0.0")
+            .generate(&entity_types(), &repository(&directory))
+            .expect("could not generate document");
+
+        assert_eq!(document["openapi"], "3.1.0");
+        assert!(document.get("paths").is_none());
+        let schemas = &document["components"]["schemas"];
+        let mut names: Vec<_> = schemas
+            .as_object()
+            .expect("schemas are not an object")
+            .keys()
+            .collect();
+        names.sort();
+        assert_eq!(names, [
+            "Address", "Age", "Name", "Number", "Person", "Song", "Text"
+        ]);
+
+        assert_eq!(
+            schemas["Person"],
+            json!({
+                "type": "object",
+                "title": "Person",
+                "pluralTitle": "People",
+                "properties": {
+                    "https://blockprotocol.org/@alice/types/property-type/age/": {
+                        "$ref": "#/components/schemas/Age"
+                    },
+                    "https://blockprotocol.org/@alice/types/property-type/address/": {
+                        "type": "array",
+                        "items": { "$ref": "#/components/schemas/Address" }
+                    }
+                },
+                "x-blockprotocol-id": "https://blockprotocol.org/@alice/types/entity-type/person/v/1"
+            })
+        );
+        assert_eq!(
+            schemas["Address"]["oneOf"][0]["properties"]
+                ["https://blockprotocol.org/@alice/types/property-type/name/"],
+            json!({ "$ref": "#/components/schemas/Name" })
+        );
+        assert_eq!(
+            schemas["Text"],
+            json!({
+                "title": "Text",
+                "description": "An ordered sequence of characters",
+                "type": "string",
+                "x-blockprotocol-id": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1"
+            })
+        );
+    }
+
+    #[test]
+    fn paths() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let document = OpenApiGenerator::new("Music", "1.0.0")
+            .with_paths(true)
+            .generate(&entity_types(), &repository(&directory))
+            .expect("could not generate document");
+
+        let paths = &document["paths"];
+        assert_eq!(paths["/people"]["get"]["operationId"], "listPeople");
+        assert_eq!(
+            paths["/people"]["get"]["responses"]["200"]["content"]["application/json"]["schema"]
+                ["items"],
+            json!({ "$ref": "#/components/schemas/Person" })
+        );
+        assert_eq!(paths["/people"]["post"]["operationId"], "createPerson");
+        assert_eq!(paths["/people"]["post"]["requestBody"]["required"], true);
+        assert_eq!(
+            paths["/songs/{entityId}"]["put"]["responses"]["200"]["content"]["application/json"]
+                ["schema"],
+            json!({ "$ref": "#/components/schemas/Song" })
+        );
+        assert_eq!(
+            paths["/songs/{entityId}"]["delete"]["responses"]["404"]["description"],
+            "The Song does not exist"
+        );
+    }
+
+    #[test]
+    fn operation_ids_of_equally_titled_entity_types() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let mut entity_types = entity_types();
+        let mut other_person: serde_json::Value = entity_types[0].clone().into();
+        other_person["$id"] = json!("https://blockprotocol.org/@bob/types/entity-type/person/v/1");
+        entity_types.push(EntityType::try_from(other_person).expect("invalid entity type"));
+
+        let document = OpenApiGenerator::new("Music", "1.0.0")
+            .with_paths(true)
+            .generate(&entity_types, &repository(&directory))
+            .expect("could not generate document");
+
+        let paths = &document["paths"];
+        assert_eq!(paths["/people"]["get"]["operationId"], "listPeople");
+        assert_eq!(paths["/people"]["post"]["operationId"], "createPerson");
+        assert_eq!(paths["/people-2"]["get"]["operationId"], "listPeople2");
+        assert_eq!(paths["/people-2"]["post"]["operationId"], "createPerson2");
+        assert_eq!(
+            paths["/people-2/{entityId}"]["get"]["operationId"],
+            "getPerson2"
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum PatchTypeError<E> {
+    #[error("patch operation {index} refers to an invalid JSON pointer")]
+    InvalidPointer { index: usize },
+    #[error("patch operation {index} is a `test` operation which failed")]
+    TestFailed { index: usize },
+    #[error("the patched type is invalid since patch operation {index}: `{error}`")]
+    InvalidType { index: usize, error: E },
+}
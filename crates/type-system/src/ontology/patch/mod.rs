@@ -0,0 +1,220 @@
+//! Applying [JSON Patch] documents to types of the Type System.
+//!
+//! Patches are applied to the JSON representation of a type, after which the patched document is
+//! converted back to its native representation. This means a patched type goes through exactly the
+//! same validation as a type which was parsed from a string.
+//!
+//! [JSON Patch]: https://www.rfc-editor.org/rfc/rfc6902
+
+mod error;
+
+pub use error::PatchTypeError;
+pub use json_patch::{Patch, PatchOperation};
+
+/// Applies the given [`Patch`] to the JSON representation of `ontology_type` and converts the
+/// result back to its native representation.
+///
+/// The operations are applied in order. If the patched document is not a valid type, the returned
+/// error refers to the operation after which the document stopped being valid for good, i.e. the
+/// last operation which turned a valid intermediate document into an invalid one.
+///
+/// # Errors
+///
+/// - [`PatchTypeError::InvalidPointer`] if an operation refers to a location which does not exist
+/// - [`PatchTypeError::TestFailed`] if a `test` operation did not succeed
+/// - [`PatchTypeError::InvalidType`] if the patched document is not a valid type
+pub fn apply_patch<T>(
+    ontology_type: &T,
+    patch: &Patch,
+) -> Result<T, PatchTypeError<<T as TryFrom<serde_json::Value>>::Error>>
+where
+    T: Clone + Into<serde_json::Value> + TryFrom<serde_json::Value>,
+{
+    let mut documents = Vec::with_capacity(patch.0.len());
+    let mut document: serde_json::Value = ontology_type.clone().into();
+
+    for (index, operation) in patch.0.iter().enumerate() {
+        json_patch::patch(&mut document, &Patch(vec![operation.clone()])).map_err(|error| {
+            match error {
+                json_patch::PatchError::InvalidPointer => PatchTypeError::InvalidPointer { index },
+                json_patch::PatchError::TestFailed => PatchTypeError::TestFailed { index },
+            }
+        })?;
+        documents.push(document.clone());
+    }
+
+    let error = match T::try_from(document) {
+        Ok(patched) => return Ok(patched),
+        Err(error) => error,
+    };
+
+    // Intermediate documents don't have to be valid, e.g. a property has to be added before it can
+    // be marked as required. The offending operation is the one after which no document was valid
+    // anymore.
+    let index = documents
+        .iter()
+        .rposition(|document| T::try_from(document.clone()).is_ok())
+        .map_or(0, |valid_index| valid_index + 1);
+
+    Err(PatchTypeError::InvalidType { index, error })
+}
+
+/// Computes a [`Patch`] which transforms the JSON representation of `from` into the one of `to`.
+///
+/// Applying the returned patch to `from` by using [`apply_patch`] results in `to`.
+#[must_use]
+pub fn diff<T>(from: &T, to: &T) -> Patch
+where
+    T: Clone + Into<serde_json::Value>,
+{
+    json_patch::diff(&from.clone().into(), &to.clone().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test_data, uri::BaseUri, EntityType, LinkType, ParseEntityTypeError,
+        ParsePropertyTypeError, ParsePropertyTypeObjectError, PropertyType, ValidationError,
+    };
+
+    fn patch_from_value(value: serde_json::Value) -> Patch {
+        serde_json::from_value(value).expect("invalid JSON Patch")
+    }
+
+    #[test]
+    fn add_property() {
+        let entity_type =
+            EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid type");
+        let patch = patch_from_value(json!([
+            {
+                "op": "add",
+                "path": "/properties/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1age~1",
+                "value": { "$ref": "https://blockprotocol.org/@alice/types/property-type/age/v/1" }
+            },
+            {
+                "op": "add",
+                "path": "/required/-",
+                "value": "https://blockprotocol.org/@alice/types/property-type/age/"
+            }
+        ]));
+
+        let patched = apply_patch(&entity_type, &patch).expect("failed to apply patch");
+
+        let age =
+            BaseUri::new("https://blockprotocol.org/@alice/types/property-type/age/".to_owned())
+                .expect("invalid base URI");
+        assert!(patched.properties().contains_key(&age));
+        assert!(patched.required().contains(&age));
+    }
+
+    #[test]
+    fn invalid_pointer() {
+        let link_type = LinkType::from_str(test_data::link_type::OWNS_V2).expect("invalid type");
+        let patch = patch_from_value(json!([
+            { "op": "replace", "path": "/title", "value": "Possesses" },
+            { "op": "remove", "path": "/relatedKeywords/10" }
+        ]));
+
+        assert_eq!(
+            apply_patch(&link_type, &patch),
+            Err(PatchTypeError::InvalidPointer { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_failed() {
+        let property_type =
+            PropertyType::from_str(test_data::property_type::AGE_V1).expect("invalid type");
+        let patch = patch_from_value(json!([
+            { "op": "test", "path": "/title", "value": "Age" },
+            { "op": "test", "path": "/pluralTitle", "value": "Age" }
+        ]));
+
+        assert_eq!(
+            apply_patch(&property_type, &patch),
+            Err(PatchTypeError::TestFailed { index: 1 })
+        );
+    }
+
+    #[test]
+    fn invalid_result() {
+        let property_type =
+            PropertyType::from_str(test_data::property_type::AGE_V1).expect("invalid type");
+        let patch = patch_from_value(json!([
+            { "op": "replace", "path": "/title", "value": "Age in years" },
+            { "op": "remove", "path": "/oneOf/0" },
+            { "op": "replace", "path": "/pluralTitle", "value": "Ages in years" }
+        ]));
+
+        match apply_patch(&property_type, &patch) {
+            Err(PatchTypeError::InvalidType {
+                index: 1,
+                error: ParsePropertyTypeError::InvalidOneOf(_),
+            }) => {}
+            result => panic!("unexpected result: {result:?}"),
+        }
+    }
+
+    #[test]
+    fn temporarily_invalid() {
+        let entity_type =
+            EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid type");
+        let patch = patch_from_value(json!([
+            {
+                "op": "add",
+                "path": "/required/-",
+                "value": "https://blockprotocol.org/@alice/types/property-type/age/"
+            },
+            {
+                "op": "add",
+                "path": "/properties/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1age~1",
+                "value": { "$ref": "https://blockprotocol.org/@alice/types/property-type/age/v/1" }
+            },
+            {
+                "op": "add",
+                "path": "/required/-",
+                "value": "https://blockprotocol.org/@alice/types/property-type/email/"
+            }
+        ]));
+
+        assert_eq!(
+            apply_patch(&entity_type, &patch),
+            Err(PatchTypeError::InvalidType {
+                index: 2,
+                error: ParseEntityTypeError::InvalidPropertyTypeObject(
+                    ParsePropertyTypeObjectError::ValidationError(
+                        ValidationError::MissingRequiredProperty(
+                            BaseUri::new(
+                                "https://blockprotocol.org/@alice/types/property-type/email/"
+                                    .to_owned()
+                            )
+                            .expect("invalid base URI")
+                        )
+                    )
+                )
+            })
+        );
+    }
+
+    #[test]
+    fn diff_round_trip() {
+        let book = EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid type");
+        let song = EntityType::from_str(test_data::entity_type::SONG_V1).expect("invalid type");
+
+        let patch = diff(&book, &song);
+
+        assert_eq!(apply_patch(&book, &patch), Ok(song));
+    }
+
+    #[test]
+    fn diff_identical() {
+        let book = EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid type");
+
+        assert!(diff(&book, &book).0.is_empty());
+    }
+}
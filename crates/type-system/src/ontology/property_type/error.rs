@@ -25,4 +25,8 @@ pub enum ParsePropertyTypeError {
     InvalidArrayItems(Box<ParseOneOfArrayError>), // TODO - better name for variant
     #[error("error in JSON: `{0}`")]
     InvalidJson(String),
+    #[error("error in YAML: `{0}`")]
+    InvalidYaml(String),
+    #[error("error in TOML: `{0}`")]
+    InvalidToml(String),
 }
@@ -7,9 +7,15 @@ use std::{collections::HashSet, str::FromStr};
 
 pub use error::ParsePropertyTypeError;
 
+#[cfg(any(feature = "yaml", feature = "toml"))]
+use crate::format;
+#[cfg(feature = "toml")]
+use crate::format::SerializeTomlError;
 use crate::{
+    canonical::to_canonical_json,
     uri::{BaseUri, ParseVersionedUriError, VersionedUri},
-    Array, DataTypeReference, Object, OneOf, ValidateUri, ValidationError, ValueOrArray,
+    Array, Compatibility, DataTypeReference, Object, OneOf, ValidateUri, ValidationError,
+    ValueOrArray,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -82,6 +88,28 @@ impl PropertyType {
             .flat_map(|value| value.property_type_references().into_iter())
             .collect()
     }
+
+    /// Brings the property type into a normalized form.
+    ///
+    /// Parts of the schema where the order is not meaningful are sorted and deduplicated, i.e. the
+    /// `oneOf` variants and `required` lists, and `minItems` constraints of `0` are removed. Two
+    /// normalized property types are equal if they only differ in these parts.
+    pub fn normalize(&mut self) {
+        normalize_one_of(&mut self.one_of);
+    }
+}
+
+fn normalize_one_of(one_of: &mut OneOf<PropertyValues>) {
+    for value in one_of.one_of_mut() {
+        value.normalize();
+    }
+    one_of.sort_and_dedup_by_key(to_canonical_json);
+}
+
+impl Compatibility for PropertyType {
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        self.id.base_uri() == other.id.base_uri() && self.one_of.is_compatible_with(&other.one_of)
+    }
 }
 
 impl FromStr for PropertyType {
@@ -95,6 +123,57 @@ impl FromStr for PropertyType {
     }
 }
 
+#[cfg(any(feature = "yaml", feature = "toml"))]
+impl PropertyType {
+    /// Parses a property type from its YAML representation.
+    ///
+    /// # Errors
+    ///
+    /// - [`ParsePropertyTypeError::InvalidYaml`] if `property_type_yaml` is not a valid YAML
+    ///   representation of a property type
+    /// - the same errors as parsing the JSON representation otherwise
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(property_type_yaml: &str) -> Result<Self, ParsePropertyTypeError> {
+        let property_type_repr: repr::PropertyType = serde_yaml::from_str(property_type_yaml)
+            .map_err(|err| ParsePropertyTypeError::InvalidYaml(err.to_string()))?;
+
+        Self::try_from(property_type_repr)
+    }
+
+    /// Serializes the property type as YAML.
+    #[cfg(feature = "yaml")]
+    #[must_use]
+    pub fn to_yaml(&self) -> String {
+        format::to_yaml(&self.clone().into())
+    }
+
+    /// Parses a property type from its TOML representation.
+    ///
+    /// # Errors
+    ///
+    /// - [`ParsePropertyTypeError::InvalidToml`] if `property_type_toml` is not a valid TOML
+    ///   representation of a property type
+    /// - the same errors as parsing the JSON representation otherwise
+    #[cfg(feature = "toml")]
+    pub fn from_toml(property_type_toml: &str) -> Result<Self, ParsePropertyTypeError> {
+        let property_type_repr: repr::PropertyType = toml::from_str(property_type_toml)
+            .map_err(|err| ParsePropertyTypeError::InvalidToml(err.to_string()))?;
+
+        Self::try_from(property_type_repr)
+    }
+
+    /// Serializes the property type as TOML.
+    ///
+    /// # Errors
+    ///
+    /// - [`SerializeTomlError`] if the property type contains a value which cannot be represented
+    ///   in TOML, e.g. `null`
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> Result<String, SerializeTomlError> {
+        format::to_toml(&self.clone().into())
+    }
+}
+
 impl TryFrom<serde_json::Value> for PropertyType {
     type Error = ParsePropertyTypeError;
 
@@ -145,6 +224,12 @@ impl ValidateUri for PropertyTypeReference {
     }
 }
 
+impl Compatibility for PropertyTypeReference {
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        self.uri == other.uri
+    }
+}
+
 impl TryFrom<serde_json::Value> for PropertyTypeReference {
     type Error = ParseVersionedUriError;
 
@@ -187,6 +272,22 @@ impl PropertyValues {
         }
     }
 
+    fn normalize(&mut self) {
+        match self {
+            Self::DataTypeReference(_) => {}
+            Self::PropertyTypeObject(object) => {
+                object.normalize_required();
+                for property in object.properties_mut() {
+                    property.normalize_bounds();
+                }
+            }
+            Self::ArrayOfPropertyValues(array) => {
+                array.normalize_bounds();
+                normalize_one_of(array.items_mut());
+            }
+        }
+    }
+
     #[must_use]
     fn property_type_references(&self) -> Vec<&PropertyTypeReference> {
         match self {
@@ -209,6 +310,32 @@ impl PropertyValues {
     }
 }
 
+impl Compatibility for PropertyValues {
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::DataTypeReference(reference), Self::DataTypeReference(other_reference)) => {
+                reference.is_compatible_with(other_reference)
+            }
+            (Self::PropertyTypeObject(object), Self::PropertyTypeObject(other_object)) => {
+                object.is_compatible_with(other_object)
+            }
+            (Self::ArrayOfPropertyValues(array), Self::ArrayOfPropertyValues(other_array)) => {
+                array.is_compatible_with(other_array)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl From<PropertyValues> for serde_json::Value {
+    fn from(property_values: PropertyValues) -> Self {
+        let property_values_repr: repr::PropertyValues = property_values.into();
+
+        serde_json::to_value(property_values_repr)
+            .expect("Failed to serialize Property Values repr")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -440,4 +567,98 @@ mod tests {
             .validate_uri(uri_b.base_uri()) // Try and validate against a different URI
             .expect_err("expected validation against base URI to fail but it didn't");
     }
+
+    fn user_id_property_type(one_of: &serde_json::Value) -> PropertyType {
+        PropertyType::try_from(json!({
+            "kind": "propertyType",
+            "$id": "https://blockprotocol.org/@alice/types/property-type/user-id/v/3",
+            "title": "User ID",
+            "pluralTitle": "User IDs",
+            "oneOf": one_of
+        }))
+        .expect("invalid property type")
+    }
+
+    #[test]
+    fn normalize_one_of() {
+        let text =
+            json!({ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" });
+        let number = json!({ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1" });
+
+        let mut lhs = user_id_property_type(&json!([text, number, text]));
+        let mut rhs = user_id_property_type(&json!([number, text]));
+        assert_ne!(lhs, rhs);
+        assert!(lhs.is_equivalent_to(&rhs));
+
+        lhs.normalize();
+        rhs.normalize();
+        assert_eq!(lhs, rhs);
+        assert_eq!(lhs.one_of().len(), 2);
+    }
+
+    #[test]
+    fn normalize_nested_object() {
+        let email = "https://blockprotocol.org/@blockprotocol/types/property-type/email/";
+        let phone = "https://blockprotocol.org/@blockprotocol/types/property-type/phone-number/";
+        let object = |required: serde_json::Value, min_items: usize| {
+            json!([{
+                "type": "object",
+                "properties": {
+                    email: { "$ref": format!("{email}v/1") },
+                    phone: {
+                        "type": "array",
+                        "items": { "$ref": format!("{phone}v/1") },
+                        "minItems": min_items
+                    }
+                },
+                "required": required
+            }])
+        };
+
+        let mut lhs = user_id_property_type(&object(json!([phone, email, phone]), 0));
+        let mut rhs = user_id_property_type(&object(json!([email, phone]), 0));
+        assert_ne!(lhs, rhs);
+        assert!(lhs.is_equivalent_to(&rhs));
+        assert!(!lhs.is_equivalent_to(&user_id_property_type(&object(json!([email, phone]), 1))));
+
+        lhs.normalize();
+        rhs.normalize();
+        assert_eq!(lhs, rhs);
+    }
+
+    #[test]
+    fn compatibility() {
+        let text =
+            json!({ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" });
+        let number = json!({ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1" });
+
+        let text_only = user_id_property_type(&json!([text]));
+        let text_or_number = user_id_property_type(&json!([text, number]));
+
+        assert!(text_only.is_compatible_with(&text_or_number));
+        assert!(!text_or_number.is_compatible_with(&text_only));
+        assert!(!text_only.is_equivalent_to(&text_or_number));
+    }
+
+    #[test]
+    fn annotations_are_ignored() {
+        let user_id_v2 =
+            PropertyType::from_str(test_data::property_type::USER_ID_V2).expect("invalid type");
+        let mut renamed = user_id_v2.clone();
+        renamed.title = "Identifier".to_owned();
+        renamed.description = Some("Identifies a user".to_owned());
+
+        assert!(user_id_v2.is_equivalent_to(&renamed));
+    }
+
+    #[test]
+    fn different_property_types_are_incompatible() {
+        let age = PropertyType::from_str(test_data::property_type::AGE_V1).expect("invalid type");
+        let numbers =
+            PropertyType::from_str(test_data::property_type::NUMBERS_V1).expect("invalid type");
+        let mut renamed = age.clone();
+        renamed.id = numbers.id().clone();
+
+        assert!(!age.is_compatible_with(&renamed));
+    }
 }
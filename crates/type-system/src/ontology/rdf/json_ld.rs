@@ -0,0 +1,289 @@
+use std::collections::{BTreeMap, HashSet};
+
+use serde_json::{json, Map, Value};
+
+use super::{Ontology, Term, PREFIXES};
+use crate::{
+    uri::{BaseUri, VersionedUri},
+    EntityType, PropertyTypeReference, PropertyValues, TypeBundle, ValueOrArray,
+    ValueOrMaybeOrderedArray,
+};
+
+fn term(term: &Term) -> Value {
+    match term {
+        Term::Iri(iri) => json!({ "@id": iri }),
+        Term::Name(name) => json!({ "@id": name }),
+        Term::Literal(literal) => json!(literal),
+        Term::Count(count) => json!({
+            "@value": count.to_string(),
+            "@type": "xsd:nonNegativeInteger"
+        }),
+        Term::Node(statements) => node(statements),
+    }
+}
+
+fn node(statements: &[(&'static str, Term)]) -> Value {
+    let mut node = Map::new();
+    for (predicate, object) in statements {
+        let (key, value) = if *predicate == "rdf:type" {
+            let class = match object {
+                Term::Name(class) => class,
+                _ => unreachable!("classes are always referenced by name"),
+            };
+            ("@type", json!(class))
+        } else {
+            (*predicate, term(object))
+        };
+
+        match node.get_mut(key) {
+            Some(Value::Array(values)) => values.push(value),
+            Some(existing) => *existing = json!([existing.take(), value]),
+            None => {
+                node.insert(key.to_owned(), value);
+            }
+        }
+    }
+    Value::Object(node)
+}
+
+impl Ontology {
+    /// Renders the ontology as a [JSON-LD](https://www.w3.org/TR/json-ld11/) document.
+    #[must_use]
+    pub fn to_json_ld(&self) -> Value {
+        let context: Map<_, _> = PREFIXES
+            .iter()
+            .map(|(prefix, iri)| ((*prefix).to_owned(), json!(iri)))
+            .collect();
+        let graph: Vec<_> = self
+            .resources
+            .iter()
+            .map(|resource| {
+                let mut node = node(&resource.statements);
+                node["@id"] = json!(resource.iri);
+                node
+            })
+            .collect();
+
+        json!({ "@context": context, "@graph": graph })
+    }
+}
+
+/// Collects the term definitions for the properties of an object.
+struct Context<'a> {
+    bundle: &'a TypeBundle,
+    terms: BTreeMap<String, Map<String, Value>>,
+    visited: HashSet<&'a VersionedUri>,
+}
+
+impl<'a> Context<'a> {
+    fn define(&mut self, iri: &str) -> &mut Map<String, Value> {
+        self.terms.entry(iri.to_owned()).or_insert_with(|| {
+            let mut definition = Map::new();
+            definition.insert("@id".to_owned(), json!(iri));
+            definition
+        })
+    }
+
+    fn properties(
+        &mut self,
+        properties: impl Iterator<Item = (&'a BaseUri, &'a ValueOrArray<PropertyTypeReference>)>,
+    ) {
+        for (base_uri, property) in properties {
+            let reference = match property {
+                ValueOrArray::Value(reference) => reference,
+                ValueOrArray::Array(array) => {
+                    // The order of the values of a property is preserved
+                    self.define(base_uri.as_str())
+                        .insert("@container".to_owned(), json!("@list"));
+                    array.items()
+                }
+            };
+            self.define(base_uri.as_str());
+            self.property_type(base_uri, reference.uri());
+        }
+    }
+
+    fn property_type(&mut self, base_uri: &BaseUri, uri: &'a VersionedUri) {
+        if !self.visited.insert(uri) {
+            return;
+        }
+        let property_type = match self.bundle.property_type(uri) {
+            Some(property_type) => property_type,
+            None => return,
+        };
+
+        if let [PropertyValues::DataTypeReference(reference)] = property_type.one_of() {
+            // Values of the Block Protocol data types are native JSON-LD values
+            if !reference
+                .uri()
+                .base_uri()
+                .as_str()
+                .starts_with("https://blockprotocol.org/@blockprotocol/types/data-type/")
+            {
+                self.define(base_uri.as_str())
+                    .insert("@type".to_owned(), json!(reference.uri().to_string()));
+            }
+        }
+        self.values(property_type.one_of());
+    }
+
+    fn values(&mut self, values: &'a [PropertyValues]) {
+        for value in values {
+            match value {
+                PropertyValues::DataTypeReference(_) => {}
+                PropertyValues::PropertyTypeObject(object) => {
+                    self.properties(object.properties().iter());
+                }
+                PropertyValues::ArrayOfPropertyValues(array) => {
+                    self.values(array.items().one_of());
+                }
+            }
+        }
+    }
+}
+
+/// Returns a JSON-LD document containing the `@context` for instances of `entity_type`.
+///
+/// The context defines a term for every property and link of the entity type, as well as for the
+/// properties of nested objects, which are looked up in `bundle`:
+///
+/// - properties whose values are arrays and ordered links are `@list`s,
+/// - links refer to entities by their `@id`,
+/// - the values of a property type which only allows a single data type, which is not a Block
+///   Protocol data type, are typed with the data type.
+#[must_use]
+pub fn json_ld_context(entity_type: &EntityType, bundle: &TypeBundle) -> Value {
+    let mut context = Context {
+        bundle,
+        terms: BTreeMap::new(),
+        visited: HashSet::new(),
+    };
+    context.properties(entity_type.properties().iter());
+
+    for (link_type, link) in entity_type.links() {
+        let definition = context.define(&link_type.to_string());
+        definition.insert("@type".to_owned(), json!("@id"));
+        if let ValueOrMaybeOrderedArray::Array(array) = link {
+            if array.ordered() {
+                definition.insert("@container".to_owned(), json!("@list"));
+            }
+        }
+    }
+
+    json!({ "@context": context.terms })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rdf::tests::bundle, PropertyType};
+
+    #[test]
+    fn json_ld() {
+        let json_ld = Ontology::new(&bundle()).to_json_ld();
+        assert_eq!(
+            json_ld["@context"]["owl"],
+            json!("http://www.w3.org/2002/07/owl#")
+        );
+
+        let book = json_ld["@graph"]
+            .as_array()
+            .expect("graph is not an array")
+            .iter()
+            .find(|node| {
+                node["@id"] == "https://blockprotocol.org/@alice/types/entity-type/book/v/1"
+            })
+            .expect("missing entity type");
+        assert_eq!(book["@type"], "owl:Class");
+        assert_eq!(book["rdfs:label"], "Book");
+        assert_eq!(
+            book["rdfs:subClassOf"][0],
+            json!({
+                "@type": "owl:Restriction",
+                "owl:onProperty": {
+                    "@id": "https://blockprotocol.org/@alice/types/link-type/written-by/v/1"
+                },
+                "owl:minCardinality": { "@value": "1", "@type": "xsd:nonNegativeInteger" }
+            })
+        );
+    }
+
+    #[test]
+    fn context() {
+        let mut bundle = bundle();
+        bundle.insert_property_type(
+            PropertyType::try_from(json!({
+                "kind": "propertyType",
+                "$id": "https://example.com/@alice/types/property-type/published-in/v/1",
+                "title": "Published In",
+                "pluralTitle": "Published In",
+                "oneOf": [{ "$ref": "https://example.com/@alice/types/data-type/year/v/1" }]
+            }))
+            .expect("invalid property type"),
+        );
+        let entity_type = EntityType::try_from(json!({
+            "kind": "entityType",
+            "$id": "https://example.com/@alice/types/entity-type/album/v/1",
+            "type": "object",
+            "title": "Album",
+            "pluralTitle": "Albums",
+            "properties": {
+                "https://blockprotocol.org/@alice/types/property-type/name/": {
+                    "type": "array",
+                    "items": { "$ref": "https://blockprotocol.org/@alice/types/property-type/name/v/1" }
+                },
+                "https://blockprotocol.org/@alice/types/property-type/contact-information/": {
+                    "$ref": "https://blockprotocol.org/@alice/types/property-type/contact-information/v/1"
+                },
+                "https://example.com/@alice/types/property-type/published-in/": {
+                    "$ref": "https://example.com/@alice/types/property-type/published-in/v/1"
+                }
+            },
+            "links": {
+                "https://blockprotocol.org/@alice/types/link-type/contains/v/1": {
+                    "type": "array",
+                    "items": { "$ref": "https://blockprotocol.org/@alice/types/entity-type/song/v/1" },
+                    "ordered": true
+                },
+                "https://blockprotocol.org/@alice/types/link-type/written-by/v/1": {
+                    "$ref": "https://blockprotocol.org/@alice/types/entity-type/person/v/1"
+                }
+            }
+        }))
+        .expect("invalid entity type");
+
+        assert_eq!(
+            json_ld_context(&entity_type, &bundle),
+            json!({
+                "@context": {
+                    "https://blockprotocol.org/@alice/types/link-type/contains/v/1": {
+                        "@id": "https://blockprotocol.org/@alice/types/link-type/contains/v/1",
+                        "@type": "@id",
+                        "@container": "@list"
+                    },
+                    "https://blockprotocol.org/@alice/types/link-type/written-by/v/1": {
+                        "@id": "https://blockprotocol.org/@alice/types/link-type/written-by/v/1",
+                        "@type": "@id"
+                    },
+                    "https://blockprotocol.org/@alice/types/property-type/contact-information/": {
+                        "@id": "https://blockprotocol.org/@alice/types/property-type/contact-information/"
+                    },
+                    "https://blockprotocol.org/@alice/types/property-type/name/": {
+                        "@id": "https://blockprotocol.org/@alice/types/property-type/name/",
+                        "@container": "@list"
+                    },
+                    "https://blockprotocol.org/@blockprotocol/types/property-type/email/": {
+                        "@id": "https://blockprotocol.org/@blockprotocol/types/property-type/email/"
+                    },
+                    "https://blockprotocol.org/@blockprotocol/types/property-type/phone-number/": {
+                        "@id": "https://blockprotocol.org/@blockprotocol/types/property-type/phone-number/"
+                    },
+                    "https://example.com/@alice/types/property-type/published-in/": {
+                        "@id": "https://example.com/@alice/types/property-type/published-in/",
+                        "@type": "https://example.com/@alice/types/data-type/year/v/1"
+                    }
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! Exporting types of the Type System as linked data.
+//!
+//! An [`Ontology`] describes the types of a [`TypeBundle`] in terms of OWL and can be rendered as
+//! [Turtle] or [JSON-LD]:
+//!
+//! - [`EntityType`]s become `owl:Class`es identified by their [`VersionedUri`]. Required properties
+//!   and links, as well as the target of links, are described by `owl:Restriction`s the class is a
+//!   subclass of.
+//! - [`PropertyType`]s become `owl:DatatypeProperty`s identified by their [`BaseUri`], as entities
+//!   are keyed by the base URIs of their properties. Property types which allow objects become
+//!   `owl:ObjectProperty`s instead. The range of a property type is only specified if it allows a
+//!   single data type.
+//! - [`LinkType`]s become `owl:ObjectProperty`s identified by their [`VersionedUri`].
+//! - [`DataType`]s become `rdfs:Datatype`s identified by their [`VersionedUri`]. Data types without
+//!   constraints are equivalent to the corresponding XML Schema datatype.
+//!
+//! The `title` and `description` of a type are used as its `rdfs:label` and `rdfs:comment`.
+//!
+//! [`json_ld_context`] creates a JSON-LD `@context` for the instances of an entity type.
+//!
+//! [Turtle]: https://www.w3.org/TR/turtle/
+//! [JSON-LD]: https://www.w3.org/TR/json-ld11/
+//! [`BaseUri`]: crate::uri::BaseUri
+//! [`VersionedUri`]: crate::uri::VersionedUri
+
+mod json_ld;
+mod turtle;
+
+pub use json_ld::json_ld_context;
+
+use crate::{
+    DataType, EntityType, LinkType, PropertyType, PropertyValues, TypeBundle,
+    ValueOrMaybeOrderedArray,
+};
+
+/// The prefixes used for the vocabularies referenced by an [`Ontology`].
+const PREFIXES: &[(&str, &str)] = &[
+    ("owl", "http://www.w3.org/2002/07/owl#"),
+    ("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+    ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+];
+
+/// The object of a statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Term {
+    /// An absolute IRI.
+    Iri(String),
+    /// An IRI abbreviated by one of the [`PREFIXES`].
+    Name(&'static str),
+    Literal(String),
+    /// A literal of the type `xsd:nonNegativeInteger`.
+    Count(usize),
+    /// A blank node described by the given statements.
+    Node(Vec<(&'static str, Term)>),
+}
+
+/// A resource described by an [`Ontology`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Resource {
+    iri: String,
+    statements: Vec<(&'static str, Term)>,
+}
+
+impl Resource {
+    fn new(iri: String, class: &'static str, title: &str, description: Option<&str>) -> Self {
+        let mut statements = vec![
+            ("rdf:type", Term::Name(class)),
+            ("rdfs:label", Term::Literal(title.to_owned())),
+        ];
+        if let Some(description) = description {
+            statements.push(("rdfs:comment", Term::Literal(description.to_owned())));
+        }
+        Self { iri, statements }
+    }
+}
+
+fn restriction(property: String, constraint: &'static str, value: Term) -> Term {
+    Term::Node(vec![
+        ("rdf:type", Term::Name("owl:Restriction")),
+        ("owl:onProperty", Term::Iri(property)),
+        (constraint, value),
+    ])
+}
+
+/// Returns whether the values contain an object.
+fn allows_objects(values: &[PropertyValues]) -> bool {
+    values.iter().any(|value| match value {
+        PropertyValues::DataTypeReference(_) => false,
+        PropertyValues::PropertyTypeObject(_) => true,
+        PropertyValues::ArrayOfPropertyValues(array) => allows_objects(array.items().one_of()),
+    })
+}
+
+/// The types of a [`TypeBundle`] described in terms of OWL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ontology {
+    resources: Vec<Resource>,
+}
+
+impl Ontology {
+    /// Describes all types inside of `bundle`.
+    #[must_use]
+    pub fn new(bundle: &TypeBundle) -> Self {
+        let mut resources: Vec<_> = bundle
+            .data_types()
+            .map(data_type)
+            .chain(bundle.property_types().map(property_type))
+            .chain(bundle.entity_types().map(entity_type))
+            .chain(bundle.link_types().map(link_type))
+            .collect();
+        resources.sort_by(|lhs, rhs| lhs.iri.cmp(&rhs.iri));
+        Self { resources }
+    }
+}
+
+fn data_type(data_type: &DataType) -> Resource {
+    let mut resource = Resource::new(
+        data_type.id().to_string(),
+        "rdfs:Datatype",
+        data_type.title(),
+        data_type.description(),
+    );
+
+    let equivalent = match data_type.json_type() {
+        "string" => Some("xsd:string"),
+        "number" => Some("xsd:double"),
+        "integer" => Some("xsd:integer"),
+        "boolean" => Some("xsd:boolean"),
+        _ => None,
+    };
+    if let (Some(equivalent), true) = (equivalent, data_type.additional_properties().is_empty()) {
+        resource
+            .statements
+            .push(("owl:equivalentClass", Term::Name(equivalent)));
+    }
+    resource
+}
+
+fn property_type(property_type: &PropertyType) -> Resource {
+    let class = if allows_objects(property_type.one_of()) {
+        "owl:ObjectProperty"
+    } else {
+        "owl:DatatypeProperty"
+    };
+    let mut resource = Resource::new(
+        property_type.id().base_uri().to_string(),
+        class,
+        property_type.title(),
+        property_type.description(),
+    );
+
+    if let [PropertyValues::DataTypeReference(reference)] = property_type.one_of() {
+        resource
+            .statements
+            .push(("rdfs:range", Term::Iri(reference.uri().to_string())));
+    }
+    resource
+}
+
+fn entity_type(entity_type: &EntityType) -> Resource {
+    let mut resource = Resource::new(
+        entity_type.id().to_string(),
+        "owl:Class",
+        entity_type.title(),
+        entity_type.description(),
+    );
+
+    let mut required: Vec<_> = entity_type
+        .required()
+        .iter()
+        .map(ToString::to_string)
+        .chain(entity_type.required_links().iter().map(ToString::to_string))
+        .collect();
+    required.sort();
+    for property in required {
+        resource.statements.push((
+            "rdfs:subClassOf",
+            restriction(property, "owl:minCardinality", Term::Count(1)),
+        ));
+    }
+
+    let mut links: Vec<_> = entity_type.links().iter().collect();
+    links.sort_by_key(|(link_type, _)| link_type.to_string());
+    for (link_type, link) in links {
+        resource.statements.push((
+            "rdfs:subClassOf",
+            restriction(
+                link_type.to_string(),
+                "owl:allValuesFrom",
+                Term::Iri(link.inner().uri().to_string()),
+            ),
+        ));
+        if let ValueOrMaybeOrderedArray::Array(array) = link {
+            if let Some(max_items) = array.array().max_items() {
+                resource.statements.push((
+                    "rdfs:subClassOf",
+                    restriction(
+                        link_type.to_string(),
+                        "owl:maxCardinality",
+                        Term::Count(max_items),
+                    ),
+                ));
+            }
+        } else {
+            resource.statements.push((
+                "rdfs:subClassOf",
+                restriction(link_type.to_string(), "owl:maxCardinality", Term::Count(1)),
+            ));
+        }
+    }
+    resource
+}
+
+fn link_type(link_type: &LinkType) -> Resource {
+    Resource::new(
+        link_type.id().to_string(),
+        "owl:ObjectProperty",
+        link_type.title(),
+        Some(link_type.description()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::test_data;
+
+    pub(super) fn bundle() -> TypeBundle {
+        let mut bundle = TypeBundle::new();
+        for data_type in [
+            test_data::data_type::TEXT_V1,
+            test_data::data_type::NUMBER_V1,
+        ] {
+            bundle.insert_data_type(DataType::from_str(data_type).expect("invalid data type"));
+        }
+        for property_type in [
+            test_data::property_type::NAME_V1,
+            test_data::property_type::NUMBERS_V1,
+            test_data::property_type::CONTACT_INFORMATION_V1,
+        ] {
+            bundle.insert_property_type(
+                PropertyType::from_str(property_type).expect("invalid property type"),
+            );
+        }
+        bundle.insert_entity_type(
+            EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid entity type"),
+        );
+        bundle.insert_link_type(
+            LinkType::from_str(test_data::link_type::OWNS_V2).expect("invalid link type"),
+        );
+        bundle
+    }
+
+    #[test]
+    fn classification() {
+        let ontology = Ontology::new(&bundle());
+        let class = |iri: &str| {
+            ontology
+                .resources
+                .iter()
+                .find(|resource| resource.iri == iri)
+                .map(|resource| resource.statements[0].1.clone())
+                .expect("missing resource")
+        };
+
+        assert_eq!(
+            class("https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1"),
+            Term::Name("rdfs:Datatype")
+        );
+        assert_eq!(
+            class("https://blockprotocol.org/@alice/types/property-type/name/"),
+            Term::Name("owl:DatatypeProperty")
+        );
+        assert_eq!(
+            class("https://blockprotocol.org/@alice/types/property-type/contact-information/"),
+            Term::Name("owl:ObjectProperty")
+        );
+        assert_eq!(
+            class("https://blockprotocol.org/@alice/types/entity-type/book/v/1"),
+            Term::Name("owl:Class")
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use std::fmt::{self, Write};
+
+use super::{Ontology, Term, PREFIXES};
+
+/// Writes `literal` as a quoted Turtle string.
+fn write_literal(output: &mut impl Write, literal: &str) -> fmt::Result {
+    output.write_char('"')?;
+    for character in literal.chars() {
+        match character {
+            '"' => output.write_str("\\\"")?,
+            '\\' => output.write_str("\\\\")?,
+            '\n' => output.write_str("\\n")?,
+            '\r' => output.write_str("\\r")?,
+            '\t' => output.write_str("\\t")?,
+            _ => output.write_char(character)?,
+        }
+    }
+    output.write_char('"')
+}
+
+fn write_term(output: &mut impl Write, term: &Term, indentation: usize) -> fmt::Result {
+    match term {
+        Term::Iri(iri) => write!(output, "<{iri}>"),
+        Term::Name(name) => output.write_str(name),
+        Term::Literal(literal) => write_literal(output, literal),
+        Term::Count(count) => write!(output, "\"{count}\"^^xsd:nonNegativeInteger"),
+        Term::Node(statements) => {
+            output.write_str("[\n")?;
+            write_statements(output, statements, indentation + 1)?;
+            write!(output, "\n{:width$}]", "", width = indentation * 4)
+        }
+    }
+}
+
+fn write_statements(
+    output: &mut impl Write,
+    statements: &[(&'static str, Term)],
+    indentation: usize,
+) -> fmt::Result {
+    for (index, (predicate, object)) in statements.iter().enumerate() {
+        if index > 0 {
+            output.write_str(" ;\n")?;
+        }
+        let predicate = if *predicate == "rdf:type" {
+            "a"
+        } else {
+            predicate
+        };
+        write!(output, "{:width$}{predicate} ", "", width = indentation * 4)?;
+        write_term(output, object, indentation)?;
+    }
+    Ok(())
+}
+
+impl Ontology {
+    /// Renders the ontology as [Turtle](https://www.w3.org/TR/turtle/).
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing to a [`String`] fails, which cannot happen.
+    #[must_use]
+    pub fn to_turtle(&self) -> String {
+        let mut output = String::new();
+        self.write_turtle(&mut output)
+            .expect("writing to a string does not fail");
+        output
+    }
+
+    fn write_turtle(&self, output: &mut impl Write) -> fmt::Result {
+        for (prefix, iri) in PREFIXES {
+            writeln!(output, "@prefix {prefix}: <{iri}> .")?;
+        }
+        for resource in &self.resources {
+            write!(output, "\n<{}>\n", resource.iri)?;
+            write_statements(output, &resource.statements, 1)?;
+            output.write_str(" .\n")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rdf::tests::bundle;
+
+    #[test]
+    fn literals() {
+        let mut output = String::new();
+        write_literal(&mut output, "a \"quoted\"\nline\\").expect("failed to write literal");
+        assert_eq!(output, r#""a \"quoted\"\nline\\""#);
+    }
+
+    #[test]
+    fn turtle() {
+        let turtle = Ontology::new(&bundle()).to_turtle();
+
+        assert!(turtle.starts_with("@prefix owl: <http://www.w3.org/2002/07/owl#> .\n"));
+        assert!(turtle.contains(
+            "\n<https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1>\n    a \
+             rdfs:Datatype ;\n    rdfs:label \"Text\" ;\n    rdfs:comment \"An ordered sequence \
+             of characters\" ;\n    owl:equivalentClass xsd:string .\n"
+        ));
+        assert!(turtle.contains(
+            "\n<https://blockprotocol.org/@alice/types/property-type/name/>\n    a \
+             owl:DatatypeProperty ;\n    rdfs:label \"Name\" ;\n    rdfs:range \
+             <https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1> .\n"
+        ));
+        assert!(turtle.contains(
+            "    rdfs:subClassOf [\n        a owl:Restriction ;\n        owl:onProperty \
+             <https://blockprotocol.org/@alice/types/link-type/written-by/v/1> ;\n        \
+             owl:allValuesFrom <https://blockprotocol.org/@alice/types/entity-type/person/v/1>\n    \
+             ] ;\n"
+        ));
+    }
+}
@@ -0,0 +1,53 @@
+//! Repositories containing test data for the tests of code resolving types.
+
+use std::str::FromStr;
+
+use crate::{
+    repository::FsTypeRepository, test_data, DataType, LinkType, OntologyType, PropertyType,
+};
+
+/// Writes `ontology_type` to `repository`.
+pub(crate) fn store<T>(repository: &FsTypeRepository, ontology_type: &T)
+where
+    T: OntologyType + Clone + Into<serde_json::Value>,
+{
+    repository
+        .store(ontology_type)
+        .expect("failed to store type");
+}
+
+/// Returns a repository in `directory`, which contains the text and number data types, the name,
+/// age, contrived, and tree node property types, and the owns and submitted by link types.
+pub(crate) fn repository(directory: &tempfile::TempDir) -> FsTypeRepository {
+    let repository = FsTypeRepository::new(directory.path());
+    for data_type in [
+        test_data::data_type::TEXT_V1,
+        test_data::data_type::NUMBER_V1,
+    ] {
+        store(
+            &repository,
+            &DataType::from_str(data_type).expect("invalid type"),
+        );
+    }
+    for property_type in [
+        test_data::property_type::NAME_V1,
+        test_data::property_type::AGE_V1,
+        test_data::property_type::CONTRIVED_PROPERTY_V1,
+        test_data::property_type::TREE_NODE_V1,
+    ] {
+        store(
+            &repository,
+            &PropertyType::from_str(property_type).expect("invalid type"),
+        );
+    }
+    for link_type in [
+        test_data::link_type::OWNS_V2,
+        test_data::link_type::SUBMITTED_BY_V1,
+    ] {
+        store(
+            &repository,
+            &LinkType::from_str(link_type).expect("invalid type"),
+        );
+    }
+    repository
+}
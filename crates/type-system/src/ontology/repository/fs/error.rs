@@ -0,0 +1,28 @@
+use std::{io, path::PathBuf};
+
+use thiserror::Error;
+
+use crate::{uri::VersionedUri, ParseOntologyTypeError, ValidationError};
+
+#[derive(Debug, Error)]
+pub enum FsTypeRepositoryError {
+    #[error("type `{0}` does not exist in the repository")]
+    NotFound(VersionedUri),
+    #[error("the URI cannot be mapped to a path: {0}")]
+    InvalidUri(ValidationError),
+    #[error("`{}` is not a valid type: {error}", .path.display())]
+    InvalidType {
+        path: PathBuf,
+        error: ParseOntologyTypeError,
+    },
+    #[error("`{}` contains type `{id}` which is expected at a different path", .path.display())]
+    MismatchedPath { path: PathBuf, id: VersionedUri },
+    #[error("`{}` is not part of the repository layout", .0.display())]
+    UnexpectedPath(PathBuf),
+    #[error("could not access `{}`: {error}", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        error: io::Error,
+    },
+}
@@ -0,0 +1,479 @@
+mod error;
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+pub use error::FsTypeRepositoryError;
+
+use crate::{
+    repository::{TypeRepository, TypeResolver},
+    uri::{BaseUri, TypeUriComponents, VersionedUri},
+    DataType, EntityType, LinkType, OntologyType, OntologyTypeKind, ParseOntologyTypeError,
+    PropertyType, TypeBundle, ValidationError,
+};
+
+/// A repository of types stored as JSON files in a directory.
+///
+/// Every type is stored at a path derived from its [`VersionedUri`]:
+///
+/// `<root>/<host>/<namespace>/<kind>/<slug>/v/<version>.json`
+///
+/// where the components are the ones of the [`TypeUriComponents`] of the URI, e.g. the type
+/// `https://blockprotocol.org/@alice/types/entity-type/book/v/1` is stored at
+/// `<root>/blockprotocol.org/alice/entity-type/book/v/1.json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FsTypeRepository {
+    root: PathBuf,
+}
+
+impl FsTypeRepository {
+    /// Creates a repository rooted at the directory `root`.
+    ///
+    /// The directory does not have to exist until the first type is stored.
+    #[must_use]
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the path at which the type identified by `uri` is stored.
+    ///
+    /// # Errors
+    ///
+    /// - [`FsTypeRepositoryError::InvalidUri`] if `uri` does not follow the Block Protocol
+    ///   convention for type URIs
+    pub fn path(&self, uri: &VersionedUri) -> Result<PathBuf, FsTypeRepositoryError> {
+        let components = components(uri.base_uri())?;
+        Ok(self
+            .base_path(&components)
+            .join(format!("{}.json", uri.version())))
+    }
+
+    /// Writes `ontology_type` to its path inside of the repository, replacing any existing file.
+    ///
+    /// Returns the path the type was written to.
+    ///
+    /// # Errors
+    ///
+    /// - [`FsTypeRepositoryError::InvalidUri`] if the URI of the type does not follow the Block
+    ///   Protocol convention for type URIs or refers to a different kind of type
+    /// - [`FsTypeRepositoryError::Io`] if the file could not be written
+    pub fn store<T>(&self, ontology_type: &T) -> Result<PathBuf, FsTypeRepositoryError>
+    where
+        T: OntologyType + Clone + Into<serde_json::Value>,
+    {
+        ontology_type
+            .validate_kind()
+            .map_err(FsTypeRepositoryError::InvalidUri)?;
+        let path = self.path(ontology_type.versioned_uri())?;
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory).map_err(|error| FsTypeRepositoryError::Io {
+                path: directory.to_path_buf(),
+                error,
+            })?;
+        }
+
+        let contents: serde_json::Value = ontology_type.clone().into();
+        fs::write(&path, format!("{contents:#}\n")).map_err(|error| FsTypeRepositoryError::Io {
+            path: path.clone(),
+            error,
+        })?;
+
+        Ok(path)
+    }
+
+    fn base_path(&self, components: &TypeUriComponents) -> PathBuf {
+        self.root
+            .join(components.host())
+            .join(components.namespace())
+            .join(components.kind().uri_segment())
+            .join(components.slug())
+            .join("v")
+    }
+
+    /// Reads the type at `path` and ensures that it's stored at the path derived from its `$id`.
+    fn read<T>(&self, path: &Path) -> Result<T, FsTypeRepositoryError>
+    where
+        T: OntologyType + FromStr,
+        ParseOntologyTypeError: From<T::Err>,
+    {
+        let contents = fs::read_to_string(path).map_err(|error| FsTypeRepositoryError::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        let ontology_type =
+            T::from_str(&contents).map_err(|error| FsTypeRepositoryError::InvalidType {
+                path: path.to_path_buf(),
+                error: error.into(),
+            })?;
+
+        if ontology_type.validate_kind().is_err()
+            || self.path(ontology_type.versioned_uri())? != path
+        {
+            return Err(FsTypeRepositoryError::MismatchedPath {
+                path: path.to_path_buf(),
+                id: ontology_type.versioned_uri().clone(),
+            });
+        }
+
+        Ok(ontology_type)
+    }
+
+    pub(super) fn resolve<T>(&self, uri: &VersionedUri) -> Result<T, FsTypeRepositoryError>
+    where
+        T: OntologyType + FromStr,
+        ParseOntologyTypeError: From<T::Err>,
+    {
+        let kind = components(uri.base_uri())?.kind();
+        if kind != T::KIND {
+            return Err(FsTypeRepositoryError::InvalidUri(
+                ValidationError::MismatchedKind {
+                    expected: T::KIND,
+                    actual: kind,
+                },
+            ));
+        }
+
+        let path = self.path(uri)?;
+        if !path.is_file() {
+            return Err(FsTypeRepositoryError::NotFound(uri.clone()));
+        }
+
+        self.read(&path)
+    }
+}
+
+impl TypeResolver for FsTypeRepository {
+    type Error = FsTypeRepositoryError;
+
+    fn resolve_data_type(&self, uri: &VersionedUri) -> Result<DataType, Self::Error> {
+        self.resolve(uri)
+    }
+
+    fn resolve_property_type(&self, uri: &VersionedUri) -> Result<PropertyType, Self::Error> {
+        self.resolve(uri)
+    }
+
+    fn resolve_entity_type(&self, uri: &VersionedUri) -> Result<EntityType, Self::Error> {
+        self.resolve(uri)
+    }
+
+    fn resolve_link_type(&self, uri: &VersionedUri) -> Result<LinkType, Self::Error> {
+        self.resolve(uri)
+    }
+}
+
+impl TypeRepository for FsTypeRepository {
+    /// Returns all versions of the type identified by `base_uri` in ascending order.
+    ///
+    /// Files inside of the version directory which are not named `<version>.json` are ignored.
+    ///
+    /// # Errors
+    ///
+    /// - [`FsTypeRepositoryError::InvalidUri`] if `base_uri` does not follow the Block Protocol
+    ///   convention for type URIs
+    /// - [`FsTypeRepositoryError::Io`] if the version directory could not be read
+    fn versions(&self, base_uri: &BaseUri) -> Result<Vec<u32>, Self::Error> {
+        let directory = self.base_path(&components(base_uri)?);
+
+        let entries = match fs::read_dir(&directory) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => {
+                return Err(FsTypeRepositoryError::Io {
+                    path: directory,
+                    error,
+                });
+            }
+        };
+
+        let mut versions = Vec::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|error| FsTypeRepositoryError::Io {
+                    path: directory.clone(),
+                    error,
+                })?
+                .path();
+            if let Some(version) = parse_version(&path) {
+                versions.push(version);
+            }
+        }
+        versions.sort_unstable();
+
+        Ok(versions)
+    }
+
+    /// Loads and validates every type inside of the repository.
+    ///
+    /// # Errors
+    ///
+    /// - [`FsTypeRepositoryError::UnexpectedPath`] if the repository contains a file which does not
+    ///   follow the layout of the repository
+    /// - [`FsTypeRepositoryError::InvalidType`] if a file is not a valid type of the kind implied
+    ///   by its path
+    /// - [`FsTypeRepositoryError::MismatchedPath`] if the `$id` of a type does not match its path
+    /// - [`FsTypeRepositoryError::Io`] if the repository could not be read
+    fn load_bundle(&self) -> Result<TypeBundle, Self::Error> {
+        let mut bundle = TypeBundle::new();
+
+        // <host>/<namespace>/<kind>/<slug>/v/<version>.json
+        for path in files(&self.root, 6)? {
+            let kind = path
+                .strip_prefix(&self.root)
+                .ok()
+                .and_then(|relative| relative.iter().nth(2))
+                .and_then(|segment| OntologyTypeKind::from_uri_segment(segment.to_str()?))
+                .ok_or_else(|| FsTypeRepositoryError::UnexpectedPath(path.clone()))?;
+            if parse_version(&path).is_none() {
+                return Err(FsTypeRepositoryError::UnexpectedPath(path));
+            }
+
+            match kind {
+                OntologyTypeKind::DataType => {
+                    bundle.insert_data_type(self.read(&path)?);
+                }
+                OntologyTypeKind::PropertyType => {
+                    bundle.insert_property_type(self.read(&path)?);
+                }
+                OntologyTypeKind::EntityType => {
+                    bundle.insert_entity_type(self.read(&path)?);
+                }
+                OntologyTypeKind::LinkType => {
+                    bundle.insert_link_type(self.read(&path)?);
+                }
+            }
+        }
+
+        Ok(bundle)
+    }
+
+    fn store_data_type(&self, data_type: &DataType) -> Result<(), Self::Error> {
+        self.store(data_type).map(drop)
+    }
+
+    fn store_property_type(&self, property_type: &PropertyType) -> Result<(), Self::Error> {
+        self.store(property_type).map(drop)
+    }
+
+    fn store_entity_type(&self, entity_type: &EntityType) -> Result<(), Self::Error> {
+        self.store(entity_type).map(drop)
+    }
+
+    fn store_link_type(&self, link_type: &LinkType) -> Result<(), Self::Error> {
+        self.store(link_type).map(drop)
+    }
+}
+
+fn components(base_uri: &BaseUri) -> Result<TypeUriComponents, FsTypeRepositoryError> {
+    base_uri.components().map_err(|error| {
+        FsTypeRepositoryError::InvalidUri(ValidationError::UnconventionalUri(error))
+    })
+}
+
+/// Returns the version of a file named `<version>.json`.
+fn parse_version(path: &Path) -> Option<u32> {
+    if path.extension()? != "json" {
+        return None;
+    }
+    path.file_stem()?.to_str()?.parse().ok()
+}
+
+/// Returns all files inside of `directory`, which are expected to be exactly `depth` levels deep.
+///
+/// Hidden files and directories are skipped.
+fn files(directory: &Path, depth: usize) -> Result<Vec<PathBuf>, FsTypeRepositoryError> {
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => {
+            return Err(FsTypeRepositoryError::Io {
+                path: directory.to_path_buf(),
+                error,
+            });
+        }
+    };
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|error| FsTypeRepositoryError::Io {
+                path: directory.to_path_buf(),
+                error,
+            })?
+            .path();
+
+        // Hidden files, e.g. `.git`, are not part of the repository
+        if path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map_or(false, |name| name.starts_with('.'))
+        {
+            continue;
+        }
+
+        match (path.is_dir(), depth) {
+            (true, 1) | (false, 2..) => return Err(FsTypeRepositoryError::UnexpectedPath(path)),
+            (true, _) => files.extend(self::files(&path, depth - 1)?),
+            (false, _) => files.push(path),
+        }
+    }
+    files.sort();
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::test_data;
+
+    fn book() -> EntityType {
+        EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid entity type")
+    }
+
+    #[test]
+    fn layout() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = FsTypeRepository::new(directory.path());
+
+        let path = repository.store(&book()).expect("failed to store type");
+        assert_eq!(
+            path,
+            directory
+                .path()
+                .join("blockprotocol.org/alice/entity-type/book/v/1.json")
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = FsTypeRepository::new(directory.path());
+
+        let text = DataType::from_str(test_data::data_type::TEXT_V1).expect("invalid data type");
+        let owns = LinkType::from_str(test_data::link_type::OWNS_V2).expect("invalid link type");
+        repository.store(&book()).expect("failed to store type");
+        repository.store(&text).expect("failed to store type");
+        repository.store(&owns).expect("failed to store type");
+
+        assert_eq!(
+            repository
+                .resolve_entity_type(book().id())
+                .expect("failed to resolve type"),
+            book()
+        );
+        assert_eq!(
+            repository
+                .resolve_link_type(owns.id())
+                .expect("failed to resolve type"),
+            owns
+        );
+
+        let bundle = repository.load_bundle().expect("failed to load repository");
+        assert_eq!(bundle.len(), 3);
+        assert_eq!(bundle.data_type(text.id()), Some(&text));
+    }
+
+    #[test]
+    fn not_found() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = FsTypeRepository::new(directory.path());
+
+        assert!(matches!(
+            repository.resolve_entity_type(book().id()),
+            Err(FsTypeRepositoryError::NotFound(uri)) if &uri == book().id()
+        ));
+    }
+
+    #[test]
+    fn mismatched_kind() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = FsTypeRepository::new(directory.path());
+
+        assert!(matches!(
+            repository.resolve_property_type(book().id()),
+            Err(FsTypeRepositoryError::InvalidUri(
+                ValidationError::MismatchedKind { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn mismatched_path() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = FsTypeRepository::new(directory.path());
+
+        let path = repository.store(&book()).expect("failed to store type");
+        let moved = path.with_file_name("2.json");
+        fs::rename(&path, &moved).expect("failed to move file");
+
+        assert!(matches!(
+            repository.load_bundle(),
+            Err(FsTypeRepositoryError::MismatchedPath { path, .. }) if path == moved
+        ));
+    }
+
+    #[test]
+    fn invalid_file() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = FsTypeRepository::new(directory.path());
+
+        let path = repository.store(&book()).expect("failed to store type");
+        fs::write(
+            &path,
+            json!({ "kind": "dataType", "$id": book().id() }).to_string(),
+        )
+        .expect("failed to write file");
+
+        assert!(matches!(
+            repository.resolve_entity_type(book().id()),
+            Err(FsTypeRepositoryError::InvalidType {
+                error: ParseOntologyTypeError::EntityType(_),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn versions() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = FsTypeRepository::new(directory.path());
+
+        let book = book();
+        assert_eq!(repository.versions(book.id().base_uri()).ok(), Some(vec![]));
+
+        let mut value: serde_json::Value = book.clone().into();
+        for version in [3, 1, 10] {
+            value["$id"] = json!(format!("{}v/{version}", book.id().base_uri()));
+            repository
+                .store(&EntityType::try_from(value.clone()).expect("invalid entity type"))
+                .expect("failed to store type");
+        }
+        fs::write(
+            repository
+                .path(book.id())
+                .expect("invalid URI")
+                .with_file_name("README.md"),
+            "",
+        )
+        .expect("failed to write file");
+
+        assert_eq!(
+            repository.versions(book.id().base_uri()).ok(),
+            Some(vec![1, 3, 10])
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use std::io;
+
+use thiserror::Error;
+
+use crate::{repository::FsTypeRepositoryError, uri::VersionedUri, ParseOntologyTypeError};
+
+#[derive(Debug, Error)]
+pub enum HttpTypeResolverError {
+    #[error("type `{0}` does not exist")]
+    NotFound(VersionedUri),
+    #[error("type `{0}` is not cached and the resolver is in offline mode")]
+    Offline(VersionedUri),
+    #[error("could not request type `{uri}`: {error}")]
+    Request {
+        uri: VersionedUri,
+        #[source]
+        error: Box<ureq::Error>,
+    },
+    #[error("could not read the response for type `{uri}`: {error}")]
+    Io {
+        uri: VersionedUri,
+        #[source]
+        error: io::Error,
+    },
+    #[error("the response for type `{uri}` is not a valid type: {error}")]
+    InvalidResponse {
+        uri: VersionedUri,
+        error: ParseOntologyTypeError,
+    },
+    #[error("requested type `{expected}` but received type `{actual}`")]
+    MismatchedId {
+        expected: VersionedUri,
+        actual: VersionedUri,
+    },
+    #[error("could not access the cache: {0}")]
+    Cache(FsTypeRepositoryError),
+}
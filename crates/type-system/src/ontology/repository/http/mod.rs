@@ -0,0 +1,350 @@
+mod error;
+
+use std::{path::PathBuf, str::FromStr};
+
+pub use error::HttpTypeResolverError;
+
+use crate::{
+    repository::{FsTypeRepository, FsTypeRepositoryError, TypeResolver},
+    uri::VersionedUri,
+    DataType, EntityType, LinkType, OntologyType, ParseOntologyTypeError, PropertyType,
+};
+
+/// Resolves types by requesting their [`VersionedUri`] over HTTP.
+///
+/// Responses can be cached on disk by using [`with_cache`]. As a [`VersionedUri`] always refers to
+/// the same type, cached types are never requested again. The cache uses the layout of
+/// [`FsTypeRepository`], so types with a URI which does not follow the Block Protocol convention
+/// for type URIs are not cached.
+///
+/// [`with_cache`]: Self::with_cache
+#[derive(Debug, Clone)]
+pub struct HttpTypeResolver {
+    agent: ureq::Agent,
+    cache: Option<FsTypeRepository>,
+    offline: bool,
+}
+
+impl Default for HttpTypeResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HttpTypeResolver {
+    /// Creates a resolver which does not cache any responses.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            agent: ureq::Agent::new(),
+            cache: None,
+            offline: false,
+        }
+    }
+
+    /// Caches responses inside of `directory`.
+    #[must_use]
+    pub fn with_cache(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.cache = Some(FsTypeRepository::new(directory));
+        self
+    }
+
+    /// If `offline` is `true`, types are only served from the cache and no requests are made.
+    #[must_use]
+    pub const fn with_offline_mode(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    #[must_use]
+    pub const fn cache(&self) -> Option<&FsTypeRepository> {
+        self.cache.as_ref()
+    }
+
+    #[must_use]
+    pub const fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    fn resolve<T>(&self, uri: &VersionedUri) -> Result<T, HttpTypeResolverError>
+    where
+        T: OntologyType + FromStr + Clone + Into<serde_json::Value>,
+        ParseOntologyTypeError: From<T::Err>,
+    {
+        if let Some(cache) = &self.cache {
+            match cache.resolve::<T>(uri) {
+                // The cache does not distinguish URIs which only differ in their scheme, so the
+                // cached type may be a different type.
+                Ok(ontology_type) if ontology_type.versioned_uri() == uri => {
+                    return Ok(ontology_type);
+                }
+                Ok(_)
+                | Err(FsTypeRepositoryError::NotFound(_) | FsTypeRepositoryError::InvalidUri(_)) => {
+                }
+                Err(error) => return Err(HttpTypeResolverError::Cache(error)),
+            }
+        }
+
+        if self.offline {
+            return Err(HttpTypeResolverError::Offline(uri.clone()));
+        }
+
+        let response = self
+            .agent
+            .request_url("GET", &uri.to_url())
+            .set("Accept", "application/json")
+            .call()
+            .map_err(|error| match error {
+                ureq::Error::Status(404, _) => HttpTypeResolverError::NotFound(uri.clone()),
+                error => HttpTypeResolverError::Request {
+                    uri: uri.clone(),
+                    error: Box::new(error),
+                },
+            })?;
+        let body = response
+            .into_string()
+            .map_err(|error| HttpTypeResolverError::Io {
+                uri: uri.clone(),
+                error,
+            })?;
+
+        let ontology_type =
+            T::from_str(&body).map_err(|error| HttpTypeResolverError::InvalidResponse {
+                uri: uri.clone(),
+                error: error.into(),
+            })?;
+        if ontology_type.versioned_uri() != uri {
+            return Err(HttpTypeResolverError::MismatchedId {
+                expected: uri.clone(),
+                actual: ontology_type.versioned_uri().clone(),
+            });
+        }
+
+        if let Some(cache) = &self.cache {
+            match cache.store(&ontology_type) {
+                Ok(_) | Err(FsTypeRepositoryError::InvalidUri(_)) => {}
+                Err(error) => return Err(HttpTypeResolverError::Cache(error)),
+            }
+        }
+
+        Ok(ontology_type)
+    }
+}
+
+impl TypeResolver for HttpTypeResolver {
+    type Error = HttpTypeResolverError;
+
+    fn resolve_data_type(&self, uri: &VersionedUri) -> Result<DataType, Self::Error> {
+        self.resolve(uri)
+    }
+
+    fn resolve_property_type(&self, uri: &VersionedUri) -> Result<PropertyType, Self::Error> {
+        self.resolve(uri)
+    }
+
+    fn resolve_entity_type(&self, uri: &VersionedUri) -> Result<EntityType, Self::Error> {
+        self.resolve(uri)
+    }
+
+    fn resolve_link_type(&self, uri: &VersionedUri) -> Result<LinkType, Self::Error> {
+        self.resolve(uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    use super::*;
+    use crate::{rewrite, test_data, uri::BaseUri};
+
+    /// A local server which responds with the given body for the path of each type.
+    struct Server {
+        requests: Arc<AtomicUsize>,
+    }
+
+    impl Server {
+        fn start(responses: impl FnOnce(&str) -> HashMap<String, String>) -> Self {
+            let server = tiny_http::Server::http("127.0.0.1:0").expect("failed to start server");
+            let host = format!(
+                "127.0.0.1:{}",
+                server
+                    .server_addr()
+                    .to_ip()
+                    .expect("not an IP address")
+                    .port()
+            );
+            let responses = responses(&host);
+            let requests = Arc::new(AtomicUsize::new(0));
+
+            let counter = Arc::clone(&requests);
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    let response = responses.get(request.url()).map_or_else(
+                        || tiny_http::Response::from_string("").with_status_code(404),
+                        |body| tiny_http::Response::from_string(body.clone()),
+                    );
+                    request.respond(response).expect("failed to respond");
+                }
+            });
+
+            Self { requests }
+        }
+
+        fn requests(&self) -> usize {
+            self.requests.load(Ordering::SeqCst)
+        }
+    }
+
+    /// Moves the type `input` onto `host` and returns it alongside its JSON representation.
+    fn served_at<T>(input: &str, host: &str) -> (T, String)
+    where
+        T: FromStr + Clone + Into<serde_json::Value> + TryFrom<serde_json::Value>,
+        <T as FromStr>::Err: std::fmt::Debug,
+        rewrite::RewriteUrisError: From<<T as TryFrom<serde_json::Value>>::Error>,
+    {
+        let original = T::from_str(input).expect("invalid type");
+        let mut prefixes = rewrite::PrefixMap::new();
+        prefixes.insert(
+            BaseUri::new("https://blockprotocol.org/".to_owned()).expect("invalid base URI"),
+            BaseUri::new(format!("http://{host}/")).expect("invalid base URI"),
+        );
+        let moved = rewrite::rewrite_uris(&original, |uri| prefixes.map(uri))
+            .expect("failed to rewrite type");
+        let json: serde_json::Value = moved.clone().into();
+        (moved, json.to_string())
+    }
+
+    /// Returns the path a type is requested at, without relying on [`VersionedUri::to_url`].
+    fn path(uri: &VersionedUri) -> String {
+        url::Url::parse(&uri.to_string())
+            .expect("invalid URL")
+            .path()
+            .to_owned()
+    }
+
+    #[test]
+    fn fetch_and_cache() {
+        let mut book = None;
+        let server = Server::start(|host| {
+            let (entity_type, json) =
+                served_at::<EntityType>(test_data::entity_type::BOOK_V1, host);
+            let path = path(entity_type.id());
+            book = Some(entity_type);
+            HashMap::from([(path, json)])
+        });
+        let book = book.expect("book was not created");
+        let cache = tempfile::tempdir().expect("failed to create directory");
+
+        let resolver = HttpTypeResolver::new().with_cache(cache.path());
+        assert_eq!(
+            resolver
+                .resolve_entity_type(book.id())
+                .expect("failed to resolve type"),
+            book
+        );
+        assert_eq!(
+            resolver
+                .resolve_entity_type(book.id())
+                .expect("failed to resolve type"),
+            book
+        );
+        assert_eq!(server.requests(), 1);
+
+        let offline = HttpTypeResolver::new()
+            .with_cache(cache.path())
+            .with_offline_mode(true);
+        assert_eq!(
+            offline
+                .resolve_entity_type(book.id())
+                .expect("failed to resolve type"),
+            book
+        );
+        assert_eq!(server.requests(), 1);
+    }
+
+    #[test]
+    fn cache_with_different_scheme() {
+        let mut book = None;
+        let server = Server::start(|host| {
+            let (entity_type, json) =
+                served_at::<EntityType>(test_data::entity_type::BOOK_V1, host);
+            let path = path(entity_type.id());
+            book = Some(entity_type);
+            HashMap::from([(path, json)])
+        });
+        let book = book.expect("book was not created");
+        let cache = tempfile::tempdir().expect("failed to create directory");
+
+        let resolver = HttpTypeResolver::new().with_cache(cache.path());
+        resolver
+            .resolve_entity_type(book.id())
+            .expect("failed to resolve type");
+        assert_eq!(server.requests(), 1);
+
+        let https = VersionedUri::from_str(&book.id().to_string().replacen("http", "https", 1))
+            .expect("invalid versioned URI");
+        let offline = HttpTypeResolver::new()
+            .with_cache(cache.path())
+            .with_offline_mode(true);
+        assert!(matches!(
+            offline.resolve_entity_type(&https),
+            Err(HttpTypeResolverError::Offline(uri)) if uri == https
+        ));
+    }
+
+    #[test]
+    fn offline_without_cache() {
+        let book = EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid type");
+        let resolver = HttpTypeResolver::new().with_offline_mode(true);
+
+        assert!(matches!(
+            resolver.resolve_entity_type(book.id()),
+            Err(HttpTypeResolverError::Offline(uri)) if &uri == book.id()
+        ));
+    }
+
+    #[test]
+    fn invalid_responses() {
+        let mut uris = Vec::new();
+        let server = Server::start(|host| {
+            let (text, text_json) = served_at::<DataType>(test_data::data_type::TEXT_V1, host);
+            let (book, book_json) = served_at::<EntityType>(test_data::entity_type::BOOK_V1, host);
+            let next_version = VersionedUri::new(book.id().base_uri().clone(), 2);
+
+            let responses = HashMap::from([
+                (path(text.id()), text_json),
+                (path(&next_version), book_json),
+            ]);
+            uris = vec![text.id().clone(), next_version, book.id().clone()];
+            responses
+        });
+        let resolver = HttpTypeResolver::new();
+
+        assert!(matches!(
+            resolver.resolve_entity_type(&uris[0]),
+            Err(HttpTypeResolverError::InvalidResponse {
+                error: ParseOntologyTypeError::EntityType(_),
+                ..
+            })
+        ));
+        assert!(matches!(
+            resolver.resolve_entity_type(&uris[1]),
+            Err(HttpTypeResolverError::MismatchedId { expected, actual })
+                if expected == uris[1] && actual == uris[2]
+        ));
+        assert!(matches!(
+            resolver.resolve_entity_type(&uris[2]),
+            Err(HttpTypeResolverError::NotFound(uri)) if uri == uris[2]
+        ));
+        assert_eq!(server.requests(), 3);
+    }
+}
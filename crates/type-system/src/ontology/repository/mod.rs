@@ -0,0 +1,195 @@
+//! Storing and retrieving types of the Type System.
+//!
+//! A [`TypeResolver`] looks up a type by its [`VersionedUri`]. Implementations are provided for
+//! types stored on disk ([`FsTypeRepository`]) and, with the `http` feature enabled, for types
+//! served over HTTP (`HttpTypeResolver`). With the `server` feature enabled, a [`TypeRepository`]
+//! can be served over HTTP by using a `TypeServer`.
+
+#[cfg(test)]
+pub(crate) mod fixtures;
+mod fs;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "server")]
+mod server;
+
+pub use fs::{FsTypeRepository, FsTypeRepositoryError};
+#[cfg(feature = "http")]
+pub use http::{HttpTypeResolver, HttpTypeResolverError};
+#[cfg(feature = "server")]
+pub use server::{TypeServer, TypeServerResponse};
+
+use crate::{
+    uri::{BaseUri, VersionedUri},
+    DataType, EntityType, LinkType, OntologyType, OntologyTypeKind, PropertyType, TypeBundle,
+};
+
+/// Looks up types by their [`VersionedUri`].
+pub trait TypeResolver {
+    type Error;
+
+    /// Returns the [`DataType`] identified by `uri`.
+    ///
+    /// # Errors
+    ///
+    /// - if the type could not be found or is not a valid [`DataType`]
+    fn resolve_data_type(&self, uri: &VersionedUri) -> Result<DataType, Self::Error>;
+
+    /// Returns the [`PropertyType`] identified by `uri`.
+    ///
+    /// # Errors
+    ///
+    /// - if the type could not be found or is not a valid [`PropertyType`]
+    fn resolve_property_type(&self, uri: &VersionedUri) -> Result<PropertyType, Self::Error>;
+
+    /// Returns the [`EntityType`] identified by `uri`.
+    ///
+    /// # Errors
+    ///
+    /// - if the type could not be found or is not a valid [`EntityType`]
+    fn resolve_entity_type(&self, uri: &VersionedUri) -> Result<EntityType, Self::Error>;
+
+    /// Returns the [`LinkType`] identified by `uri`.
+    ///
+    /// # Errors
+    ///
+    /// - if the type could not be found or is not a valid [`LinkType`]
+    fn resolve_link_type(&self, uri: &VersionedUri) -> Result<LinkType, Self::Error>;
+
+    /// Returns a [`TypeBundle`] containing the type of the given `kind` identified by `uri` and
+    /// all types it transitively references.
+    ///
+    /// # Errors
+    ///
+    /// - if the type or any of its dependencies could not be resolved
+    fn resolve_with_dependencies(
+        &self,
+        kind: OntologyTypeKind,
+        uri: &VersionedUri,
+    ) -> Result<TypeBundle, Self::Error> {
+        fn owned(
+            references: Vec<(OntologyTypeKind, &VersionedUri)>,
+        ) -> Vec<(OntologyTypeKind, VersionedUri)> {
+            references
+                .into_iter()
+                .map(|(kind, uri)| (kind, uri.clone()))
+                .collect()
+        }
+
+        let mut bundle = TypeBundle::new();
+        let mut queue = vec![(kind, uri.clone())];
+
+        while let Some((kind, uri)) = queue.pop() {
+            if bundle.contains(&uri) {
+                continue;
+            }
+
+            let references = match kind {
+                OntologyTypeKind::DataType => {
+                    let data_type = self.resolve_data_type(&uri)?;
+                    let references = owned(data_type.referenced_types());
+                    bundle.insert_data_type(data_type);
+                    references
+                }
+                OntologyTypeKind::PropertyType => {
+                    let property_type = self.resolve_property_type(&uri)?;
+                    let references = owned(property_type.referenced_types());
+                    bundle.insert_property_type(property_type);
+                    references
+                }
+                OntologyTypeKind::EntityType => {
+                    let entity_type = self.resolve_entity_type(&uri)?;
+                    let references = owned(entity_type.referenced_types());
+                    bundle.insert_entity_type(entity_type);
+                    references
+                }
+                OntologyTypeKind::LinkType => {
+                    let link_type = self.resolve_link_type(&uri)?;
+                    let references = owned(link_type.referenced_types());
+                    bundle.insert_link_type(link_type);
+                    references
+                }
+            };
+            queue.extend(references);
+        }
+
+        Ok(bundle)
+    }
+}
+
+/// A [`TypeResolver`] which is able to enumerate and store types.
+pub trait TypeRepository: TypeResolver {
+    /// Returns all versions of the type identified by `base_uri` in ascending order.
+    ///
+    /// # Errors
+    ///
+    /// - if the versions could not be determined
+    fn versions(&self, base_uri: &BaseUri) -> Result<Vec<u32>, Self::Error>;
+
+    /// Returns all types inside of the repository.
+    ///
+    /// # Errors
+    ///
+    /// - if the repository could not be read or contains invalid types
+    fn load_bundle(&self) -> Result<TypeBundle, Self::Error>;
+
+    /// Stores `data_type` inside of the repository.
+    ///
+    /// # Errors
+    ///
+    /// - if the type could not be stored
+    fn store_data_type(&self, data_type: &DataType) -> Result<(), Self::Error>;
+
+    /// Stores `property_type` inside of the repository.
+    ///
+    /// # Errors
+    ///
+    /// - if the type could not be stored
+    fn store_property_type(&self, property_type: &PropertyType) -> Result<(), Self::Error>;
+
+    /// Stores `entity_type` inside of the repository.
+    ///
+    /// # Errors
+    ///
+    /// - if the type could not be stored
+    fn store_entity_type(&self, entity_type: &EntityType) -> Result<(), Self::Error>;
+
+    /// Stores `link_type` inside of the repository.
+    ///
+    /// # Errors
+    ///
+    /// - if the type could not be stored
+    fn store_link_type(&self, link_type: &LinkType) -> Result<(), Self::Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::test_data;
+
+    #[test]
+    fn resolve_with_dependencies() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = FsTypeRepository::new(directory.path());
+
+        let text = DataType::from_str(test_data::data_type::TEXT_V1).expect("invalid data type");
+        let name = PropertyType::from_str(test_data::property_type::NAME_V1).expect("invalid type");
+        repository.store(&text).expect("failed to store type");
+        repository.store(&name).expect("failed to store type");
+
+        let bundle = repository
+            .resolve_with_dependencies(OntologyTypeKind::PropertyType, name.id())
+            .expect("failed to resolve dependencies");
+        assert_eq!(bundle.len(), 2);
+        assert_eq!(bundle.data_type(text.id()), Some(&text));
+
+        let book = EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid type");
+        repository.store(&book).expect("failed to store type");
+        assert!(matches!(
+            repository.resolve_with_dependencies(OntologyTypeKind::EntityType, book.id()),
+            Err(FsTypeRepositoryError::NotFound(_))
+        ));
+    }
+}
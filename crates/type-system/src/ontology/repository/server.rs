@@ -0,0 +1,633 @@
+use std::{fmt, io, str::FromStr};
+
+use serde_json::{json, Value};
+use url::Url;
+
+use crate::{
+    canonical::content_hash,
+    repository::TypeRepository,
+    uri::{BaseUri, VersionedUri},
+    DataType, EntityType, LinkType, OntologyType, OntologyTypeKind, PropertyType,
+};
+
+/// The response to a request made to a [`TypeServer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeServerResponse {
+    status: u16,
+    body: Value,
+}
+
+impl TypeServerResponse {
+    const fn new(status: u16, body: Value) -> Self {
+        Self { status, body }
+    }
+
+    fn error(status: u16, message: impl fmt::Display) -> Self {
+        Self::new(status, json!({ "error": message.to_string() }))
+    }
+
+    #[must_use]
+    pub const fn status(&self) -> u16 {
+        self.status
+    }
+
+    #[must_use]
+    pub const fn body(&self) -> &Value {
+        &self.body
+    }
+}
+
+/// A type repository server following the "Discoverability" section of the Type System RFC.
+///
+/// Types are served at their canonical path relative to the `origin` of the server, e.g. the type
+/// `<origin>/@alice/types/entity-type/book/v/1` is served at `/@alice/types/entity-type/book/v/1`.
+/// The following endpoints are available:
+///
+/// - `GET /@<namespace>/types/<kind>/<slug>/v/<version>` returns the type
+/// - `GET /@<namespace>/types/<kind>/<slug>/v/latest` returns the latest version of the type
+/// - `GET /@<namespace>/types/<kind>/<slug>/` returns `{ "versions": [...] }`
+/// - `GET /types?kind=<kind>&query=<query>` lists all types, optionally filtered by their `kind`
+///   and by a case-insensitive `query` matched against their title and `$id`
+/// - `POST /types` publishes the type in the request body
+///
+/// A type can only be published once: publishing a type with an existing `$id` but a different
+/// content is rejected. All types referenced by a published type have to be published beforehand
+/// and have to be of the kind they are referenced as.
+#[derive(Debug, Clone)]
+pub struct TypeServer<R> {
+    repository: R,
+    origin: BaseUri,
+}
+
+impl<R> TypeServer<R>
+where
+    R: TypeRepository,
+    R::Error: fmt::Display,
+{
+    /// Creates a server for the types inside of `repository` which are hosted at `origin`.
+    #[must_use]
+    pub const fn new(repository: R, origin: BaseUri) -> Self {
+        Self { repository, origin }
+    }
+
+    #[must_use]
+    pub const fn repository(&self) -> &R {
+        &self.repository
+    }
+
+    #[must_use]
+    pub const fn origin(&self) -> &BaseUri {
+        &self.origin
+    }
+
+    /// Handles a single request to `url`, which is relative to the origin of the server, e.g.
+    /// `/types?kind=entityType`.
+    #[must_use]
+    pub fn handle(&self, method: &str, url: &str, body: &str) -> TypeServerResponse {
+        let origin = self.origin.to_url();
+        let url = match origin.join(url.trim_start_matches('/')) {
+            Ok(url) => url,
+            Err(error) => return TypeServerResponse::error(400, error),
+        };
+        // An absolute URL inside of the request would otherwise refer to types of another host
+        if url.origin() != origin.origin() {
+            return TypeServerResponse::error(400, format!("`{url}` is not hosted at `{origin}`"));
+        }
+
+        match (method, url.path().strip_prefix(origin.path())) {
+            ("GET", Some("types")) => self.list(&url),
+            ("POST", Some("types")) => self.publish(body),
+            ("GET", _) => self.get(&url),
+            _ => TypeServerResponse::error(405, format!("`{method}` is not supported")),
+        }
+    }
+
+    /// Serves requests received by `server` until it's shut down.
+    ///
+    /// A request with a body which is not valid UTF-8 is answered with `400`. Failing to respond to
+    /// a request, e.g. because the client disconnected, is logged to stderr and does not stop the
+    /// server.
+    ///
+    /// # Errors
+    ///
+    /// - [`io::Error`] if the `Content-Type` header of the responses could not be created
+    #[expect(
+        clippy::print_stderr,
+        reason = "there is no caller to return the error of a single response to"
+    )]
+    pub fn serve(&self, server: &tiny_http::Server) -> io::Result<()> {
+        let content_type = tiny_http::Header::from_bytes("Content-Type", "application/json")
+            .map_err(|()| {
+                io::Error::new(io::ErrorKind::InvalidInput, "invalid `Content-Type` header")
+            })?;
+
+        for mut request in server.incoming_requests() {
+            let mut body = String::new();
+            let response = match request.as_reader().read_to_string(&mut body) {
+                Ok(_) => self.handle(request.method().as_str(), request.url(), &body),
+                Err(error) => TypeServerResponse::error(400, error),
+            };
+
+            let url = request.url().to_owned();
+            if let Err(error) = request.respond(
+                tiny_http::Response::from_string(response.body.to_string())
+                    .with_status_code(response.status)
+                    .with_header(content_type.clone()),
+            ) {
+                eprintln!("could not respond to `{url}`: {error}");
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get(&self, url: &Url) -> TypeServerResponse {
+        let mut url = url.clone();
+        url.set_query(None);
+        let uri = url.as_str();
+
+        if let Some(base_uri) = uri.strip_suffix("v/latest") {
+            let base_uri = match BaseUri::new(base_uri.to_owned()) {
+                Ok(base_uri) => base_uri,
+                Err(error) => return TypeServerResponse::error(400, error),
+            };
+            return match self.repository.versions(&base_uri) {
+                Ok(versions) => match versions.last() {
+                    Some(version) => self.get_versioned(&VersionedUri::new(base_uri, *version)),
+                    None => {
+                        TypeServerResponse::error(404, format!("type `{base_uri}` does not exist"))
+                    }
+                },
+                Err(error) => TypeServerResponse::error(500, error),
+            };
+        }
+
+        if uri.ends_with('/') {
+            let base_uri = match BaseUri::new(uri.to_owned()) {
+                Ok(base_uri) => base_uri,
+                Err(error) => return TypeServerResponse::error(400, error),
+            };
+            return match self.repository.versions(&base_uri) {
+                Ok(versions) if versions.is_empty() => {
+                    TypeServerResponse::error(404, format!("type `{base_uri}` does not exist"))
+                }
+                Ok(versions) => TypeServerResponse::new(200, json!({ "versions": versions })),
+                Err(error) => TypeServerResponse::error(500, error),
+            };
+        }
+
+        match VersionedUri::from_str(uri) {
+            Ok(uri) => self.get_versioned(&uri),
+            Err(error) => TypeServerResponse::error(404, error),
+        }
+    }
+
+    fn get_versioned(&self, uri: &VersionedUri) -> TypeServerResponse {
+        let kind = match uri.base_uri().components() {
+            Ok(components) => components.kind(),
+            Err(error) => return TypeServerResponse::error(404, error),
+        };
+
+        match self.repository.versions(uri.base_uri()) {
+            Ok(versions) if versions.contains(&uri.version()) => {}
+            Ok(_) => return TypeServerResponse::error(404, format!("type `{uri}` does not exist")),
+            Err(error) => return TypeServerResponse::error(500, error),
+        }
+
+        match self.resolve(kind, uri) {
+            Ok(ontology_type) => TypeServerResponse::new(200, ontology_type),
+            Err(error) => TypeServerResponse::error(500, error),
+        }
+    }
+
+    fn list(&self, url: &Url) -> TypeServerResponse {
+        let mut kind: Option<OntologyTypeKind> = None;
+        let mut query = None;
+        for (key, value) in url.query_pairs() {
+            match key.as_ref() {
+                "kind" => match serde_json::from_value(Value::String(value.into_owned())) {
+                    Ok(value) => kind = Some(value),
+                    Err(error) => return TypeServerResponse::error(400, error),
+                },
+                "query" => query = Some(value.to_lowercase()),
+                _ => {
+                    return TypeServerResponse::error(
+                        400,
+                        format!("unknown query parameter `{key}`"),
+                    );
+                }
+            }
+        }
+
+        let bundle = match self.repository.load_bundle() {
+            Ok(bundle) => bundle,
+            Err(error) => return TypeServerResponse::error(500, error),
+        };
+
+        let entries = bundle
+            .data_types()
+            .map(|data_type| {
+                (
+                    OntologyTypeKind::DataType,
+                    data_type.id(),
+                    data_type.title(),
+                )
+            })
+            .chain(bundle.property_types().map(|property_type| {
+                (
+                    OntologyTypeKind::PropertyType,
+                    property_type.id(),
+                    property_type.title(),
+                )
+            }))
+            .chain(bundle.entity_types().map(|entity_type| {
+                (
+                    OntologyTypeKind::EntityType,
+                    entity_type.id(),
+                    entity_type.title(),
+                )
+            }))
+            .chain(bundle.link_types().map(|link_type| {
+                (
+                    OntologyTypeKind::LinkType,
+                    link_type.id(),
+                    link_type.title(),
+                )
+            }));
+
+        let mut types: Vec<_> = entries
+            .filter(|(entry_kind, ..)| kind.map_or(true, |kind| kind == *entry_kind))
+            .filter(|(_, id, title)| {
+                query.as_ref().map_or(true, |query| {
+                    title.to_lowercase().contains(query)
+                        || id.to_string().to_lowercase().contains(query)
+                })
+            })
+            .map(|(kind, id, title)| json!({ "kind": kind, "$id": id.to_string(), "title": title }))
+            .collect();
+        types.sort_by_cached_key(|entry| entry["$id"].to_string());
+
+        TypeServerResponse::new(200, json!({ "types": types }))
+    }
+
+    fn publish(&self, body: &str) -> TypeServerResponse {
+        let value: Value = match serde_json::from_str(body) {
+            Ok(value) => value,
+            Err(error) => return TypeServerResponse::error(400, error),
+        };
+        let kind = match serde_json::from_value(value.get("kind").cloned().unwrap_or_default()) {
+            Ok(kind) => kind,
+            Err(error) => return TypeServerResponse::error(400, error),
+        };
+
+        match kind {
+            OntologyTypeKind::DataType => {
+                self.publish_type(DataType::try_from(value), R::store_data_type)
+            }
+            OntologyTypeKind::PropertyType => {
+                self.publish_type(PropertyType::try_from(value), R::store_property_type)
+            }
+            OntologyTypeKind::EntityType => {
+                self.publish_type(EntityType::try_from(value), R::store_entity_type)
+            }
+            OntologyTypeKind::LinkType => {
+                self.publish_type(LinkType::try_from(value), R::store_link_type)
+            }
+        }
+    }
+
+    fn publish_type<T, E>(
+        &self,
+        ontology_type: Result<T, E>,
+        store: impl FnOnce(&R, &T) -> Result<(), R::Error>,
+    ) -> TypeServerResponse
+    where
+        T: OntologyType + Clone + Into<Value>,
+        E: fmt::Display,
+    {
+        let ontology_type = match ontology_type {
+            Ok(ontology_type) => ontology_type,
+            Err(error) => return TypeServerResponse::error(400, error),
+        };
+        let id = ontology_type.versioned_uri();
+
+        if let Err(error) = ontology_type.validate_kind() {
+            return TypeServerResponse::error(400, error);
+        }
+        if !id.base_uri().as_str().starts_with(self.origin.as_str()) {
+            return TypeServerResponse::error(
+                400,
+                format!("`{id}` is not hosted at `{}`", self.origin),
+            );
+        }
+
+        let value: Value = ontology_type.clone().into();
+        match self.repository.versions(id.base_uri()) {
+            Ok(versions) if versions.contains(&id.version()) => {
+                return match self.resolve(T::KIND, id) {
+                    Ok(existing) if content_hash(&existing) == content_hash(&value) => {
+                        TypeServerResponse::new(200, value)
+                    }
+                    Ok(_) => TypeServerResponse::error(
+                        409,
+                        format!("`{id}` was already published with a different content"),
+                    ),
+                    Err(error) => TypeServerResponse::error(500, error),
+                };
+            }
+            Ok(_) => {}
+            Err(error) => return TypeServerResponse::error(500, error),
+        }
+
+        let references = ontology_type.referenced_types();
+        let mismatched: Vec<_> = references
+            .iter()
+            .filter(|(kind, uri)| {
+                uri.base_uri()
+                    .components()
+                    .map_or(true, |components| components.kind() != *kind)
+            })
+            .map(|(kind, uri)| json!({ "kind": kind, "$id": uri.to_string() }))
+            .collect();
+        if !mismatched.is_empty() {
+            return TypeServerResponse::new(
+                422,
+                json!({
+                    "error": "the type refers to types of a different kind",
+                    "mismatched": mismatched,
+                }),
+            );
+        }
+
+        let missing: Vec<_> = references
+            .into_iter()
+            .filter(|(_, uri)| *uri != id)
+            .filter(|(_, uri)| {
+                !self
+                    .repository
+                    .versions(uri.base_uri())
+                    .map_or(false, |versions| versions.contains(&uri.version()))
+            })
+            .map(|(_, uri)| uri.to_string())
+            .collect();
+        if !missing.is_empty() {
+            return TypeServerResponse::new(
+                422,
+                json!({
+                    "error": "the type refers to types which were not published",
+                    "missing": missing,
+                }),
+            );
+        }
+
+        match store(&self.repository, &ontology_type) {
+            Ok(()) => TypeServerResponse::new(201, value),
+            Err(error) => TypeServerResponse::error(500, error),
+        }
+    }
+
+    fn resolve(&self, kind: OntologyTypeKind, uri: &VersionedUri) -> Result<Value, R::Error> {
+        Ok(match kind {
+            OntologyTypeKind::DataType => self.repository.resolve_data_type(uri)?.into(),
+            OntologyTypeKind::PropertyType => self.repository.resolve_property_type(uri)?.into(),
+            OntologyTypeKind::EntityType => self.repository.resolve_entity_type(uri)?.into(),
+            OntologyTypeKind::LinkType => self.repository.resolve_link_type(uri)?.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{repository::FsTypeRepository, rewrite, test_data};
+
+    const ORIGIN: &str = "http://localhost:3000/";
+
+    fn server(directory: &tempfile::TempDir) -> TypeServer<FsTypeRepository> {
+        TypeServer::new(
+            FsTypeRepository::new(directory.path()),
+            BaseUri::new(ORIGIN.to_owned()).expect("invalid base URI"),
+        )
+    }
+
+    /// Returns the JSON of the type `input` hosted at [`ORIGIN`].
+    fn hosted<T>(input: &str) -> String
+    where
+        T: FromStr + Clone + Into<Value> + TryFrom<Value>,
+        <T as FromStr>::Err: fmt::Debug,
+        rewrite::RewriteUrisError: From<<T as TryFrom<Value>>::Error>,
+    {
+        let mut prefixes = rewrite::PrefixMap::new();
+        prefixes.insert(
+            BaseUri::new("https://blockprotocol.org/".to_owned()).expect("invalid base URI"),
+            BaseUri::new(ORIGIN.to_owned()).expect("invalid base URI"),
+        );
+        let ontology_type = T::from_str(input).expect("invalid type");
+        let hosted: Value = rewrite::rewrite_uris(&ontology_type, |uri| prefixes.map(uri))
+            .expect("failed to rewrite type")
+            .into();
+        hosted.to_string()
+    }
+
+    fn text() -> String {
+        hosted::<DataType>(test_data::data_type::TEXT_V1)
+    }
+
+    fn name() -> String {
+        hosted::<PropertyType>(test_data::property_type::NAME_V1)
+    }
+
+    #[test]
+    fn publish_and_get() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let server = server(&directory);
+
+        assert_eq!(server.handle("POST", "/types", &text()).status(), 201);
+        assert_eq!(server.handle("POST", "/types", &name()).status(), 201);
+
+        let response = server.handle("GET", "/@alice/types/property-type/name/v/1", "");
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            response.body(),
+            &serde_json::from_str::<Value>(&name()).expect("invalid JSON")
+        );
+
+        let latest = server.handle("GET", "/@alice/types/property-type/name/v/latest", "");
+        assert_eq!(latest, response);
+
+        let versions = server.handle("GET", "/@alice/types/property-type/name/", "");
+        assert_eq!(versions.body(), &json!({ "versions": [1] }));
+
+        assert_eq!(
+            server
+                .handle("GET", "/@alice/types/property-type/name/v/2", "")
+                .status(),
+            404
+        );
+    }
+
+    #[test]
+    fn republish() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let server = server(&directory);
+
+        assert_eq!(server.handle("POST", "/types", &text()).status(), 201);
+        assert_eq!(server.handle("POST", "/types", &text()).status(), 200);
+
+        // Publishing the same content with a different order of keys is not a conflict
+        let original: Value = serde_json::from_str(&text()).expect("invalid JSON");
+        let reordered = original
+            .as_object()
+            .expect("not an object")
+            .iter()
+            .rev()
+            .map(|(key, value)| format!("{}:{value}", Value::from(key.as_str())))
+            .collect::<Vec<_>>()
+            .join(",");
+        assert_eq!(
+            server
+                .handle("POST", "/types", &format!("{{{reordered}}}"))
+                .status(),
+            200
+        );
+
+        let mut changed: Value = serde_json::from_str(&text()).expect("invalid JSON");
+        changed["description"] = json!("A different description");
+        assert_eq!(
+            server
+                .handle("POST", "/types", &changed.to_string())
+                .status(),
+            409
+        );
+    }
+
+    #[test]
+    fn missing_references() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let server = server(&directory);
+
+        let response = server.handle("POST", "/types", &name());
+        assert_eq!(response.status(), 422);
+        assert_eq!(
+            response.body()["missing"],
+            json!(["http://localhost:3000/@blockprotocol/types/data-type/text/v/1"])
+        );
+    }
+
+    #[test]
+    fn foreign_origin() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let server = server(&directory);
+
+        assert_eq!(
+            server
+                .handle("POST", "/types", test_data::data_type::TEXT_V1)
+                .status(),
+            400
+        );
+    }
+
+    #[test]
+    fn foreign_request_target() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let server = server(&directory);
+        assert_eq!(server.handle("POST", "/types", &text()).status(), 201);
+
+        assert_eq!(
+            server
+                .handle(
+                    "GET",
+                    "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1",
+                    ""
+                )
+                .status(),
+            400
+        );
+    }
+
+    #[test]
+    fn mismatched_references() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let server = server(&directory);
+        assert_eq!(server.handle("POST", "/types", &text()).status(), 201);
+
+        // The data type is referenced as a property type
+        let mut entity_type: Value =
+            serde_json::from_str(&hosted::<EntityType>(test_data::entity_type::BOOK_V1))
+                .expect("invalid JSON");
+        let text = "http://localhost:3000/@blockprotocol/types/data-type/text/";
+        entity_type["properties"] = json!({ text: { "$ref": format!("{text}v/1") } });
+        entity_type["required"] = json!([]);
+        entity_type["links"] = json!({});
+        entity_type["requiredLinks"] = json!([]);
+
+        let response = server.handle("POST", "/types", &entity_type.to_string());
+        assert_eq!(response.status(), 422, "{response:?}");
+        assert_eq!(
+            response.body()["mismatched"],
+            json!([{ "kind": "propertyType", "$id": format!("{text}v/1") }])
+        );
+    }
+
+    #[test]
+    fn list_and_search() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let server = server(&directory);
+        assert_eq!(server.handle("POST", "/types", &text()).status(), 201);
+        assert_eq!(server.handle("POST", "/types", &name()).status(), 201);
+
+        let all = server.handle("GET", "/types", "");
+        assert_eq!(all.body()["types"].as_array().map(Vec::len), Some(2));
+
+        let data_types = server.handle("GET", "/types?kind=dataType", "");
+        assert_eq!(
+            data_types.body()["types"][0]["title"],
+            json!("Text"),
+            "{data_types:?}"
+        );
+
+        let search = server.handle("GET", "/types?query=NAME", "");
+        assert_eq!(
+            search.body()["types"],
+            json!([{
+                "kind": "propertyType",
+                "$id": "http://localhost:3000/@alice/types/property-type/name/v/1",
+                "title": "Name",
+            }])
+        );
+
+        assert_eq!(server.handle("GET", "/types?kind=block", "").status(), 400);
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn resolve_over_http() {
+        use crate::repository::{HttpTypeResolver, TypeResolver};
+
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let http = tiny_http::Server::http("127.0.0.1:0").expect("failed to start server");
+        let origin = format!(
+            "http://127.0.0.1:{}/",
+            http.server_addr()
+                .to_ip()
+                .expect("not an IP address")
+                .port()
+        );
+
+        let server = TypeServer::new(
+            FsTypeRepository::new(directory.path()),
+            BaseUri::new(origin.clone()).expect("invalid base URI"),
+        );
+        let text = text().replace(ORIGIN, &origin);
+        assert_eq!(server.handle("POST", "/types", &text).status(), 201);
+        std::thread::spawn(move || server.serve(&http));
+
+        let id =
+            VersionedUri::from_str(&format!("{origin}@blockprotocol/types/data-type/text/v/1"))
+                .expect("invalid versioned URI");
+        let data_type = HttpTypeResolver::new()
+            .resolve_data_type(&id)
+            .expect("failed to resolve type");
+        assert_eq!(data_type.id(), &id);
+    }
+}
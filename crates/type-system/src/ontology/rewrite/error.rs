@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::{
+    uri::VersionedUri, ParseDataTypeError, ParseEntityTypeError, ParseLinkTypeError,
+    ParsePropertyTypeError,
+};
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum RewriteUrisError {
+    #[error("multiple keys were rewritten to the same URI: `{0}`")]
+    DuplicateKey(String),
+    #[error("multiple types were rewritten to the same URI: `{0}`")]
+    DuplicateType(VersionedUri),
+    #[error("the rewritten data type is invalid: `{0}`")]
+    InvalidDataType(ParseDataTypeError),
+    #[error("the rewritten property type is invalid: `{0}`")]
+    InvalidPropertyType(ParsePropertyTypeError),
+    #[error("the rewritten entity type is invalid: `{0}`")]
+    InvalidEntityType(ParseEntityTypeError),
+    #[error("the rewritten link type is invalid: `{0}`")]
+    InvalidLinkType(ParseLinkTypeError),
+}
+
+impl From<ParseDataTypeError> for RewriteUrisError {
+    fn from(error: ParseDataTypeError) -> Self {
+        Self::InvalidDataType(error)
+    }
+}
+
+impl From<ParsePropertyTypeError> for RewriteUrisError {
+    fn from(error: ParsePropertyTypeError) -> Self {
+        Self::InvalidPropertyType(error)
+    }
+}
+
+impl From<ParseEntityTypeError> for RewriteUrisError {
+    fn from(error: ParseEntityTypeError) -> Self {
+        Self::InvalidEntityType(error)
+    }
+}
+
+impl From<ParseLinkTypeError> for RewriteUrisError {
+    fn from(error: ParseLinkTypeError) -> Self {
+        Self::InvalidLinkType(error)
+    }
+}
@@ -0,0 +1,424 @@
+//! Rewriting the URIs of types of the Type System, e.g. to mirror types onto a different host.
+//!
+//! Every occurrence of a URI inside of a type is passed to a mapping function which decides on
+//! the new [`BaseUri`], the version of [`VersionedUri`]s is kept. This covers the `$id` of the
+//! type, every `$ref`, the keys of `properties` and `links`, the entries of `required` and
+//! `requiredLinks`, as well as the property keys inside of `default` and `examples`.
+//!
+//! Like [`patch`](crate::patch), rewriting operates on the JSON representation of a type, so the
+//! rewritten type goes through exactly the same validation as a type which was parsed from a
+//! string.
+
+mod error;
+
+use std::{mem, str::FromStr};
+
+pub use error::RewriteUrisError;
+use serde_json::{Map, Value};
+
+use crate::{
+    uri::{BaseUri, VersionedUri},
+    TypeBundle,
+};
+
+/// Maps [`BaseUri`]s starting with a given prefix onto a different prefix.
+///
+/// If multiple prefixes match a URI, the longest one is used.
+///
+/// # Example
+///
+/// ```
+/// use type_system::{rewrite::PrefixMap, uri::BaseUri};
+///
+/// let mut prefixes = PrefixMap::new();
+/// prefixes.insert(
+///     BaseUri::new("https://blockprotocol.org/".to_owned())?,
+///     BaseUri::new("https://types.example.com/mirror/".to_owned())?,
+/// );
+///
+/// let uri = BaseUri::new("https://blockprotocol.org/@alice/types/data-type/text/".to_owned())?;
+/// assert_eq!(
+///     prefixes.map(&uri).as_ref().map(BaseUri::as_str),
+///     Some("https://types.example.com/mirror/@alice/types/data-type/text/")
+/// );
+/// # Ok::<(), type_system::uri::ParseBaseUriError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrefixMap {
+    prefixes: Vec<(BaseUri, BaseUri)>,
+}
+
+impl PrefixMap {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps URIs starting with `from` onto `to`.
+    ///
+    /// If `from` was already mapped, the previous target is replaced and returned.
+    pub fn insert(&mut self, from: BaseUri, to: BaseUri) -> Option<BaseUri> {
+        if let Some((_, target)) = self.prefixes.iter_mut().find(|(prefix, _)| *prefix == from) {
+            return Some(mem::replace(target, to));
+        }
+        self.prefixes.push((from, to));
+        None
+    }
+
+    /// Returns the rewritten [`BaseUri`] or `None` if no prefix matches `uri`.
+    ///
+    /// # Panics
+    ///
+    /// - if replacing the prefix does not result in a valid [`BaseUri`], which cannot happen as
+    ///   both prefixes are valid [`BaseUri`]s ending in a `/`
+    #[must_use]
+    pub fn map(&self, uri: &BaseUri) -> Option<BaseUri> {
+        self.prefixes
+            .iter()
+            .filter_map(|(prefix, target)| {
+                let suffix = uri.as_str().strip_prefix(prefix.as_str())?;
+                Some((prefix.as_str().len(), target, suffix))
+            })
+            .max_by_key(|(prefix_len, ..)| *prefix_len)
+            .map(|(_, target, suffix)| {
+                BaseUri::new(format!("{target}{suffix}"))
+                    .expect("replacing the prefix of a base URI should result in a valid base URI")
+            })
+    }
+}
+
+/// Rewrites every URI inside of `ontology_type` by using `mapping` and validates the result.
+///
+/// `mapping` returns the new [`BaseUri`] for a given [`BaseUri`] or `None` if it should be kept
+/// as it is.
+///
+/// # Errors
+///
+/// - [`RewriteUrisError::DuplicateKey`] if multiple keys of an object were rewritten to the same
+///   URI
+/// - [`RewriteUrisError::InvalidDataType`], [`RewriteUrisError::InvalidPropertyType`],
+///   [`RewriteUrisError::InvalidEntityType`], or [`RewriteUrisError::InvalidLinkType`] if the
+///   rewritten type is not valid
+pub fn rewrite_uris<T>(
+    ontology_type: &T,
+    mapping: impl FnMut(&BaseUri) -> Option<BaseUri>,
+) -> Result<T, RewriteUrisError>
+where
+    T: Clone + Into<Value> + TryFrom<Value>,
+    RewriteUrisError: From<<T as TryFrom<Value>>::Error>,
+{
+    let mut document = ontology_type.clone().into();
+    let mut rewriter = Rewriter { mapping };
+
+    // Apart from the `$id`, data types may contain arbitrary keywords which are not interpreted
+    if document.get("kind").and_then(Value::as_str) == Some("dataType") {
+        if let Some(Value::String(id)) = document.get_mut("$id") {
+            *id = rewriter.rewrite_uri(id);
+        }
+    } else {
+        rewriter.rewrite_schema(&mut document)?;
+    }
+
+    Ok(T::try_from(document)?)
+}
+
+/// Rewrites every URI of every type inside of `bundle` by using `mapping`.
+///
+/// See [`rewrite_uris`] for details.
+///
+/// # Errors
+///
+/// - [`RewriteUrisError::DuplicateType`] if multiple types of the same kind were rewritten to the
+///   same [`VersionedUri`]
+/// - any error returned by [`rewrite_uris`]
+pub fn rewrite_bundle_uris(
+    bundle: &TypeBundle,
+    mut mapping: impl FnMut(&BaseUri) -> Option<BaseUri>,
+) -> Result<TypeBundle, RewriteUrisError> {
+    let mut rewritten = TypeBundle::new();
+
+    for data_type in bundle.data_types() {
+        let data_type = rewrite_uris(data_type, &mut mapping)?;
+        let id = data_type.id().clone();
+        if rewritten.insert_data_type(data_type).is_some() {
+            return Err(RewriteUrisError::DuplicateType(id));
+        }
+    }
+    for property_type in bundle.property_types() {
+        let property_type = rewrite_uris(property_type, &mut mapping)?;
+        let id = property_type.id().clone();
+        if rewritten.insert_property_type(property_type).is_some() {
+            return Err(RewriteUrisError::DuplicateType(id));
+        }
+    }
+    for entity_type in bundle.entity_types() {
+        let entity_type = rewrite_uris(entity_type, &mut mapping)?;
+        let id = entity_type.id().clone();
+        if rewritten.insert_entity_type(entity_type).is_some() {
+            return Err(RewriteUrisError::DuplicateType(id));
+        }
+    }
+    for link_type in bundle.link_types() {
+        let link_type = rewrite_uris(link_type, &mut mapping)?;
+        let id = link_type.id().clone();
+        if rewritten.insert_link_type(link_type).is_some() {
+            return Err(RewriteUrisError::DuplicateType(id));
+        }
+    }
+
+    Ok(rewritten)
+}
+
+struct Rewriter<F> {
+    mapping: F,
+}
+
+impl<F> Rewriter<F>
+where
+    F: FnMut(&BaseUri) -> Option<BaseUri>,
+{
+    /// Rewrites `uri` if it's either a [`BaseUri`] or a [`VersionedUri`], other strings are
+    /// returned unchanged.
+    fn rewrite_uri(&mut self, uri: &str) -> String {
+        if let Ok(versioned_uri) = VersionedUri::from_str(uri) {
+            if let Some(base_uri) = (self.mapping)(versioned_uri.base_uri()) {
+                return VersionedUri::new(base_uri, versioned_uri.version()).to_string();
+            }
+        } else if let Ok(base_uri) = BaseUri::new(uri.to_owned()) {
+            if let Some(base_uri) = (self.mapping)(&base_uri) {
+                return base_uri.to_string();
+            }
+        }
+
+        uri.to_owned()
+    }
+
+    fn rewrite_keys(
+        &mut self,
+        object: Map<String, Value>,
+    ) -> Result<Map<String, Value>, RewriteUrisError> {
+        let mut rewritten = Map::with_capacity(object.len());
+        for (key, value) in object {
+            let key = self.rewrite_uri(&key);
+            if rewritten.contains_key(&key) {
+                return Err(RewriteUrisError::DuplicateKey(key));
+            }
+            rewritten.insert(key, value);
+        }
+        Ok(rewritten)
+    }
+
+    fn rewrite_schema(&mut self, value: &mut Value) -> Result<(), RewriteUrisError> {
+        match value {
+            Value::Object(object) => {
+                for (key, value) in object.iter_mut() {
+                    match (key.as_str(), value) {
+                        ("$id" | "$ref", Value::String(uri)) => *uri = self.rewrite_uri(uri),
+                        ("properties" | "links", Value::Object(object)) => {
+                            *object = self.rewrite_keys(mem::take(object))?;
+                            for value in object.values_mut() {
+                                self.rewrite_schema(value)?;
+                            }
+                        }
+                        ("required" | "requiredLinks", Value::Array(uris)) => {
+                            for uri in uris {
+                                if let Value::String(uri) = uri {
+                                    *uri = self.rewrite_uri(uri);
+                                }
+                            }
+                        }
+                        ("default" | "examples", value) => self.rewrite_instance(value)?,
+                        (_, value) => self.rewrite_schema(value)?,
+                    }
+                }
+            }
+            Value::Array(values) => {
+                for value in values {
+                    self.rewrite_schema(value)?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Rewrites the property keys of instance data, e.g. the `default` value of an entity type.
+    fn rewrite_instance(&mut self, value: &mut Value) -> Result<(), RewriteUrisError> {
+        match value {
+            Value::Object(object) => {
+                *object = self.rewrite_keys(mem::take(object))?;
+                for value in object.values_mut() {
+                    self.rewrite_instance(value)?;
+                }
+            }
+            Value::Array(values) => {
+                for value in values {
+                    self.rewrite_instance(value)?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use super::*;
+    use crate::{test_data, DataType, EntityType, LinkType, PropertyType};
+
+    fn mirror() -> PrefixMap {
+        let mut prefixes = PrefixMap::new();
+        prefixes.insert(
+            BaseUri::new("https://blockprotocol.org/".to_owned()).expect("invalid base URI"),
+            BaseUri::new("https://mirror.example.com/bp/".to_owned()).expect("invalid base URI"),
+        );
+        prefixes
+    }
+
+    fn mirrored(value: &Value) -> Value {
+        let json = serde_json::to_string(value).expect("failed to serialize");
+        serde_json::from_str(&json.replace(
+            "https://blockprotocol.org/",
+            "https://mirror.example.com/bp/",
+        ))
+        .expect("failed to deserialize")
+    }
+
+    fn check_mirrored<T>(input: &str)
+    where
+        T: Clone + FromStr + Into<Value> + TryFrom<Value> + fmt::Debug + PartialEq,
+        <T as FromStr>::Err: fmt::Debug,
+        RewriteUrisError: From<<T as TryFrom<Value>>::Error>,
+    {
+        let prefixes = mirror();
+        let ontology_type = T::from_str(input).expect("invalid type");
+        let rewritten =
+            rewrite_uris(&ontology_type, |uri| prefixes.map(uri)).expect("failed to rewrite");
+
+        assert_eq!(rewritten.into(), mirrored(&ontology_type.into()));
+    }
+
+    #[test]
+    fn data_type() {
+        check_mirrored::<DataType>(test_data::data_type::TEXT_V1);
+    }
+
+    #[test]
+    fn property_type() {
+        check_mirrored::<PropertyType>(test_data::property_type::CONTACT_INFORMATION_V1);
+        check_mirrored::<PropertyType>(test_data::property_type::INTERESTS_V1);
+    }
+
+    #[test]
+    fn entity_type() {
+        check_mirrored::<EntityType>(test_data::entity_type::BOOK_V1);
+        check_mirrored::<EntityType>(test_data::entity_type::PLAYLIST_V1);
+    }
+
+    #[test]
+    fn link_type() {
+        check_mirrored::<LinkType>(test_data::link_type::OWNS_V2);
+    }
+
+    #[test]
+    fn longest_prefix() {
+        let mut prefixes = mirror();
+        prefixes.insert(
+            BaseUri::new("https://blockprotocol.org/@alice/".to_owned()).expect("invalid base URI"),
+            BaseUri::new("https://alice.example.com/".to_owned()).expect("invalid base URI"),
+        );
+
+        let uri = |uri: &str| BaseUri::new(uri.to_owned()).expect("invalid base URI");
+        assert_eq!(
+            prefixes.map(&uri(
+                "https://blockprotocol.org/@alice/types/data-type/text/"
+            )),
+            Some(uri("https://alice.example.com/types/data-type/text/"))
+        );
+        assert_eq!(
+            prefixes.map(&uri("https://blockprotocol.org/@bob/types/data-type/text/")),
+            Some(uri(
+                "https://mirror.example.com/bp/@bob/types/data-type/text/"
+            ))
+        );
+        assert_eq!(
+            prefixes.map(&uri("https://example.com/@alice/types/data-type/text/")),
+            None
+        );
+    }
+
+    #[test]
+    fn duplicate_key() {
+        let book = EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid type");
+        let target =
+            BaseUri::new("https://blockprotocol.org/@alice/types/property-type/name/".to_owned())
+                .expect("invalid base URI");
+
+        assert_eq!(
+            rewrite_uris(&book, |uri| {
+                uri.as_str().ends_with("/blurb/").then(|| target.clone())
+            }),
+            Err(RewriteUrisError::DuplicateKey(target.to_string()))
+        );
+    }
+
+    #[test]
+    fn inconsistent_mapping() {
+        let book = EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid type");
+
+        // Only the first occurrence of the URI is rewritten, so the key of the property does not
+        // match the reference anymore
+        let mut rewritten_once = false;
+        let rewritten = rewrite_uris(&book, |uri| {
+            if rewritten_once || !uri.as_str().ends_with("/blurb/") {
+                return None;
+            }
+            rewritten_once = true;
+            Some(BaseUri::new("https://example.com/blurb/".to_owned()).expect("invalid base URI"))
+        });
+
+        assert!(matches!(
+            rewritten,
+            Err(RewriteUrisError::InvalidEntityType(_))
+        ));
+    }
+
+    #[test]
+    fn bundle() {
+        let mut bundle = TypeBundle::new();
+        bundle.insert_data_type(
+            DataType::from_str(test_data::data_type::TEXT_V1).expect("invalid data type"),
+        );
+        bundle.insert_entity_type(
+            EntityType::from_str(test_data::entity_type::BOOK_V1).expect("invalid entity type"),
+        );
+
+        let prefixes = mirror();
+        let rewritten =
+            rewrite_bundle_uris(&bundle, |uri| prefixes.map(uri)).expect("failed to rewrite");
+        assert_eq!(rewritten.len(), 2);
+        assert!(
+            rewritten
+                .entity_types()
+                .chain(bundle.entity_types())
+                .all(|entity_type| entity_type.title() == "Book")
+        );
+        assert!(rewritten.data_types().all(|data_type| {
+            data_type
+                .id()
+                .base_uri()
+                .as_str()
+                .starts_with("https://mirror.example.com/bp/")
+        }));
+
+        let collapsed = rewrite_bundle_uris(&bundle, |_| {
+            Some(BaseUri::new("https://example.com/".to_owned()).expect("invalid base URI"))
+        });
+        assert!(collapsed.is_err());
+    }
+}
@@ -3,7 +3,7 @@ use thiserror::Error;
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
-use crate::{uri::ParseVersionedUriError, ParseOneOfError};
+use crate::{uri::ParseVersionedUriError, ParseOneOfError, ValidationError};
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
@@ -11,6 +11,8 @@ use crate::{uri::ParseVersionedUriError, ParseOneOfError};
 pub enum ParseOneOfArrayError {
     #[error("invalid items definition inside array: `{0}`")]
     InvalidItems(ParseOneOfError),
+    #[error("failed validation: `{0}`")]
+    ValidationError(ValidationError),
     #[error("error in JSON: `{0}`")]
     InvalidJson(String),
 }
@@ -21,6 +23,8 @@ pub enum ParseOneOfArrayError {
 pub enum ParsePropertyTypeReferenceArrayError {
     #[error("invalid property type reference inside items: `{0}`")]
     InvalidReference(ParseVersionedUriError),
+    #[error("failed validation: `{0}`")]
+    ValidationError(ValidationError),
     #[error("error in JSON: `{0}`")]
     InvalidJson(String),
 }
@@ -1,7 +1,7 @@
 pub(crate) mod error;
 pub(in crate::ontology) mod repr;
 
-use crate::{uri::BaseUri, ValidateUri, ValidationError};
+use crate::{uri::BaseUri, Compatibility, ValidateUri, ValidationError};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Array<T> {
@@ -11,8 +11,13 @@ pub struct Array<T> {
 }
 
 impl<T> Array<T> {
+    /// Creates a new `Array` without validating.
     #[must_use]
-    pub const fn new(items: T, min_items: Option<usize>, max_items: Option<usize>) -> Self {
+    pub const fn new_unchecked(
+        items: T,
+        min_items: Option<usize>,
+        max_items: Option<usize>,
+    ) -> Self {
         Self {
             items,
             min_items,
@@ -20,6 +25,33 @@ impl<T> Array<T> {
         }
     }
 
+    /// Creates a new `Array` containing between `min_items` and `max_items` items.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidItemBounds`] if `min_items` is greater than `max_items`
+    pub fn new(
+        items: T,
+        min_items: Option<usize>,
+        max_items: Option<usize>,
+    ) -> Result<Self, ValidationError> {
+        let array = Self::new_unchecked(items, min_items, max_items);
+        array.validate()?;
+        Ok(array)
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        match (self.min_items, self.max_items) {
+            (Some(min_items), Some(max_items)) if min_items > max_items => {
+                Err(ValidationError::InvalidItemBounds {
+                    min_items,
+                    max_items,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
     #[must_use]
     pub const fn items(&self) -> &T {
         &self.items
@@ -34,6 +66,36 @@ impl<T> Array<T> {
     pub const fn max_items(&self) -> Option<usize> {
         self.max_items
     }
+
+    pub(crate) fn items_mut(&mut self) -> &mut T {
+        &mut self.items
+    }
+
+    /// Returns `true` if there is a value for the array, given whether there is a value for its
+    /// items.
+    pub(crate) fn is_satisfiable(&self, items_satisfiable: bool) -> bool {
+        self.validate().is_ok() && (items_satisfiable || self.min_items.unwrap_or(0) == 0)
+    }
+
+    /// Removes constraints which are equivalent to not specifying the constraint at all.
+    pub(crate) fn normalize_bounds(&mut self) {
+        if self.min_items == Some(0) {
+            self.min_items = None;
+        }
+    }
+}
+
+impl<T: Compatibility> Compatibility for Array<T> {
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        let min_items_compatible = other.min_items.unwrap_or(0) <= self.min_items.unwrap_or(0);
+        let max_items_compatible = match (self.max_items, other.max_items) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(max_items), Some(other_max_items)) => max_items <= other_max_items,
+        };
+
+        min_items_compatible && max_items_compatible && self.items.is_compatible_with(&other.items)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -42,6 +104,24 @@ pub enum ValueOrArray<T> {
     Array(Array<T>),
 }
 
+impl<T> ValueOrArray<T> {
+    pub(crate) fn normalize_bounds(&mut self) {
+        if let Self::Array(array) = self {
+            array.normalize_bounds();
+        }
+    }
+}
+
+impl<T: Compatibility> Compatibility for ValueOrArray<T> {
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Value(value), Self::Value(other_value)) => value.is_compatible_with(other_value),
+            (Self::Array(array), Self::Array(other_array)) => array.is_compatible_with(other_array),
+            _ => false,
+        }
+    }
+}
+
 impl<T: ValidateUri> ValidateUri for ValueOrArray<T> {
     fn validate_uri(&self, base_uri: &BaseUri) -> Result<(), ValidationError> {
         match self {
@@ -58,7 +138,10 @@ mod tests {
     use serde_json::json;
 
     use super::*;
-    use crate::{repr, uri::VersionedUri, PropertyTypeReference};
+    use crate::{
+        repr, uri::VersionedUri, utils::tests::ensure_failed_validation,
+        ParsePropertyTypeReferenceArrayError, PropertyTypeReference,
+    };
 
     fn get_test_value_or_array(uri: &VersionedUri) -> ValueOrArray<PropertyTypeReference> {
         let json_repr = json!({
@@ -102,4 +185,30 @@ mod tests {
             .validate_uri(uri_b.base_uri()) // Try and validate against a different URI
             .expect_err("expected validation against base URI to fail but it didn't");
     }
+
+    #[test]
+    fn invalid_bounds() {
+        ensure_failed_validation::<
+            repr::Array<repr::PropertyTypeReference>,
+            Array<PropertyTypeReference>,
+        >(
+            &json!({
+                "type": "array",
+                "items": {
+                    "$ref": "https://blockprotocol.org/@alice/types/property-type/age/v/2"
+                },
+                "minItems": 5,
+                "maxItems": 2,
+            }),
+            ParsePropertyTypeReferenceArrayError::ValidationError(
+                ValidationError::InvalidItemBounds {
+                    min_items: 5,
+                    max_items: 2,
+                },
+            ),
+        );
+
+        assert!(Array::new((), Some(2), Some(2)).is_ok());
+        assert!(Array::new((), None, Some(0)).is_ok());
+    }
 }
@@ -35,14 +35,15 @@ impl TryFrom<Array<repr::OneOf<repr::PropertyValues>>> for super::Array<OneOf<Pr
     type Error = ParseOneOfArrayError;
 
     fn try_from(array_repr: Array<repr::OneOf<repr::PropertyValues>>) -> Result<Self, Self::Error> {
-        Ok(Self {
-            items: array_repr
+        Self::new(
+            array_repr
                 .items
                 .try_into()
                 .map_err(ParseOneOfArrayError::InvalidItems)?,
-            min_items: array_repr.min_items,
-            max_items: array_repr.max_items,
-        })
+            array_repr.min_items,
+            array_repr.max_items,
+        )
+        .map_err(ParseOneOfArrayError::ValidationError)
     }
 }
 
@@ -50,14 +51,15 @@ impl TryFrom<Array<repr::PropertyTypeReference>> for super::Array<PropertyTypeRe
     type Error = ParsePropertyTypeReferenceArrayError;
 
     fn try_from(array_repr: Array<repr::PropertyTypeReference>) -> Result<Self, Self::Error> {
-        Ok(Self {
-            items: array_repr
+        Self::new(
+            array_repr
                 .items
                 .try_into()
                 .map_err(ParsePropertyTypeReferenceArrayError::InvalidReference)?,
-            min_items: array_repr.min_items,
-            max_items: array_repr.max_items,
-        })
+            array_repr.min_items,
+            array_repr.max_items,
+        )
+        .map_err(ParsePropertyTypeReferenceArrayError::ValidationError)
     }
 }
 
@@ -65,14 +67,15 @@ impl TryFrom<Array<repr::EntityTypeReference>> for super::Array<EntityTypeRefere
     type Error = ParseEntityTypeReferenceArrayError;
 
     fn try_from(array_repr: Array<repr::EntityTypeReference>) -> Result<Self, Self::Error> {
-        Ok(Self {
-            items: array_repr
+        Self::new(
+            array_repr
                 .items
                 .try_into()
                 .map_err(ParseEntityTypeReferenceArrayError::InvalidReference)?,
-            min_items: array_repr.min_items,
-            max_items: array_repr.max_items,
-        })
+            array_repr.min_items,
+            array_repr.max_items,
+        )
+        .map_err(ParseEntityTypeReferenceArrayError::ValidationError)
     }
 }
 
@@ -0,0 +1,20 @@
+/// Compatibility of schemas as defined by [RFC 0408].
+///
+/// A schema `X` is _compatible with_ a schema `Y` if all possible values that satisfy `X` also
+/// satisfy `Y`. Two schemas are _equivalent_ if they are compatible in both directions. Semantic
+/// annotations like `title` or `description` don't affect the compatibility of schemas, neither
+/// does the order of constraints.
+///
+/// Referenced types are not resolved, so two references are only compatible if they refer to the
+/// exact same version of a type.
+///
+/// [RFC 0408]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/0408-versioning-types.md#determining-type-compatibility
+pub trait Compatibility {
+    /// Returns `true` if every value which satisfies `self` also satisfies `other`.
+    fn is_compatible_with(&self, other: &Self) -> bool;
+
+    /// Returns `true` if `self` is compatible with `other` and `other` is compatible with `self`.
+    fn is_equivalent_to(&self, other: &Self) -> bool {
+        self.is_compatible_with(other) && other.is_compatible_with(self)
+    }
+}
@@ -1,4 +1,7 @@
 pub(crate) mod array;
+pub(crate) mod compatibility;
+pub(crate) mod naming;
 pub(crate) mod object;
 pub(crate) mod one_of;
+pub(crate) mod pointer;
 pub(crate) mod validate;
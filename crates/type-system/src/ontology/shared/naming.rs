@@ -0,0 +1,192 @@
+//! Helpers for naming types which are created from other formats, and for naming the parts of
+//! other formats which are generated from types.
+
+use std::{collections::HashSet, fmt};
+
+use crate::{
+    uri::{BaseUri, VersionedUri},
+    DataTypeReference,
+};
+
+/// The Block Protocol data types values are assigned to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Primitive {
+    Text,
+    Number,
+    Boolean,
+    Null,
+    Object,
+    EmptyList,
+}
+
+impl Primitive {
+    const fn slug(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Number => "number",
+            Self::Boolean => "boolean",
+            Self::Null => "null",
+            Self::Object => "object",
+            Self::EmptyList => "empty-list",
+        }
+    }
+
+    pub(crate) fn reference(self) -> DataTypeReference {
+        let base_uri = BaseUri::new(format!(
+            "https://blockprotocol.org/@blockprotocol/types/data-type/{}/",
+            self.slug()
+        ))
+        .expect("the URIs of the Block Protocol data types are valid");
+        DataTypeReference::new(VersionedUri::new(base_uri, 1))
+    }
+}
+
+/// Splits a key like `firstName`, `first_name` or `first-name` into its words.
+pub(crate) fn words(key: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut previous_lowercase = false;
+    for character in key.chars() {
+        if !character.is_alphanumeric() {
+            previous_lowercase = false;
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if character.is_uppercase() && previous_lowercase {
+            words.push(std::mem::take(&mut word));
+        }
+        previous_lowercase = character.is_lowercase() || character.is_numeric();
+        word.push(character);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+pub(crate) fn title(words: &[String]) -> String {
+    words
+        .iter()
+        .map(|word| {
+            let mut characters = word.chars();
+            characters.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(characters).collect()
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Joins `words` in `PascalCase`, dropping all characters which are not ASCII alphanumerics.
+pub(crate) fn pascal_case(words: &[String]) -> String {
+    words
+        .iter()
+        .flat_map(|word| {
+            let mut characters = word.chars();
+            characters
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(characters.flat_map(char::to_lowercase))
+        })
+        .filter(char::is_ascii_alphanumeric)
+        .collect()
+}
+
+/// Returns `name` as an identifier of a generated format, i.e. prefixed by `_` if it starts with a
+/// digit, or `None` if it's empty.
+pub(crate) fn identifier(name: String) -> Option<String> {
+    if name.is_empty() {
+        None
+    } else if name.starts_with(|character: char| character.is_ascii_digit()) {
+        Some(format!("_{name}"))
+    } else {
+        Some(name)
+    }
+}
+
+/// Returns `name`, or `name` with a numeric suffix after `separator` if `name` is already in
+/// `names`, and adds it to `names`.
+pub(crate) fn unique(names: &mut HashSet<String>, name: &str, separator: &str) -> String {
+    let mut unique = name.to_owned();
+    let mut suffix = 1;
+    while names.contains(&unique) {
+        suffix += 1;
+        unique = format!("{name}{separator}{suffix}");
+    }
+    names.insert(unique.clone());
+    unique
+}
+
+/// An error of generating another format from types, which fails if a referenced type cannot be
+/// resolved.
+pub(crate) trait UnresolvedTypeError: Sized {
+    fn unresolved_type(uri: VersionedUri, error: String) -> Self;
+
+    /// Creates the error for the type identified by `uri`, which could not be resolved.
+    fn unresolved(uri: &VersionedUri, error: &impl fmt::Display) -> Self {
+        Self::unresolved_type(uri.clone(), error.to_string())
+    }
+}
+
+pub(crate) fn slug(words: &[String]) -> String {
+    words.join("-").to_lowercase()
+}
+
+/// Guesses the plural of an English title.
+pub(crate) fn plural(title: &str) -> String {
+    let lowercase = title.to_lowercase();
+    if lowercase.ends_with('s')
+        || lowercase.ends_with('x')
+        || lowercase.ends_with("ch")
+        || lowercase.ends_with("sh")
+    {
+        format!("{title}es")
+    } else if lowercase.ends_with('y')
+        && !["ay", "ey", "iy", "oy", "uy"]
+            .iter()
+            .any(|ending| lowercase.ends_with(ending))
+    {
+        format!("{}ies", title.trim_end_matches(['y', 'Y']))
+    } else {
+        format!("{title}s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys() {
+        assert_eq!(words("firstName"), ["first", "Name"]);
+        assert_eq!(words("first_name"), ["first", "name"]);
+        assert_eq!(words("HTTP-status code2"), ["HTTP", "status", "code2"]);
+        assert_eq!(title(&words("emailAddress")), "Email Address");
+        assert_eq!(slug(&words("emailAddress")), "email-address");
+        assert_eq!(pascal_case(&words("HTTP-status code2")), "HttpStatusCode2");
+
+        assert_eq!(plural("Book"), "Books");
+        assert_eq!(plural("Address"), "Addresses");
+        assert_eq!(plural("Category"), "Categories");
+        assert_eq!(plural("Day"), "Days");
+    }
+
+    #[test]
+    fn identifiers() {
+        assert_eq!(identifier("name".to_owned()), Some("name".to_owned()));
+        assert_eq!(identifier("2nd".to_owned()), Some("_2nd".to_owned()));
+        assert_eq!(identifier(String::new()), None);
+    }
+
+    #[test]
+    fn unique_names() {
+        let mut names = HashSet::new();
+        assert_eq!(unique(&mut names, "Name", ""), "Name");
+        assert_eq!(unique(&mut names, "Name", ""), "Name2");
+        assert_eq!(unique(&mut names, "Name", "_"), "Name_2");
+        assert_eq!(names.len(), 3);
+    }
+}
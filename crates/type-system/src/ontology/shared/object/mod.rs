@@ -3,7 +3,7 @@ pub(in crate::ontology) mod repr;
 
 use std::collections::HashMap;
 
-use crate::{uri::BaseUri, ValidateUri, ValidationError};
+use crate::{uri::BaseUri, Compatibility, ValidateUri, ValidationError};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Object<T, const MIN: usize = 0> {
@@ -71,6 +71,42 @@ impl<T: ValidateUri, const MIN: usize> Object<T, MIN> {
     }
 }
 
+impl<T, const MIN: usize> Object<T, MIN> {
+    pub(crate) fn properties_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.properties.values_mut()
+    }
+
+    /// Sorts the `required` list and removes duplicated entries from it.
+    pub(crate) fn normalize_required(&mut self) {
+        self.required
+            .sort_by(|lhs, rhs| lhs.as_str().cmp(rhs.as_str()));
+        self.required.dedup();
+    }
+}
+
+impl<T: Compatibility, const MIN: usize> Compatibility for Object<T, MIN> {
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        // Additional properties are not allowed, so every property has to be known to `other`
+        let properties_compatible = self.properties.iter().all(|(base_uri, property)| {
+            other
+                .properties
+                .get(base_uri)
+                .map_or(false, |other_property| {
+                    property.is_compatible_with(other_property)
+                })
+        });
+
+        // Every value of `self` contains the properties required by `self`, so these have to be a
+        // superset of the properties required by `other`
+        let required_compatible = other
+            .required
+            .iter()
+            .all(|base_uri| self.required.contains(base_uri));
+
+        properties_compatible && required_compatible
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
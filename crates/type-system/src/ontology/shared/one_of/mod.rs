@@ -1,7 +1,7 @@
 pub(crate) mod error;
 pub(in crate::ontology) mod repr;
 
-use crate::ValidationError;
+use crate::{Compatibility, ValidationError};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OneOf<T> {
@@ -32,6 +32,26 @@ impl<T> OneOf<T> {
         &self.possibilities
     }
 
+    pub(crate) fn one_of_mut(&mut self) -> &mut [T] {
+        &mut self.possibilities
+    }
+
+    /// Sorts the possibilities by the given key and removes possibilities with duplicated keys.
+    pub(crate) fn sort_and_dedup_by_key<K: Ord>(&mut self, mut key: impl FnMut(&T) -> K) {
+        let mut keyed = self
+            .possibilities
+            .drain(..)
+            .map(|possibility| (key(&possibility), possibility))
+            .collect::<Vec<_>>();
+        keyed.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+        keyed.dedup_by(|(lhs, _), (rhs, _)| lhs == rhs);
+
+        self.possibilities = keyed
+            .into_iter()
+            .map(|(_, possibility)| possibility)
+            .collect();
+    }
+
     fn validate(&self) -> Result<(), ValidationError> {
         if self.one_of().is_empty() {
             return Err(ValidationError::EmptyOneOf);
@@ -40,6 +60,17 @@ impl<T> OneOf<T> {
     }
 }
 
+impl<T: Compatibility> Compatibility for OneOf<T> {
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        self.possibilities.iter().all(|possibility| {
+            other
+                .possibilities
+                .iter()
+                .any(|other_possibility| possibility.is_compatible_with(other_possibility))
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
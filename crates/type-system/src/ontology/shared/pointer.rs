@@ -0,0 +1,26 @@
+//! Helpers for building JSON Pointers to the parts of a type, as defined in [RFC 6901].
+//!
+//! [RFC 6901]: https://www.rfc-editor.org/rfc/rfc6901
+
+use std::fmt;
+
+/// Appends `segment` to the JSON Pointer `path`.
+pub(crate) fn child(path: &str, segment: impl fmt::Display) -> String {
+    let segment = segment.to_string().replace('~', "~0").replace('/', "~1");
+    format!("{path}/{segment}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_segments() {
+        assert_eq!(child("", "properties"), "/properties");
+        assert_eq!(child("/oneOf", 0), "/oneOf/0");
+        assert_eq!(
+            child("/properties", "https://example.com/~alice/"),
+            "/properties/https:~1~1example.com~1~0alice~1"
+        );
+    }
+}
@@ -8,7 +8,10 @@ use serde::{Deserialize, Serialize};
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
-use crate::uri::{BaseUri, VersionedUri};
+use crate::{
+    uri::{BaseUri, ParseTypeUriComponentsError, VersionedUri},
+    OntologyTypeKind,
+};
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -29,6 +32,20 @@ pub enum ValidationError {
     MismatchedPropertyCount { actual: usize, expected: usize },
     /// `oneOf` requires at least one element.
     EmptyOneOf,
+    /// An array requires more items than it allows.
+    InvalidItemBounds { min_items: usize, max_items: usize },
+    /// A schema has marked a link as required but does not allow any items for the link.
+    EmptyRequiredLink(VersionedUri),
+    /// The types cannot have any valid instances, as they have contradicting constraints or
+    /// require values of types which cannot have any valid instances.
+    Unsatisfiable(Vec<VersionedUri>),
+    /// The URI of a type does not follow the Block Protocol convention for type URIs.
+    UnconventionalUri(ParseTypeUriComponentsError),
+    /// The URI of a type specifies a different kind of type than the type itself.
+    MismatchedKind {
+        expected: OntologyTypeKind,
+        actual: OntologyTypeKind,
+    },
 }
 
 impl Display for ValidationError {
@@ -65,6 +82,46 @@ impl Display for ValidationError {
                 )
             }
             Self::EmptyOneOf => fmt.write_str("`\"one_of\"` must have at least one item"),
+            Self::InvalidItemBounds {
+                min_items,
+                max_items,
+            } => {
+                write!(
+                    fmt,
+                    "at least {min_items} items are required, but at most {max_items} items are \
+                     allowed"
+                )
+            }
+            Self::EmptyRequiredLink(link) => {
+                write!(
+                    fmt,
+                    "the schema has marked the \"{link}\" link as required, but doesn't allow any \
+                     items for it"
+                )
+            }
+            Self::Unsatisfiable(uris) => {
+                fmt.write_str("the following types cannot have any valid instances: ")?;
+                for (index, uri) in uris.iter().enumerate() {
+                    if index > 0 {
+                        fmt.write_str(", ")?;
+                    }
+                    write!(fmt, "\"{uri}\"")?;
+                }
+                Ok(())
+            }
+            Self::UnconventionalUri(error) => {
+                write!(
+                    fmt,
+                    "the URI does not follow the type URI convention: {error}"
+                )
+            }
+            Self::MismatchedKind { expected, actual } => {
+                write!(
+                    fmt,
+                    "the URI refers to a type of kind `{actual}`, but the type is of kind \
+                     `{expected}`"
+                )
+            }
         }
     }
 }
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::{ontology::shared::naming::UnresolvedTypeError, uri::VersionedUri};
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum GenerateDdlError {
+    #[error("could not resolve `{uri}`: {error}")]
+    UnresolvedType { uri: VersionedUri, error: String },
+    #[error("the title `{0}` cannot be turned into an identifier")]
+    InvalidTitle(String),
+}
+
+impl UnresolvedTypeError for GenerateDdlError {
+    fn unresolved_type(uri: VersionedUri, error: String) -> Self {
+        Self::UnresolvedType { uri, error }
+    }
+}
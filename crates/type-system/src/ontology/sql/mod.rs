@@ -0,0 +1,853 @@
+//! Generating relational schemas for entities.
+//!
+//! A [`DdlGenerator`] maps an [`EntityType`] to `PostgreSQL` `CREATE TABLE` statements:
+//!
+//! - The entities are stored in a table named after the entity type, which is keyed by an
+//!   `entity_id` column.
+//! - Every property whose values are primitives becomes a column, typed by the
+//!   [`DataType::json_type`] of the data types it allows. A property allowing data types of
+//!   different JSON types becomes a `JSONB` column. Columns of required properties are `NOT NULL`.
+//! - Arrays and objects are either stored as `JSONB`, or in child tables referencing the table
+//!   containing them, as configured by [`NestedValues`]. Child tables for arrays have an additional
+//!   column storing the index of the value inside of the array. A property type nested inside of
+//!   itself is stored as `JSONB` from its second occurrence on, as it would require infinitely many
+//!   child tables otherwise.
+//! - Every link becomes a join table between the `entity_id` of the source and the target entity.
+//!   Join tables for ordered links have an additional `position` column.
+//!
+//! Tables and columns are named after the titles of the types, e.g. `Contact Information` becomes
+//! `contact_information`. Names which are already used by another table or column of the same
+//! table get a numeric suffix.
+
+mod error;
+
+use std::{collections::HashSet, fmt};
+
+pub use error::GenerateDdlError;
+
+use crate::{
+    ontology::shared::naming::{identifier, unique, words, UnresolvedTypeError},
+    repository::TypeResolver,
+    uri::VersionedUri,
+    DataType, EntityType, EntityTypeReference, Object, PropertyTypeReference, PropertyValues,
+    ValueOrArray, ValueOrMaybeOrderedArray,
+};
+
+/// The `PostgreSQL` type of a [`Column`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SqlType {
+    Text,
+    DoublePrecision,
+    BigInt,
+    Integer,
+    Boolean,
+    Jsonb,
+}
+
+impl SqlType {
+    /// Returns the type used for the values of `data_type`.
+    #[must_use]
+    pub fn of(data_type: &DataType) -> Self {
+        match data_type.json_type() {
+            "string" => Self::Text,
+            "number" => Self::DoublePrecision,
+            "integer" => Self::BigInt,
+            "boolean" => Self::Boolean,
+            _ => Self::Jsonb,
+        }
+    }
+}
+
+impl fmt::Display for SqlType {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(match self {
+            Self::Text => "TEXT",
+            Self::DoublePrecision => "DOUBLE PRECISION",
+            Self::BigInt => "BIGINT",
+            Self::Integer => "INTEGER",
+            Self::Boolean => "BOOLEAN",
+            Self::Jsonb => "JSONB",
+        })
+    }
+}
+
+/// How arrays and objects inside of entities are stored.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum NestedValues {
+    /// Values are stored in a `JSONB` column.
+    #[default]
+    Jsonb,
+    /// Values are stored in a child table.
+    ChildTables,
+}
+
+/// A column of a [`Table`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    name: String,
+    sql_type: SqlType,
+    not_null: bool,
+}
+
+impl Column {
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub const fn sql_type(&self) -> SqlType {
+        self.sql_type
+    }
+
+    #[must_use]
+    pub const fn not_null(&self) -> bool {
+        self.not_null
+    }
+}
+
+/// A foreign key of a [`Table`], which references the primary key of the parent table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignKey {
+    columns: Vec<String>,
+    table: String,
+    referenced_columns: Vec<String>,
+}
+
+impl ForeignKey {
+    #[must_use]
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    #[must_use]
+    pub fn table(&self) -> &str {
+        &self.table
+    }
+
+    #[must_use]
+    pub fn referenced_columns(&self) -> &[String] {
+        &self.referenced_columns
+    }
+}
+
+/// A table created by a [`DdlGenerator`].
+///
+/// The [`Display`] implementation renders the `CREATE TABLE` statement of the table.
+///
+/// [`Display`]: fmt::Display
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    name: String,
+    columns: Vec<Column>,
+    primary_key: Vec<String>,
+    foreign_key: Option<ForeignKey>,
+}
+
+impl Table {
+    fn new(
+        name: String,
+        primary_key: &[(String, SqlType)],
+        foreign_key: Option<ForeignKey>,
+    ) -> Self {
+        let mut table = Self {
+            name,
+            columns: Vec::new(),
+            primary_key: Vec::new(),
+            foreign_key,
+        };
+        for (column, sql_type) in primary_key {
+            let column = table.add_column(column, *sql_type, true);
+            table.primary_key.push(column);
+        }
+        table
+    }
+
+    /// Adds a column named `name`, or named `name` with a numeric suffix if `name` is already
+    /// used, and returns the name of the column.
+    fn add_column(&mut self, name: &str, sql_type: SqlType, not_null: bool) -> String {
+        let mut column = name.to_owned();
+        let mut suffix = 1;
+        while self.columns.iter().any(|existing| existing.name == column) {
+            suffix += 1;
+            column = format!("{name}_{suffix}");
+        }
+        self.columns.push(Column {
+            name: column.clone(),
+            sql_type,
+            not_null,
+        });
+        column
+    }
+
+    fn key(&self) -> Vec<(String, SqlType)> {
+        self.primary_key
+            .iter()
+            .map(|key| {
+                let column = self
+                    .columns
+                    .iter()
+                    .find(|column| &column.name == key)
+                    .expect("primary key columns are columns of the table");
+                (column.name.clone(), column.sql_type)
+            })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    #[must_use]
+    pub fn primary_key(&self) -> &[String] {
+        &self.primary_key
+    }
+
+    #[must_use]
+    pub const fn foreign_key(&self) -> Option<&ForeignKey> {
+        self.foreign_key.as_ref()
+    }
+}
+
+/// Writes `identifier` as a quoted `PostgreSQL` identifier.
+fn write_identifier(fmt: &mut fmt::Formatter, identifier: &str) -> fmt::Result {
+    write!(fmt, "\"{}\"", identifier.replace('"', "\"\""))
+}
+
+fn write_identifiers(fmt: &mut fmt::Formatter, identifiers: &[String]) -> fmt::Result {
+    fmt.write_str("(")?;
+    for (index, identifier) in identifiers.iter().enumerate() {
+        if index > 0 {
+            fmt.write_str(", ")?;
+        }
+        write_identifier(fmt, identifier)?;
+    }
+    fmt.write_str(")")
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("CREATE TABLE ")?;
+        write_identifier(fmt, &self.name)?;
+        fmt.write_str(" (\n")?;
+        for column in &self.columns {
+            fmt.write_str("    ")?;
+            write_identifier(fmt, &column.name)?;
+            write!(fmt, " {}", column.sql_type)?;
+            if column.not_null {
+                fmt.write_str(" NOT NULL")?;
+            }
+            fmt.write_str(",\n")?;
+        }
+        fmt.write_str("    PRIMARY KEY ")?;
+        write_identifiers(fmt, &self.primary_key)?;
+        if let Some(foreign_key) = &self.foreign_key {
+            fmt.write_str(",\n    FOREIGN KEY ")?;
+            write_identifiers(fmt, &foreign_key.columns)?;
+            fmt.write_str(" REFERENCES ")?;
+            write_identifier(fmt, &foreign_key.table)?;
+            fmt.write_str(" ")?;
+            write_identifiers(fmt, &foreign_key.referenced_columns)?;
+            fmt.write_str(" ON DELETE CASCADE")?;
+        }
+        fmt.write_str("\n);\n")
+    }
+}
+
+/// Converts a title into an identifier, e.g. `Contact Information` into `contact_information`.
+fn to_identifier(title: &str) -> Result<String, GenerateDdlError> {
+    identifier(words(title).join("_").to_lowercase())
+        .ok_or_else(|| GenerateDdlError::InvalidTitle(title.to_owned()))
+}
+
+/// Generates `PostgreSQL` tables for entity types.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DdlGenerator {
+    nested_values: NestedValues,
+}
+
+impl DdlGenerator {
+    /// Creates a generator which stores arrays and objects as `JSONB`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn with_nested_values(mut self, nested_values: NestedValues) -> Self {
+        self.nested_values = nested_values;
+        self
+    }
+
+    /// Returns the tables storing entities of `entity_type`, ordered such that every table is
+    /// created after the tables it references.
+    ///
+    /// The property types, data types, and link types used by `entity_type` are looked up by
+    /// `resolver`.
+    ///
+    /// # Errors
+    ///
+    /// - [`GenerateDdlError::UnresolvedType`] if a type used by `entity_type` cannot be resolved
+    /// - [`GenerateDdlError::InvalidTitle`] if a table or column cannot be named after a type
+    pub fn generate<R>(
+        &self,
+        entity_type: &EntityType,
+        resolver: &R,
+    ) -> Result<Vec<Table>, GenerateDdlError>
+    where
+        R: TypeResolver,
+        R::Error: fmt::Display,
+    {
+        let mut generator = Generator {
+            config: self,
+            resolver,
+            table_names: HashSet::new(),
+            expanded_property_types: HashSet::new(),
+        };
+
+        let mut entities = Table::new(
+            unique(
+                &mut generator.table_names,
+                &to_identifier(entity_type.title())?,
+                "_",
+            ),
+            &[("entity_id".to_owned(), SqlType::Text)],
+            None,
+        );
+        let mut tables = Vec::new();
+        let mut properties: Vec<_> = entity_type.properties().iter().collect();
+        properties.sort_by_key(|(base_uri, _)| base_uri.as_str());
+        for (base_uri, property) in properties {
+            let required = entity_type.required().contains(base_uri);
+            generator.property(&mut entities, &mut tables, property, required)?;
+        }
+
+        let mut links: Vec<_> = entity_type.links().iter().collect();
+        links.sort_by_key(|(link_type, _)| link_type.to_string());
+        for (link_type, link) in links {
+            tables.push(generator.link(&entities, link_type, link)?);
+        }
+
+        tables.insert(0, entities);
+        Ok(tables)
+    }
+}
+
+/// The state of a single call to [`DdlGenerator::generate`].
+struct Generator<'a, R> {
+    config: &'a DdlGenerator,
+    resolver: &'a R,
+    table_names: HashSet<String>,
+    /// The property types containing the property which is currently added.
+    expanded_property_types: HashSet<VersionedUri>,
+}
+
+impl<R> Generator<'_, R>
+where
+    R: TypeResolver,
+    R::Error: fmt::Display,
+{
+    /// Creates a child table of `parent`, which is keyed by the primary key of `parent` and
+    /// `index` if the child table stores the values of an array.
+    fn child_table(&mut self, parent: &Table, column: &str, index: Option<String>) -> Table {
+        let mut key = parent.key();
+        let columns: Vec<_> = key.iter().map(|(column, _)| column.clone()).collect();
+        let foreign_key = ForeignKey {
+            columns: columns.clone(),
+            table: parent.name.clone(),
+            referenced_columns: columns,
+        };
+        key.extend(index.map(|index| (index, SqlType::Integer)));
+        let name = unique(
+            &mut self.table_names,
+            &format!("{}_{column}", parent.name),
+            "_",
+        );
+        Table::new(name, &key, Some(foreign_key))
+    }
+
+    /// Adds the column or child table for the property referenced by `property` to `table`.
+    ///
+    /// Child tables are added to `tables`, followed by their own child tables. A property type
+    /// which is already being added further up is added as a `JSONB` column instead.
+    fn property(
+        &mut self,
+        table: &mut Table,
+        tables: &mut Vec<Table>,
+        property: &ValueOrArray<PropertyTypeReference>,
+        required: bool,
+    ) -> Result<(), GenerateDdlError> {
+        let reference = match property {
+            ValueOrArray::Value(reference) => reference,
+            ValueOrArray::Array(array) => array.items(),
+        };
+        let property_type = self
+            .resolver
+            .resolve_property_type(reference.uri())
+            .map_err(|error| GenerateDdlError::unresolved(reference.uri(), &error))?;
+        let column = to_identifier(property_type.title())?;
+        if !self.expanded_property_types.insert(reference.uri().clone()) {
+            table.add_column(&column, SqlType::Jsonb, required);
+            return Ok(());
+        }
+
+        match (property, self.config.nested_values) {
+            (ValueOrArray::Value(_), _) => {
+                self.values(table, tables, &column, property_type.one_of(), required)?;
+            }
+            (ValueOrArray::Array(_), NestedValues::Jsonb) => {
+                table.add_column(&column, SqlType::Jsonb, required);
+            }
+            (ValueOrArray::Array(_), NestedValues::ChildTables) => {
+                let mut child = self.child_table(table, &column, Some(format!("{column}_index")));
+                let mut descendants = Vec::new();
+                self.fill(&mut child, &mut descendants, property_type.one_of())?;
+                tables.push(child);
+                tables.extend(descendants);
+            }
+        }
+        self.expanded_property_types.remove(reference.uri());
+        Ok(())
+    }
+
+    /// Adds the column or child table for a value allowing `values` to `table`.
+    fn values(
+        &mut self,
+        table: &mut Table,
+        tables: &mut Vec<Table>,
+        column: &str,
+        values: &[PropertyValues],
+        required: bool,
+    ) -> Result<(), GenerateDdlError> {
+        let index = match (values, self.config.nested_values) {
+            (
+                [
+                    PropertyValues::PropertyTypeObject(_)
+                    | PropertyValues::ArrayOfPropertyValues(_),
+                ],
+                NestedValues::Jsonb,
+            ) => {
+                table.add_column(column, SqlType::Jsonb, required);
+                return Ok(());
+            }
+            ([PropertyValues::PropertyTypeObject(_)], NestedValues::ChildTables) => None,
+            ([PropertyValues::ArrayOfPropertyValues(_)], NestedValues::ChildTables) => {
+                Some(format!("{column}_index"))
+            }
+            _ => {
+                let sql_type = self.sql_type(values)?;
+                table.add_column(column, sql_type, required);
+                return Ok(());
+            }
+        };
+
+        let mut child = self.child_table(table, column, index);
+        let mut descendants = Vec::new();
+        match values {
+            [PropertyValues::ArrayOfPropertyValues(array)] => {
+                self.fill(&mut child, &mut descendants, array.items().one_of())?;
+            }
+            _ => self.fill(&mut child, &mut descendants, values)?,
+        }
+        tables.push(child);
+        tables.extend(descendants);
+        Ok(())
+    }
+
+    /// Adds the columns for a value allowing `values` to a child table, which contains a single
+    /// value per row.
+    ///
+    /// The properties of an object are added as columns, any other value is added as a `value`
+    /// column.
+    fn fill(
+        &mut self,
+        table: &mut Table,
+        tables: &mut Vec<Table>,
+        values: &[PropertyValues],
+    ) -> Result<(), GenerateDdlError> {
+        if let [PropertyValues::PropertyTypeObject(object)] = values {
+            self.object(table, tables, object)
+        } else {
+            self.values(table, tables, "value", values, true)
+        }
+    }
+
+    fn object<const MIN: usize>(
+        &mut self,
+        table: &mut Table,
+        tables: &mut Vec<Table>,
+        object: &Object<ValueOrArray<PropertyTypeReference>, MIN>,
+    ) -> Result<(), GenerateDdlError> {
+        let mut properties: Vec<_> = object.properties().iter().collect();
+        properties.sort_by_key(|(base_uri, _)| base_uri.as_str());
+        for (base_uri, property) in properties {
+            let required = object.required().contains(base_uri);
+            self.property(table, tables, property, required)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the column type for values allowing `values`, which is `JSONB` unless all values
+    /// are data types with the same column type.
+    fn sql_type(&self, values: &[PropertyValues]) -> Result<SqlType, GenerateDdlError> {
+        let mut sql_type = None;
+        for value in values {
+            let value_type = match value {
+                PropertyValues::DataTypeReference(reference) => SqlType::of(
+                    &self
+                        .resolver
+                        .resolve_data_type(reference.uri())
+                        .map_err(|error| GenerateDdlError::unresolved(reference.uri(), &error))?,
+                ),
+                _ => SqlType::Jsonb,
+            };
+            if sql_type.map_or(false, |sql_type| sql_type != value_type) {
+                return Ok(SqlType::Jsonb);
+            }
+            sql_type = Some(value_type);
+        }
+        Ok(sql_type.unwrap_or(SqlType::Jsonb))
+    }
+
+    /// Creates the join table for the link of the type `link_type`.
+    fn link(
+        &mut self,
+        entities: &Table,
+        link_type: &VersionedUri,
+        link: &ValueOrMaybeOrderedArray<EntityTypeReference>,
+    ) -> Result<Table, GenerateDdlError> {
+        let title = self
+            .resolver
+            .resolve_link_type(link_type)
+            .map_err(|error| GenerateDdlError::unresolved(link_type, &error))?
+            .title()
+            .to_owned();
+
+        let mut key = vec![("source_id".to_owned(), SqlType::Text)];
+        match link {
+            ValueOrMaybeOrderedArray::Value(_) => {}
+            ValueOrMaybeOrderedArray::Array(array) if array.ordered() => {
+                key.push(("position".to_owned(), SqlType::Integer));
+            }
+            ValueOrMaybeOrderedArray::Array(_) => {
+                key.push(("target_id".to_owned(), SqlType::Text));
+            }
+        }
+        let name = unique(
+            &mut self.table_names,
+            &format!("{}_{}", entities.name, to_identifier(&title)?),
+            "_",
+        );
+        let mut table = Table::new(
+            name,
+            &key,
+            Some(ForeignKey {
+                columns: vec!["source_id".to_owned()],
+                table: entities.name.clone(),
+                referenced_columns: entities.primary_key.clone(),
+            }),
+        );
+        if !table.primary_key.iter().any(|column| column == "target_id") {
+            table.add_column("target_id", SqlType::Text, true);
+        }
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        repository::{
+            fixtures::{self, store},
+            FsTypeRepository,
+        },
+        test_data, LinkType, PropertyType,
+    };
+
+    fn repository(directory: &tempfile::TempDir) -> FsTypeRepository {
+        let repository = fixtures::repository(directory);
+        for property_type in [
+            json!({
+                "kind": "propertyType",
+                "$id": "https://blockprotocol.org/@alice/types/property-type/street/v/1",
+                "title": "Street",
+                "pluralTitle": "Streets",
+                "oneOf": [{ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" }]
+            }),
+            json!({
+                "kind": "propertyType",
+                "$id": "https://blockprotocol.org/@alice/types/property-type/address/v/1",
+                "title": "Address",
+                "pluralTitle": "Addresses",
+                "oneOf": [{
+                    "type": "object",
+                    "properties": {
+                        "https://blockprotocol.org/@alice/types/property-type/street/": {
+                            "$ref": "https://blockprotocol.org/@alice/types/property-type/street/v/1"
+                        },
+                        "https://blockprotocol.org/@alice/types/property-type/name/": {
+                            "type": "array",
+                            "items": { "$ref": "https://blockprotocol.org/@alice/types/property-type/name/v/1" }
+                        }
+                    },
+                    "required": ["https://blockprotocol.org/@alice/types/property-type/street/"]
+                }]
+            }),
+            json!({
+                "kind": "propertyType",
+                "$id": "https://blockprotocol.org/@alice/types/property-type/identifier/v/1",
+                "title": "Identifier",
+                "pluralTitle": "Identifiers",
+                "oneOf": [
+                    { "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" },
+                    { "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1" }
+                ]
+            }),
+        ] {
+            store(
+                &repository,
+                &PropertyType::try_from(property_type).expect("invalid type"),
+            );
+        }
+        store(
+            &repository,
+            &LinkType::try_from(json!({
+                "kind": "linkType",
+                "$id": "https://blockprotocol.org/@alice/types/link-type/contains/v/1",
+                "title": "Contains",
+                "pluralTitle": "Contains",
+                "description": "Has as a part"
+            }))
+            .expect("invalid type"),
+        );
+        repository
+    }
+
+    fn person() -> EntityType {
+        EntityType::try_from(json!({
+            "kind": "entityType",
+            "$id": "https://blockprotocol.org/@alice/types/entity-type/person/v/1",
+            "type": "object",
+            "title": "Person",
+            "pluralTitle": "People",
+            "properties": {
+                "https://blockprotocol.org/@alice/types/property-type/name/": {
+                    "type": "array",
+                    "items": { "$ref": "https://blockprotocol.org/@alice/types/property-type/name/v/1" },
+                    "minItems": 1
+                },
+                "https://blockprotocol.org/@alice/types/property-type/age/": {
+                    "$ref": "https://blockprotocol.org/@alice/types/property-type/age/v/1"
+                },
+                "https://blockprotocol.org/@alice/types/property-type/address/": {
+                    "$ref": "https://blockprotocol.org/@alice/types/property-type/address/v/1"
+                },
+                "https://blockprotocol.org/@alice/types/property-type/identifier/": {
+                    "$ref": "https://blockprotocol.org/@alice/types/property-type/identifier/v/1"
+                }
+            },
+            "required": ["https://blockprotocol.org/@alice/types/property-type/age/"],
+            "links": {
+                "https://blockprotocol.org/@alice/types/link-type/owns/v/2": {
+                    "type": "array",
+                    "items": { "$ref": "https://blockprotocol.org/@alice/types/entity-type/book/v/1" },
+                    "ordered": false
+                },
+                "https://blockprotocol.org/@alice/types/link-type/contains/v/1": {
+                    "type": "array",
+                    "items": { "$ref": "https://blockprotocol.org/@alice/types/entity-type/song/v/1" },
+                    "ordered": true
+                },
+                "https://blockprotocol.org/@alice/types/link-type/submitted-by/v/1": {
+                    "$ref": "https://blockprotocol.org/@alice/types/entity-type/person/v/1"
+                }
+            }
+        }))
+        .expect("invalid entity type")
+    }
+
+    fn render(tables: &[Table]) -> String {
+        tables.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn jsonb() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let tables = DdlGenerator::new()
+            .generate(&person(), &repository(&directory))
+            .expect("failed to generate tables");
+
+        assert_eq!(
+            render(&tables),
+            r#"CREATE TABLE "person" (
+    "entity_id" TEXT NOT NULL,
+    "address" JSONB,
+    "age" DOUBLE PRECISION NOT NULL,
+    "identifier" JSONB,
+    "name" JSONB,
+    PRIMARY KEY ("entity_id")
+);
+CREATE TABLE "person_contains" (
+    "source_id" TEXT NOT NULL,
+    "position" INTEGER NOT NULL,
+    "target_id" TEXT NOT NULL,
+    PRIMARY KEY ("source_id", "position"),
+    FOREIGN KEY ("source_id") REFERENCES "person" ("entity_id") ON DELETE CASCADE
+);
+CREATE TABLE "person_owns" (
+    "source_id" TEXT NOT NULL,
+    "target_id" TEXT NOT NULL,
+    PRIMARY KEY ("source_id", "target_id"),
+    FOREIGN KEY ("source_id") REFERENCES "person" ("entity_id") ON DELETE CASCADE
+);
+CREATE TABLE "person_submitted_by" (
+    "source_id" TEXT NOT NULL,
+    "target_id" TEXT NOT NULL,
+    PRIMARY KEY ("source_id"),
+    FOREIGN KEY ("source_id") REFERENCES "person" ("entity_id") ON DELETE CASCADE
+);
+"#
+        );
+    }
+
+    #[test]
+    fn child_tables() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let tables = DdlGenerator::new()
+            .with_nested_values(NestedValues::ChildTables)
+            .generate(&person(), &repository(&directory))
+            .expect("failed to generate tables");
+
+        let names: Vec<_> = tables.iter().map(Table::name).collect();
+        assert_eq!(names, [
+            "person",
+            "person_address",
+            "person_address_name",
+            "person_name",
+            "person_contains",
+            "person_owns",
+            "person_submitted_by"
+        ]);
+        assert_eq!(
+            render(&tables[..4]),
+            r#"CREATE TABLE "person" (
+    "entity_id" TEXT NOT NULL,
+    "age" DOUBLE PRECISION NOT NULL,
+    "identifier" JSONB,
+    PRIMARY KEY ("entity_id")
+);
+CREATE TABLE "person_address" (
+    "entity_id" TEXT NOT NULL,
+    "street" TEXT NOT NULL,
+    PRIMARY KEY ("entity_id"),
+    FOREIGN KEY ("entity_id") REFERENCES "person" ("entity_id") ON DELETE CASCADE
+);
+CREATE TABLE "person_address_name" (
+    "entity_id" TEXT NOT NULL,
+    "name_index" INTEGER NOT NULL,
+    "value" TEXT NOT NULL,
+    PRIMARY KEY ("entity_id", "name_index"),
+    FOREIGN KEY ("entity_id") REFERENCES "person_address" ("entity_id") ON DELETE CASCADE
+);
+CREATE TABLE "person_name" (
+    "entity_id" TEXT NOT NULL,
+    "name_index" INTEGER NOT NULL,
+    "value" TEXT NOT NULL,
+    PRIMARY KEY ("entity_id", "name_index"),
+    FOREIGN KEY ("entity_id") REFERENCES "person" ("entity_id") ON DELETE CASCADE
+);
+"#
+        );
+    }
+
+    #[test]
+    fn recursive_property_types() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let tree = EntityType::from_str(test_data::entity_type::TREE_V1).expect("invalid type");
+        let tables = DdlGenerator::new()
+            .with_nested_values(NestedValues::ChildTables)
+            .generate(&tree, &repository(&directory))
+            .expect("failed to generate tables");
+
+        assert_eq!(
+            render(&tables),
+            r#"CREATE TABLE "tree" (
+    "entity_id" TEXT NOT NULL,
+    PRIMARY KEY ("entity_id")
+);
+CREATE TABLE "tree_tree_node" (
+    "entity_id" TEXT NOT NULL,
+    "name" TEXT NOT NULL,
+    "tree_node" JSONB,
+    PRIMARY KEY ("entity_id"),
+    FOREIGN KEY ("entity_id") REFERENCES "tree" ("entity_id") ON DELETE CASCADE
+);
+"#
+        );
+    }
+
+    #[test]
+    fn table_name_collisions() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = repository(&directory);
+        store(
+            &repository,
+            &PropertyType::try_from(json!({
+                "kind": "propertyType",
+                "$id": "https://blockprotocol.org/@alice/types/property-type/submitted-by/v/1",
+                "title": "Submitted By",
+                "pluralTitle": "Submitted By",
+                "oneOf": [{ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" }]
+            }))
+            .expect("invalid type"),
+        );
+        let song = EntityType::try_from(json!({
+            "kind": "entityType",
+            "$id": "https://blockprotocol.org/@alice/types/entity-type/song/v/1",
+            "type": "object",
+            "title": "Song",
+            "pluralTitle": "Songs",
+            "properties": {
+                "https://blockprotocol.org/@alice/types/property-type/submitted-by/": {
+                    "type": "array",
+                    "items": { "$ref": "https://blockprotocol.org/@alice/types/property-type/submitted-by/v/1" }
+                }
+            },
+            "links": {
+                "https://blockprotocol.org/@alice/types/link-type/submitted-by/v/1": {
+                    "$ref": "https://blockprotocol.org/@alice/types/entity-type/person/v/1"
+                }
+            }
+        }))
+        .expect("invalid entity type");
+
+        let tables = DdlGenerator::new()
+            .with_nested_values(NestedValues::ChildTables)
+            .generate(&song, &repository)
+            .expect("failed to generate tables");
+
+        let names: Vec<_> = tables.iter().map(Table::name).collect();
+        assert_eq!(names, ["song", "song_submitted_by", "song_submitted_by_2"]);
+    }
+
+    #[test]
+    fn unresolved_types() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = FsTypeRepository::new(directory.path());
+        assert!(matches!(
+            DdlGenerator::new().generate(&person(), &repository),
+            Err(GenerateDdlError::UnresolvedType { .. })
+        ));
+    }
+}
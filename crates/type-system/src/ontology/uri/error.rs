@@ -4,7 +4,7 @@ use thiserror::Error;
 use tsify::Tsify;
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Error)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Error)]
 #[serde(tag = "reason", content = "inner")]
 pub enum ParseBaseUriError {
     #[error("URI is missing a trailing slash")]
@@ -34,3 +34,27 @@ pub enum ParseVersionedUriError {
     #[error("invalid json: {0}")]
     InvalidJson(String),
 }
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum ParseTypeUriComponentsError {
+    #[error("URI is missing a host")]
+    MissingHost,
+    #[error("URI is missing the `@namespace` segment")]
+    MissingNamespace,
+    #[error("URI is missing the `types` segment")]
+    MissingTypesSegment,
+    #[error("URI is missing the kind of the type")]
+    MissingKind,
+    #[error("unknown kind of type: `{0}`")]
+    UnknownKind(String),
+    #[error("URI is missing the slug of the type")]
+    MissingSlug,
+    #[error("additional content after the slug of the type")]
+    AdditionalEndContent,
+    #[error("invalid base uri: {0}")]
+    InvalidBaseUri(ParseBaseUriError),
+    #[error("`{0}` cannot be used as a component of a type URI")]
+    InvalidComponent(String),
+}
@@ -3,13 +3,15 @@ mod error;
 mod wasm;
 use std::{fmt, result::Result, str::FromStr, sync::LazyLock};
 
-pub use error::{ParseBaseUriError, ParseVersionedUriError};
+pub use error::{ParseBaseUriError, ParseTypeUriComponentsError, ParseVersionedUriError};
 use regex::Regex;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 use url::Url;
 
+use crate::OntologyTypeKind;
+
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct BaseUri(String);
@@ -61,6 +63,15 @@ impl BaseUri {
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
+
+    /// Decomposes the URI into its [`TypeUriComponents`].
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseTypeUriComponentsError`] if the URI does not follow the Block Protocol convention
+    pub fn components(&self) -> Result<TypeUriComponents, ParseTypeUriComponentsError> {
+        TypeUriComponents::from_base_uri(self)
+    }
 }
 
 impl Serialize for BaseUri {
@@ -81,6 +92,173 @@ impl<'de> Deserialize<'de> for BaseUri {
     }
 }
 
+/// The components of a [`BaseUri`] which follows the Block Protocol convention for type URIs:
+///
+/// `https://<host>/@<namespace>/types/<kind>/<slug>/`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeUriComponents {
+    scheme: String,
+    host: String,
+    namespace: String,
+    kind: OntologyTypeKind,
+    slug: String,
+}
+
+impl TypeUriComponents {
+    /// Creates new `TypeUriComponents` for a type hosted at `https://<host>/`.
+    ///
+    /// The `namespace` is specified without the leading `@`. The `host` may include a port.
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseTypeUriComponentsError::InvalidBaseUri`] if the components don't form a valid URI
+    /// - [`ParseTypeUriComponentsError::InvalidComponent`] if a component would change the
+    ///   structure of the URI, e.g. because it contains a `/`
+    pub fn new(
+        host: String,
+        namespace: String,
+        kind: OntologyTypeKind,
+        slug: String,
+    ) -> Result<Self, ParseTypeUriComponentsError> {
+        let components = Self {
+            scheme: "https".to_owned(),
+            host,
+            namespace,
+            kind,
+            slug,
+        };
+
+        let base_uri = BaseUri::new(components.to_uri_string())
+            .map_err(ParseTypeUriComponentsError::InvalidBaseUri)?;
+        let parsed = Self::from_base_uri(&base_uri)?;
+
+        for (component, parsed_component) in [
+            (&components.host, &parsed.host),
+            (&components.namespace, &parsed.namespace),
+            (&components.slug, &parsed.slug),
+        ] {
+            if component != parsed_component {
+                return Err(ParseTypeUriComponentsError::InvalidComponent(
+                    component.clone(),
+                ));
+            }
+        }
+
+        Ok(components)
+    }
+
+    /// Decomposes a [`BaseUri`] into its components.
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseTypeUriComponentsError`] if the URI does not follow the Block Protocol convention
+    pub fn from_base_uri(base_uri: &BaseUri) -> Result<Self, ParseTypeUriComponentsError> {
+        let url = base_uri.to_url();
+        if url.query().is_some() || url.fragment().is_some() {
+            return Err(ParseTypeUriComponentsError::AdditionalEndContent);
+        }
+
+        let host = url
+            .host_str()
+            .ok_or(ParseTypeUriComponentsError::MissingHost)?;
+        let host = url
+            .port()
+            .map_or_else(|| host.to_owned(), |port| format!("{host}:{port}"));
+
+        // Only URLs without a host cannot be a base, which is already rejected above
+        let mut segments = url
+            .path_segments()
+            .ok_or(ParseTypeUriComponentsError::MissingHost)?;
+
+        let namespace = segments
+            .next()
+            .and_then(|segment| segment.strip_prefix('@'))
+            .filter(|namespace| !namespace.is_empty())
+            .ok_or(ParseTypeUriComponentsError::MissingNamespace)?;
+
+        if segments.next() != Some("types") {
+            return Err(ParseTypeUriComponentsError::MissingTypesSegment);
+        }
+
+        let kind = segments
+            .next()
+            .filter(|segment| !segment.is_empty())
+            .ok_or(ParseTypeUriComponentsError::MissingKind)?;
+        let kind = OntologyTypeKind::from_uri_segment(kind)
+            .ok_or_else(|| ParseTypeUriComponentsError::UnknownKind(kind.to_owned()))?;
+
+        let slug = segments
+            .next()
+            .filter(|segment| !segment.is_empty())
+            .ok_or(ParseTypeUriComponentsError::MissingSlug)?;
+
+        // The trailing slash of a base URI results in an empty last segment
+        if segments.next() != Some("") || segments.next().is_some() {
+            return Err(ParseTypeUriComponentsError::AdditionalEndContent);
+        }
+
+        Ok(Self {
+            scheme: url.scheme().to_owned(),
+            host,
+            namespace: namespace.to_owned(),
+            kind,
+            slug: slug.to_owned(),
+        })
+    }
+
+    #[must_use]
+    pub fn scheme(&self) -> &str {
+        &self.scheme
+    }
+
+    /// Returns the host of the URI, including the port if one was specified.
+    #[must_use]
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Returns the namespace owning the type without the leading `@`.
+    #[must_use]
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    #[must_use]
+    pub const fn kind(&self) -> OntologyTypeKind {
+        self.kind
+    }
+
+    #[must_use]
+    pub fn slug(&self) -> &str {
+        &self.slug
+    }
+
+    /// Composes the components into a [`BaseUri`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the components do not form a valid [`BaseUri`], which cannot happen for components
+    /// created with [`new`] or [`from_base_uri`].
+    ///
+    /// [`new`]: Self::new
+    /// [`from_base_uri`]: Self::from_base_uri
+    #[must_use]
+    pub fn to_base_uri(&self) -> BaseUri {
+        BaseUri::new(self.to_uri_string()).expect("type URI components should form a valid URI")
+    }
+
+    fn to_uri_string(&self) -> String {
+        format!(
+            "{}://{}/@{}/types/{}/{}/",
+            self.scheme,
+            self.host,
+            self.namespace,
+            self.kind.uri_segment(),
+            self.slug
+        )
+    }
+}
+
 // TODO: can we impl Tsify to turn this into a type: template string
 //  if we can then we should delete wasm::VersionedUriPatch
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -114,6 +292,7 @@ impl VersionedUri {
         let mut url = self.base_uri.to_url();
         url.path_segments_mut()
             .expect("invalid Base URI, we should have caught an invalid base already")
+            .pop_if_empty()
             .extend(["v", &self.version.to_string()]);
 
         url
@@ -185,6 +364,118 @@ mod tests {
 
     // TODO: add some unit tests for base URI
 
+    #[test]
+    fn components() {
+        let base_uri = BaseUri::new(
+            "https://blockprotocol.org/@alice/types/property-type/favorite-quote/".to_owned(),
+        )
+        .expect("invalid base URI");
+        let components = base_uri.components().expect("failed to decompose URI");
+
+        assert_eq!(components.host(), "blockprotocol.org");
+        assert_eq!(components.namespace(), "alice");
+        assert_eq!(components.kind(), OntologyTypeKind::PropertyType);
+        assert_eq!(components.slug(), "favorite-quote");
+        assert_eq!(components.to_base_uri(), base_uri);
+    }
+
+    #[test]
+    fn components_with_port() {
+        let base_uri =
+            BaseUri::new("http://localhost:3000/@hash/types/entity-type/block/".to_owned())
+                .expect("invalid base URI");
+        let components = base_uri.components().expect("failed to decompose URI");
+
+        assert_eq!(components.scheme(), "http");
+        assert_eq!(components.host(), "localhost:3000");
+        assert_eq!(components.to_base_uri(), base_uri);
+    }
+
+    #[test]
+    fn unconventional_components() {
+        let components = |uri: &str| {
+            BaseUri::new(uri.to_owned())
+                .expect("invalid base URI")
+                .components()
+        };
+
+        assert_eq!(
+            components("https://example.com/alice/types/entity-type/book/"),
+            Err(ParseTypeUriComponentsError::MissingNamespace)
+        );
+        assert_eq!(
+            components("https://example.com/@alice/schemas/entity-type/book/"),
+            Err(ParseTypeUriComponentsError::MissingTypesSegment)
+        );
+        assert_eq!(
+            components("https://example.com/@alice/types/"),
+            Err(ParseTypeUriComponentsError::MissingKind)
+        );
+        assert_eq!(
+            components("https://example.com/@alice/types/block-type/book/"),
+            Err(ParseTypeUriComponentsError::UnknownKind(
+                "block-type".to_owned()
+            ))
+        );
+        assert_eq!(
+            components("https://example.com/@alice/types/entity-type/"),
+            Err(ParseTypeUriComponentsError::MissingSlug)
+        );
+        assert_eq!(
+            components("https://example.com/@alice/types/entity-type/book/chapter/"),
+            Err(ParseTypeUriComponentsError::AdditionalEndContent)
+        );
+    }
+
+    #[test]
+    fn components_from_parts() {
+        let components = TypeUriComponents::new(
+            "types.example.com".to_owned(),
+            "alice".to_owned(),
+            OntologyTypeKind::LinkType,
+            "written-by".to_owned(),
+        )
+        .expect("invalid components");
+
+        assert_eq!(
+            components.to_base_uri().as_str(),
+            "https://types.example.com/@alice/types/link-type/written-by/"
+        );
+
+        assert_eq!(
+            TypeUriComponents::new(
+                "types.example.com".to_owned(),
+                "alice".to_owned(),
+                OntologyTypeKind::LinkType,
+                "written/by".to_owned(),
+            ),
+            Err(ParseTypeUriComponentsError::AdditionalEndContent)
+        );
+        assert_eq!(
+            TypeUriComponents::new(
+                "types.example.com".to_owned(),
+                "alice".to_owned(),
+                OntologyTypeKind::LinkType,
+                "written by".to_owned(),
+            ),
+            Err(ParseTypeUriComponentsError::InvalidComponent(
+                "written by".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn versioned_uri_to_url() {
+        let uri = "https://blockprotocol.org/@alice/types/data-type/text/v/1";
+        assert_eq!(
+            VersionedUri::from_str(uri)
+                .expect("invalid versioned URI")
+                .to_url()
+                .as_str(),
+            uri
+        );
+    }
+
     #[test]
     fn versioned_uri() {
         let input_str = "https://blockprotocol.org/@blockprotocol/types/data-type/empty-list/v/1";
@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+use serde_json::{json, Map, Value};
+
+use super::{name_part, WireSchema, WireType};
+use crate::ontology::shared::naming::unique;
+
+struct Renderer<'a> {
+    schema: &'a WireSchema,
+    /// Records can only be defined once and are referenced by name afterwards.
+    defined: HashSet<usize>,
+    names: HashSet<String>,
+}
+
+impl Renderer<'_> {
+    fn record(&mut self, index: usize) -> Value {
+        let message = &self.schema.messages[index];
+        if !self.defined.insert(index) {
+            return json!(message.name);
+        }
+
+        let fields: Vec<_> = message
+            .fields
+            .iter()
+            .map(|field| {
+                let mut avro_type = self.avro_type(&message.name, &field.name, &field.wire_type);
+                let mut definition = Map::new();
+                definition.insert("name".to_owned(), json!(field.name));
+                if !field.required && avro_type != "null" {
+                    // The default of a union has to be of the type of its first member
+                    avro_type = match avro_type {
+                        Value::Array(mut members) => {
+                            members.retain(|member| member != "null");
+                            members.insert(0, json!("null"));
+                            Value::Array(members)
+                        }
+                        avro_type => json!(["null", avro_type]),
+                    };
+                    definition.insert("default".to_owned(), Value::Null);
+                }
+                definition.insert("type".to_owned(), avro_type);
+                definition.insert("baseUri".to_owned(), json!(field.key));
+                Value::Object(definition)
+            })
+            .collect();
+
+        let mut record = Map::new();
+        record.insert("type".to_owned(), json!("record"));
+        record.insert("name".to_owned(), json!(message.name));
+        if let Some(description) = &message.description {
+            record.insert("doc".to_owned(), json!(description));
+        }
+        record.insert("fields".to_owned(), json!(fields));
+        Value::Object(record)
+    }
+
+    fn avro_type(&mut self, parent: &str, name: &str, wire_type: &WireType) -> Value {
+        match wire_type {
+            WireType::String => json!("string"),
+            WireType::Double => json!("double"),
+            WireType::Long => json!("long"),
+            WireType::Boolean => json!("boolean"),
+            WireType::Null => json!("null"),
+            WireType::Message(index) => self.record(*index),
+            WireType::List(items) => json!({
+                "type": "array",
+                "items": self.avro_type(parent, name, items)
+            }),
+            WireType::Union(variants) => {
+                // A union must not contain more than one array
+                let lists = variants
+                    .iter()
+                    .filter(|variant| matches!(variant.wire_type, WireType::List(_)))
+                    .count();
+                let members: Vec<_> = variants
+                    .iter()
+                    .map(|variant| {
+                        let avro_type = self.avro_type(parent, name, &variant.wire_type);
+                        if lists > 1 && matches!(variant.wire_type, WireType::List(_)) {
+                            let wrapper = unique(
+                                &mut self.names,
+                                &format!("{parent}{}{}", name_part(name), name_part(&variant.name)),
+                                "",
+                            );
+                            json!({
+                                "type": "record",
+                                "name": wrapper,
+                                "fields": [{ "name": "values", "type": avro_type }]
+                            })
+                        } else {
+                            avro_type
+                        }
+                    })
+                    .collect();
+                json!(members)
+            }
+        }
+    }
+}
+
+impl WireSchema {
+    /// Renders the schema as Avro record schema.
+    ///
+    /// Every field carries the base URI of the property or link it was generated from in a
+    /// `baseUri` attribute. Fields of properties and links which are not required are nullable
+    /// and default to `null`.
+    #[must_use]
+    pub fn to_avro(&self) -> Value {
+        Renderer {
+            schema: self,
+            defined: HashSet::new(),
+            names: self
+                .messages
+                .iter()
+                .map(|message| message.name.clone())
+                .collect(),
+        }
+        .record(0)
+    }
+}
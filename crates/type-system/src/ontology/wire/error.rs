@@ -0,0 +1,40 @@
+use std::{io, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::{ontology::shared::naming::UnresolvedTypeError, uri::VersionedUri};
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum ExportSchemaError {
+    #[error("could not resolve `{uri}`: {error}")]
+    UnresolvedType { uri: VersionedUri, error: String },
+    #[error("the title `{0}` cannot be turned into a name")]
+    InvalidTitle(String),
+}
+
+impl UnresolvedTypeError for ExportSchemaError {
+    fn unresolved_type(uri: VersionedUri, error: String) -> Self {
+        Self::UnresolvedType { uri, error }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum FieldNumbersError {
+    #[error("could not access `{}`: {error}", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        error: io::Error,
+    },
+    #[error("`{}` is not a valid field number mapping: {error}", .path.display())]
+    InvalidMapping {
+        path: PathBuf,
+        #[source]
+        error: serde_json::Error,
+    },
+}
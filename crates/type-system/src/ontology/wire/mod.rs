@@ -0,0 +1,712 @@
+//! Generating schemas for binary serialization formats.
+//!
+//! A [`WireSchema`] describes how the entities of an [`EntityType`] are serialized and can be
+//! rendered as Protobuf messages ([`WireSchema::to_protobuf`]) and as Avro record schemas
+//! ([`WireSchema::to_avro`]):
+//!
+//! - The entity type, and every object inside of it, becomes a message or record. Messages and
+//!   records are named after the titles of the types, e.g. `Contact Information` becomes
+//!   `ContactInformation`, and fields are named in `snake_case`.
+//! - Data types are mapped by their [`DataType::json_type`]. Arrays become repeated fields, and
+//!   properties with multiple variants become a `oneof` or a union.
+//! - Every link becomes a field containing the ID of the target entity, or a list of IDs.
+//!
+//! Protobuf identifies fields by their number. To keep new versions of an entity type
+//! wire-compatible, the numbers are taken from [`FieldNumbers`], which map the base URI of each
+//! property and link to a number, and which are meant to be persisted alongside the generated
+//! schema. Fields which are no longer used are reserved.
+//!
+//! Not every shape of [`PropertyValues`] can be represented exactly, e.g. constraints on arrays
+//! are not enforced by either format. These are reported as [`Approximation`]s.
+
+mod avro;
+mod error;
+mod protobuf;
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+pub use error::{ExportSchemaError, FieldNumbersError};
+pub use protobuf::FieldNumbers;
+use serde::Serialize;
+
+use crate::{
+    ontology::shared::naming::{identifier, pascal_case, unique, words, UnresolvedTypeError},
+    repository::TypeResolver,
+    uri::{BaseUri, VersionedUri},
+    Array, DataType, EntityType, PropertyTypeReference, PropertyValues, ValueOrArray,
+    ValueOrMaybeOrderedArray,
+};
+
+/// The reason why a value cannot be represented exactly.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ApproximationReason {
+    /// The value is an arbitrary JSON value, which is serialized as JSON string.
+    ArbitraryJson,
+    /// The data type of the value has constraints, which are not enforced.
+    ConstrainedDataType,
+    /// The array has a minimum or maximum number of items, which is not enforced.
+    ArrayBounds,
+    /// Multiple variants of the value are serialized the same way and cannot be distinguished.
+    IndistinguishableVariants,
+}
+
+impl fmt::Display for ApproximationReason {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(match self {
+            Self::ArbitraryJson => "is serialized as JSON string",
+            Self::ConstrainedDataType => "has constraints which are not enforced",
+            Self::ArrayBounds => "has a number of items which is not enforced",
+            Self::IndistinguishableVariants => "has variants which cannot be distinguished",
+        })
+    }
+}
+
+/// A value which cannot be represented exactly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Approximation {
+    path: String,
+    reason: ApproximationReason,
+}
+
+impl Approximation {
+    /// The path of the value, consisting of the name of the entity type and the names of the
+    /// fields, e.g. `Person.address.street`.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    #[must_use]
+    pub const fn reason(&self) -> ApproximationReason {
+        self.reason
+    }
+}
+
+impl fmt::Display for Approximation {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "`{}` {}", self.path, self.reason)
+    }
+}
+
+/// Converts a title into the name of a message, e.g. `Contact Information` into
+/// `ContactInformation`.
+fn message_name(title: &str) -> Result<String, ExportSchemaError> {
+    identifier(pascal_case(&words(title)))
+        .ok_or_else(|| ExportSchemaError::InvalidTitle(title.to_owned()))
+}
+
+/// Converts a title into the name of a field, e.g. `Contact Information` into
+/// `contact_information`.
+fn field_name(title: &str) -> Result<String, ExportSchemaError> {
+    let mut name = words(title).join("_").to_lowercase();
+    name.retain(|character| character.is_ascii_alphanumeric() || character == '_');
+    identifier(name).ok_or_else(|| ExportSchemaError::InvalidTitle(title.to_owned()))
+}
+
+/// Converts the name of a field into a part of the name of a wrapper message.
+fn name_part(name: &str) -> String {
+    message_name(name).unwrap_or_else(|_| "Value".to_owned())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum WireType {
+    String,
+    Double,
+    Long,
+    Boolean,
+    Null,
+    /// The message at the index inside of [`WireSchema::messages`].
+    Message(usize),
+    List(Box<WireType>),
+    /// Variants are never unions themselves.
+    Union(Vec<Variant>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Variant {
+    /// Identifies the variant across versions of the type.
+    key: String,
+    name: String,
+    wire_type: WireType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Field {
+    /// Identifies the field across versions of the type.
+    key: String,
+    name: String,
+    wire_type: WireType,
+    required: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Message {
+    /// Identifies the message across versions of the type.
+    key: String,
+    name: String,
+    description: Option<String>,
+    fields: Vec<Field>,
+}
+
+/// The serialization of the entities of an [`EntityType`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WireSchema {
+    /// The first message is the message of the entity type.
+    messages: Vec<Message>,
+    approximations: Vec<Approximation>,
+}
+
+impl WireSchema {
+    /// Creates the schema for `entity_type`, whose referenced types are resolved by `resolver`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ExportSchemaError::UnresolvedType`] if a type used by `entity_type` cannot be resolved
+    /// - [`ExportSchemaError::InvalidTitle`] if a message or field cannot be named after a type
+    pub fn new<R>(entity_type: &EntityType, resolver: &R) -> Result<Self, ExportSchemaError>
+    where
+        R: TypeResolver,
+        R::Error: fmt::Display,
+    {
+        let mut builder = Builder {
+            resolver,
+            messages: Vec::new(),
+            names: HashSet::new(),
+            property_type_messages: HashMap::new(),
+            approximations: Vec::new(),
+        };
+
+        let name = unique(&mut builder.names, &message_name(entity_type.title())?, "");
+        builder.messages.push(Message {
+            key: entity_type.id().base_uri().to_string(),
+            name: name.clone(),
+            description: entity_type.description().map(ToOwned::to_owned),
+            fields: Vec::new(),
+        });
+
+        let mut field_names = HashSet::new();
+        let mut fields = builder.fields(
+            &name,
+            &mut field_names,
+            entity_type.properties().iter(),
+            entity_type.required(),
+        )?;
+
+        let mut links: Vec<_> = entity_type.links().iter().collect();
+        links.sort_by_key(|(link_type, _)| link_type.to_string());
+        for (link_type, link) in links {
+            let title = builder
+                .resolver
+                .resolve_link_type(link_type)
+                .map_err(|error| ExportSchemaError::unresolved(link_type, &error))?
+                .title()
+                .to_owned();
+            let field = unique(&mut field_names, &field_name(&title)?, "_");
+            let wire_type = match link {
+                ValueOrMaybeOrderedArray::Value(_) => WireType::String,
+                ValueOrMaybeOrderedArray::Array(array) => {
+                    builder.bounds(&format!("{name}.{field}"), array.array());
+                    WireType::List(Box::new(WireType::String))
+                }
+            };
+            fields.push(Field {
+                key: link_type.base_uri().to_string(),
+                name: field,
+                wire_type,
+                required: entity_type.required_links().contains(link_type),
+            });
+        }
+        builder.messages[0].fields = fields;
+
+        Ok(Self {
+            messages: builder.messages,
+            approximations: builder.approximations,
+        })
+    }
+
+    /// The values which cannot be represented exactly.
+    #[must_use]
+    pub fn approximations(&self) -> &[Approximation] {
+        &self.approximations
+    }
+}
+
+struct Builder<'a, R> {
+    resolver: &'a R,
+    messages: Vec<Message>,
+    /// The names of all messages.
+    names: HashSet<String>,
+    /// The messages of the objects inside of property types, keyed by the property type and the
+    /// key of the message, so every object is a single message even if it contains itself.
+    property_type_messages: HashMap<(VersionedUri, String), usize>,
+    approximations: Vec<Approximation>,
+}
+
+impl<R> Builder<'_, R>
+where
+    R: TypeResolver,
+    R::Error: fmt::Display,
+{
+    fn approximate(&mut self, path: &str, reason: ApproximationReason) {
+        self.approximations.push(Approximation {
+            path: path.to_owned(),
+            reason,
+        });
+    }
+
+    fn bounds<T>(&mut self, path: &str, array: &Array<T>) {
+        if array.min_items().is_some() || array.max_items().is_some() {
+            self.approximate(path, ApproximationReason::ArrayBounds);
+        }
+    }
+
+    fn fields<'p>(
+        &mut self,
+        path: &str,
+        names: &mut HashSet<String>,
+        properties: impl Iterator<Item = (&'p BaseUri, &'p ValueOrArray<PropertyTypeReference>)>,
+        required: &[BaseUri],
+    ) -> Result<Vec<Field>, ExportSchemaError> {
+        let mut properties: Vec<_> = properties.collect();
+        properties.sort_by_key(|(base_uri, _)| base_uri.as_str());
+
+        let mut fields = Vec::new();
+        for (base_uri, property) in properties {
+            let reference = match property {
+                ValueOrArray::Value(reference) => reference,
+                ValueOrArray::Array(array) => array.items(),
+            };
+            let property_type = self
+                .resolver
+                .resolve_property_type(reference.uri())
+                .map_err(|error| ExportSchemaError::unresolved(reference.uri(), &error))?;
+
+            let name = unique(names, &field_name(property_type.title())?, "_");
+            let path = format!("{path}.{name}");
+            let mut wire_type = self.values(
+                &path,
+                property_type.id(),
+                property_type.title(),
+                base_uri.as_str(),
+                property_type.one_of(),
+            )?;
+            if let ValueOrArray::Array(array) = property {
+                self.bounds(&path, array);
+                wire_type = WireType::List(Box::new(wire_type));
+            }
+
+            fields.push(Field {
+                key: base_uri.to_string(),
+                name,
+                wire_type,
+                required: required.contains(base_uri),
+            });
+        }
+        Ok(fields)
+    }
+
+    fn values(
+        &mut self,
+        path: &str,
+        id: &VersionedUri,
+        title: &str,
+        key: &str,
+        values: &[PropertyValues],
+    ) -> Result<WireType, ExportSchemaError> {
+        if let [value] = values {
+            return self
+                .value(path, id, title, key, value)
+                .map(|(wire_type, _)| wire_type);
+        }
+
+        let mut names = HashSet::new();
+        let mut keys = HashSet::new();
+        let mut variants: Vec<Variant> = Vec::new();
+        for value in values {
+            let descriptor = match value {
+                PropertyValues::DataTypeReference(reference) => {
+                    reference.uri().base_uri().to_string()
+                }
+                PropertyValues::PropertyTypeObject(_) => "object".to_owned(),
+                PropertyValues::ArrayOfPropertyValues(_) => "list".to_owned(),
+            };
+            let variant_key = unique(&mut keys, &format!("{key}#{descriptor}"), "");
+            let (wire_type, name) = self.value(path, id, title, &variant_key, value)?;
+            if variants
+                .iter()
+                .any(|existing| existing.wire_type == wire_type)
+            {
+                self.approximate(path, ApproximationReason::IndistinguishableVariants);
+                continue;
+            }
+            variants.push(Variant {
+                key: variant_key,
+                name: unique(&mut names, &field_name(&name)?, "_"),
+                wire_type,
+            });
+        }
+
+        if variants.len() == 1 {
+            Ok(variants.remove(0).wire_type)
+        } else {
+            Ok(WireType::Union(variants))
+        }
+    }
+
+    /// Returns the wire type of `value` and a name for it as a variant of a union.
+    fn value(
+        &mut self,
+        path: &str,
+        id: &VersionedUri,
+        title: &str,
+        key: &str,
+        value: &PropertyValues,
+    ) -> Result<(WireType, String), ExportSchemaError> {
+        match value {
+            PropertyValues::DataTypeReference(reference) => {
+                let data_type = self
+                    .resolver
+                    .resolve_data_type(reference.uri())
+                    .map_err(|error| ExportSchemaError::unresolved(reference.uri(), &error))?;
+                Ok((
+                    self.data_type(path, &data_type),
+                    data_type.title().to_owned(),
+                ))
+            }
+            PropertyValues::PropertyTypeObject(object) => {
+                let cache_key = (id.clone(), key.to_owned());
+                if let Some(index) = self.property_type_messages.get(&cache_key) {
+                    return Ok((WireType::Message(*index), "object".to_owned()));
+                }
+                let index = self.messages.len();
+                self.property_type_messages.insert(cache_key, index);
+                let name = unique(&mut self.names, &message_name(title)?, "");
+                self.messages.push(Message {
+                    key: key.to_owned(),
+                    name,
+                    description: None,
+                    fields: Vec::new(),
+                });
+                self.messages[index].fields = self.fields(
+                    path,
+                    &mut HashSet::new(),
+                    object.properties().iter(),
+                    object.required(),
+                )?;
+                Ok((WireType::Message(index), "object".to_owned()))
+            }
+            PropertyValues::ArrayOfPropertyValues(array) => {
+                self.bounds(path, array);
+                let items = self.values(
+                    &format!("{path}[]"),
+                    id,
+                    title,
+                    &format!("{key}[]"),
+                    array.items().one_of(),
+                )?;
+                Ok((WireType::List(Box::new(items)), "list".to_owned()))
+            }
+        }
+    }
+
+    fn data_type(&mut self, path: &str, data_type: &DataType) -> WireType {
+        if !data_type.additional_properties().is_empty() {
+            self.approximate(path, ApproximationReason::ConstrainedDataType);
+        }
+        match data_type.json_type() {
+            "string" => WireType::String,
+            "number" => WireType::Double,
+            "integer" => WireType::Long,
+            "boolean" => WireType::Boolean,
+            "null" => WireType::Null,
+            _ => {
+                self.approximate(path, ApproximationReason::ArbitraryJson);
+                WireType::String
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        repository::{
+            fixtures::{self, store},
+            FsTypeRepository,
+        },
+        PropertyType,
+    };
+
+    fn repository(directory: &tempfile::TempDir) -> FsTypeRepository {
+        let repository = fixtures::repository(directory);
+        store(
+            &repository,
+            &DataType::try_from(json!({
+                "kind": "dataType",
+                "$id": "https://blockprotocol.org/@alice/types/data-type/email/v/1",
+                "title": "Email",
+                "type": "string",
+                "format": "email"
+            }))
+            .expect("invalid type"),
+        );
+        for property_type in [
+            json!({
+                "kind": "propertyType",
+                "$id": "https://blockprotocol.org/@alice/types/property-type/email/v/1",
+                "title": "Email",
+                "pluralTitle": "Emails",
+                "oneOf": [{ "$ref": "https://blockprotocol.org/@alice/types/data-type/email/v/1" }]
+            }),
+            json!({
+                "kind": "propertyType",
+                "$id": "https://blockprotocol.org/@alice/types/property-type/address/v/1",
+                "title": "Address",
+                "pluralTitle": "Addresses",
+                "oneOf": [{
+                    "type": "object",
+                    "properties": {
+                        "https://blockprotocol.org/@alice/types/property-type/name/": {
+                            "type": "array",
+                            "items": { "$ref": "https://blockprotocol.org/@alice/types/property-type/name/v/1" }
+                        }
+                    }
+                }]
+            }),
+            json!({
+                "kind": "propertyType",
+                "$id": "https://blockprotocol.org/@alice/types/property-type/identifier/v/1",
+                "title": "Identifier",
+                "pluralTitle": "Identifiers",
+                "oneOf": [
+                    { "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" },
+                    { "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1" },
+                    { "$ref": "https://blockprotocol.org/@alice/types/data-type/email/v/1" }
+                ]
+            }),
+        ] {
+            store(
+                &repository,
+                &PropertyType::try_from(property_type).expect("invalid type"),
+            );
+        }
+        repository
+    }
+
+    fn person(properties: &[&str]) -> EntityType {
+        let properties: serde_json::Map<_, _> = properties
+            .iter()
+            .map(|slug| {
+                let base_uri =
+                    format!("https://blockprotocol.org/@alice/types/property-type/{slug}/");
+                let reference = json!({ "$ref": format!("{base_uri}v/1") });
+                (base_uri, reference)
+            })
+            .collect();
+        EntityType::try_from(json!({
+            "kind": "entityType",
+            "$id": "https://blockprotocol.org/@alice/types/entity-type/person/v/1",
+            "type": "object",
+            "title": "Person",
+            "pluralTitle": "People",
+            "description": "A human being",
+            "properties": properties,
+            "required": ["https://blockprotocol.org/@alice/types/property-type/name/"],
+            "links": {
+                "https://blockprotocol.org/@alice/types/link-type/owns/v/2": {
+                    "type": "array",
+                    "items": { "$ref": "https://blockprotocol.org/@alice/types/entity-type/book/v/1" },
+                    "ordered": false
+                }
+            }
+        }))
+        .expect("invalid entity type")
+    }
+
+    #[test]
+    fn protobuf() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = repository(&directory);
+        let schema = WireSchema::new(
+            &person(&["name", "age", "address", "identifier", "contrived-property"]),
+            &repository,
+        )
+        .expect("could not create schema");
+
+        assert_eq!(
+            schema.to_protobuf(&mut FieldNumbers::new()),
+            r#"syntax = "proto3";
+
+// A human being
+message Person {
+  Address address = 1;
+  optional double age = 2;
+  oneof contrived_property {
+    double contrived_property_number = 3;
+    PersonContrivedPropertyList contrived_property_list = 4;
+  }
+  oneof identifier {
+    string identifier_text = 5;
+    double identifier_number = 6;
+  }
+  string name = 7;
+  repeated string owns = 8;
+}
+
+message PersonContrivedPropertyList {
+  repeated double values = 1;
+}
+
+message Address {
+  repeated string name = 1;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn recursive_property_types() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = repository(&directory);
+        let schema = WireSchema::new(&person(&["name", "tree-node"]), &repository)
+            .expect("could not create schema");
+
+        let proto = schema.to_protobuf(&mut FieldNumbers::new());
+        assert_eq!(proto.matches("message TreeNode ").count(), 1, "{proto}");
+        assert!(proto.contains(
+            "message TreeNode {
+  string name = 1;
+  repeated TreeNode tree_node = 2;
+}"
+        ));
+
+        // Records are defined once and referenced by their name afterwards
+        let avro = schema.to_avro().to_string();
+        assert_eq!(avro.matches(r#""name":"TreeNode""#).count(), 1, "{avro}");
+        assert!(avro.contains(r#"{"items":"TreeNode","type":"array"}"#));
+    }
+
+    #[test]
+    fn field_numbers() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = repository(&directory);
+        let path = directory.path().join("numbers.json");
+
+        let mut numbers = FieldNumbers::load(&path).expect("could not load numbers");
+        let _ = WireSchema::new(&person(&["name", "age"]), &repository)
+            .expect("could not create schema")
+            .to_protobuf(&mut numbers);
+        numbers.save(&path).expect("could not save numbers");
+
+        let mut numbers = FieldNumbers::load(&path).expect("could not load numbers");
+        assert_eq!(
+            numbers.get(
+                "https://blockprotocol.org/@alice/types/entity-type/person/",
+                "https://blockprotocol.org/@alice/types/property-type/age/"
+            ),
+            Some(1)
+        );
+        let proto = WireSchema::new(&person(&["name", "email"]), &repository)
+            .expect("could not create schema")
+            .to_protobuf(&mut numbers);
+        assert!(proto.contains(
+            "message Person {
+  reserved 1;
+  optional string email = 4;
+  string name = 2;
+  repeated string owns = 3;
+}"
+        ));
+    }
+
+    #[test]
+    fn avro() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = repository(&directory);
+        let schema = WireSchema::new(
+            &person(&["name", "address", "identifier", "contrived-property"]),
+            &repository,
+        )
+        .expect("could not create schema");
+
+        assert_eq!(
+            schema.to_avro(),
+            json!({
+                "type": "record",
+                "name": "Person",
+                "doc": "A human being",
+                "fields": [
+                    {
+                        "name": "address",
+                        "type": ["null", {
+                            "type": "record",
+                            "name": "Address",
+                            "fields": [{
+                                "name": "name",
+                                "type": ["null", { "type": "array", "items": "string" }],
+                                "default": null,
+                                "baseUri": "https://blockprotocol.org/@alice/types/property-type/name/"
+                            }]
+                        }],
+                        "default": null,
+                        "baseUri": "https://blockprotocol.org/@alice/types/property-type/address/"
+                    },
+                    {
+                        "name": "contrived_property",
+                        "type": ["null", "double", { "type": "array", "items": "double" }],
+                        "default": null,
+                        "baseUri": "https://blockprotocol.org/@alice/types/property-type/contrived-property/"
+                    },
+                    {
+                        "name": "identifier",
+                        "type": ["null", "string", "double"],
+                        "default": null,
+                        "baseUri": "https://blockprotocol.org/@alice/types/property-type/identifier/"
+                    },
+                    {
+                        "name": "name",
+                        "type": "string",
+                        "baseUri": "https://blockprotocol.org/@alice/types/property-type/name/"
+                    },
+                    {
+                        "name": "owns",
+                        "type": ["null", { "type": "array", "items": "string" }],
+                        "default": null,
+                        "baseUri": "https://blockprotocol.org/@alice/types/link-type/owns/"
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn approximations() {
+        let directory = tempfile::tempdir().expect("failed to create directory");
+        let repository = repository(&directory);
+        let schema = WireSchema::new(
+            &person(&["name", "identifier", "contrived-property", "email"]),
+            &repository,
+        )
+        .expect("could not create schema");
+
+        let approximations: Vec<_> = schema
+            .approximations()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(approximations, [
+            "`Person.contrived_property` has a number of items which is not enforced",
+            "`Person.email` has constraints which are not enforced",
+            "`Person.identifier` has constraints which are not enforced",
+            "`Person.identifier` has variants which cannot be distinguished",
+        ]);
+    }
+}
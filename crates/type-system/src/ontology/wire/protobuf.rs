@@ -0,0 +1,320 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    fmt::Write,
+    fs, io,
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{name_part, FieldNumbersError, WireSchema, WireType};
+use crate::ontology::shared::naming::unique;
+
+/// The numbers of the fields of Protobuf messages.
+///
+/// The numbers are keyed by the message and the field they belong to, which are identified by the
+/// base URIs of the types they were generated from. Numbers are never reused, so removing a field
+/// from a type and adding a different one does not break existing messages.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FieldNumbers {
+    messages: BTreeMap<String, BTreeMap<String, u32>>,
+}
+
+impl FieldNumbers {
+    /// Creates an empty mapping.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the mapping stored as JSON at `path`.
+    ///
+    /// Returns an empty mapping if the file does not exist.
+    ///
+    /// # Errors
+    ///
+    /// - [`FieldNumbersError::Io`] if the file could not be read
+    /// - [`FieldNumbersError::InvalidMapping`] if the file does not contain a valid mapping
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, FieldNumbersError> {
+        let path = path.as_ref();
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Self::new()),
+            Err(error) => {
+                return Err(FieldNumbersError::Io {
+                    path: path.to_path_buf(),
+                    error,
+                });
+            }
+        };
+        serde_json::from_str(&contents).map_err(|error| FieldNumbersError::InvalidMapping {
+            path: path.to_path_buf(),
+            error,
+        })
+    }
+
+    /// Writes the mapping as JSON to `path`, replacing any existing file.
+    ///
+    /// # Errors
+    ///
+    /// - [`FieldNumbersError::Io`] if the file could not be written
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), FieldNumbersError> {
+        let path = path.as_ref();
+        serde_json::to_string_pretty(self)
+            .map_err(io::Error::from)
+            .and_then(|contents| fs::write(path, format!("{contents}\n")))
+            .map_err(|error| FieldNumbersError::Io {
+                path: path.to_path_buf(),
+                error,
+            })
+    }
+
+    /// Returns the number of `field` inside of `message`, if one was assigned.
+    #[must_use]
+    pub fn get(&self, message: &str, field: &str) -> Option<u32> {
+        self.messages.get(message)?.get(field).copied()
+    }
+
+    /// Returns the number of `field` inside of `message`, and assigns the next free number if
+    /// none was assigned yet.
+    fn assign(&mut self, message: &str, field: &str) -> u32 {
+        let fields = self.messages.entry(message.to_owned()).or_default();
+        if let Some(number) = fields.get(field) {
+            return *number;
+        }
+        let number = fields.values().max().map_or(1, |max| max + 1);
+        fields.insert(field.to_owned(), number);
+        number
+    }
+}
+
+enum ProtoField {
+    Single {
+        label: &'static str,
+        proto_type: String,
+        name: String,
+        key: String,
+    },
+    OneOf {
+        name: String,
+        /// The type, name, and key of each member.
+        members: Vec<(String, String, String)>,
+    },
+}
+
+struct Renderer<'a> {
+    schema: &'a WireSchema,
+    numbers: &'a mut FieldNumbers,
+    names: HashSet<String>,
+    /// Wrapper messages, which are rendered after the message they are used in.
+    wrappers: Vec<String>,
+    null: bool,
+}
+
+impl Renderer<'_> {
+    fn message(
+        &mut self,
+        key: &str,
+        name: &str,
+        description: Option<&str>,
+        fields: Vec<ProtoField>,
+    ) -> String {
+        let mut body = String::new();
+        let mut used = HashSet::new();
+        for field in fields {
+            match field {
+                ProtoField::Single {
+                    label,
+                    proto_type,
+                    name,
+                    key: field_key,
+                } => {
+                    let number = self.numbers.assign(key, &field_key);
+                    used.insert(number);
+                    let _ = writeln!(body, "  {label}{proto_type} {name} = {number};");
+                }
+                ProtoField::OneOf { name, members } => {
+                    let _ = writeln!(body, "  oneof {name} {{");
+                    for (proto_type, name, field_key) in members {
+                        let number = self.numbers.assign(key, &field_key);
+                        used.insert(number);
+                        let _ = writeln!(body, "    {proto_type} {name} = {number};");
+                    }
+                    body.push_str("  }\n");
+                }
+            }
+        }
+
+        let mut definition = String::new();
+        if let Some(description) = description {
+            for line in description.lines() {
+                let _ = writeln!(definition, "// {line}");
+            }
+        }
+        let _ = writeln!(definition, "message {name} {{");
+        let reserved: BTreeSet<_> = self
+            .numbers
+            .messages
+            .get(key)
+            .into_iter()
+            .flat_map(BTreeMap::values)
+            .filter(|number| !used.contains(number))
+            .collect();
+        let reserved: Vec<_> = reserved.into_iter().map(ToString::to_string).collect();
+        if !reserved.is_empty() {
+            let _ = writeln!(definition, "  reserved {};", reserved.join(", "));
+        }
+        definition.push_str(&body);
+        definition.push_str("}\n");
+        definition
+    }
+
+    /// Defines a message containing a single field and returns its name.
+    fn wrapper(&mut self, key: &str, name: &str, field: ProtoField) -> String {
+        let name = unique(&mut self.names, name, "");
+        let definition = self.message(key, &name, None, vec![field]);
+        self.wrappers.push(definition);
+        name
+    }
+
+    fn field(
+        &mut self,
+        parent: &str,
+        key: &str,
+        name: &str,
+        wire_type: &WireType,
+        required: bool,
+    ) -> ProtoField {
+        match wire_type {
+            WireType::Union(variants) => ProtoField::OneOf {
+                name: name.to_owned(),
+                members: variants
+                    .iter()
+                    .map(|variant| {
+                        let wrapper =
+                            format!("{parent}{}{}", name_part(name), name_part(&variant.name));
+                        let proto_type = match &variant.wire_type {
+                            WireType::List(items) => {
+                                let field = self.repeated(&wrapper, &variant.key, "values", items);
+                                self.wrapper(&variant.key, &wrapper, field)
+                            }
+                            wire_type => self.scalar(wire_type),
+                        };
+                        (
+                            proto_type,
+                            format!("{name}_{}", variant.name),
+                            variant.key.clone(),
+                        )
+                    })
+                    .collect(),
+            },
+            WireType::List(items) => self.repeated(parent, key, name, items),
+            WireType::Message(_) => ProtoField::Single {
+                label: "",
+                proto_type: self.scalar(wire_type),
+                name: name.to_owned(),
+                key: key.to_owned(),
+            },
+            _ => ProtoField::Single {
+                label: if required { "" } else { "optional " },
+                proto_type: self.scalar(wire_type),
+                name: name.to_owned(),
+                key: key.to_owned(),
+            },
+        }
+    }
+
+    fn repeated(&mut self, parent: &str, key: &str, name: &str, items: &WireType) -> ProtoField {
+        let proto_type = match items {
+            WireType::List(_) | WireType::Union(_) => {
+                // Lists of lists and lists of unions cannot be represented directly
+                let wrapper = format!("{parent}{}Item", name_part(name));
+                let item_key = format!("{key}[]");
+                let field = self.field(&wrapper, &item_key, "value", items, true);
+                self.wrapper(&item_key, &wrapper, field)
+            }
+            _ => self.scalar(items),
+        };
+        ProtoField::Single {
+            label: "repeated ",
+            proto_type,
+            name: name.to_owned(),
+            key: key.to_owned(),
+        }
+    }
+
+    /// Returns the type of values which are neither lists nor unions.
+    fn scalar(&mut self, wire_type: &WireType) -> String {
+        match wire_type {
+            WireType::String => "string".to_owned(),
+            WireType::Double => "double".to_owned(),
+            WireType::Long => "int64".to_owned(),
+            WireType::Boolean => "bool".to_owned(),
+            WireType::Null => {
+                self.null = true;
+                "google.protobuf.NullValue".to_owned()
+            }
+            WireType::Message(index) => self.schema.messages[*index].name.clone(),
+            WireType::List(_) | WireType::Union(_) => {
+                unreachable!("lists and unions are wrapped in messages")
+            }
+        }
+    }
+}
+
+impl WireSchema {
+    /// Renders the schema as Protobuf messages.
+    ///
+    /// The numbers of the fields are taken from `numbers`. Fields without a number are assigned
+    /// the next free number of their message, which is added to `numbers`, and numbers in
+    /// `numbers` which are no longer used are reserved.
+    #[must_use]
+    pub fn to_protobuf(&self, numbers: &mut FieldNumbers) -> String {
+        let mut renderer = Renderer {
+            schema: self,
+            numbers,
+            names: self
+                .messages
+                .iter()
+                .map(|message| message.name.clone())
+                .collect(),
+            wrappers: Vec::new(),
+            null: false,
+        };
+
+        let mut definitions = Vec::new();
+        for message in &self.messages {
+            let fields = message
+                .fields
+                .iter()
+                .map(|field| {
+                    renderer.field(
+                        &message.name,
+                        &field.key,
+                        &field.name,
+                        &field.wire_type,
+                        field.required,
+                    )
+                })
+                .collect();
+            definitions.push(renderer.message(
+                &message.key,
+                &message.name,
+                message.description.as_deref(),
+                fields,
+            ));
+            definitions.append(&mut renderer.wrappers);
+        }
+
+        let mut proto = "syntax = \"proto3\";\n".to_owned();
+        if renderer.null {
+            proto.push_str("\nimport \"google/protobuf/struct.proto\";\n");
+        }
+        for definition in definitions {
+            proto.push('\n');
+            proto.push_str(&definition);
+        }
+        proto
+    }
+}
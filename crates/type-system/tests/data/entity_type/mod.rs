@@ -7,3 +7,4 @@ pub(crate) const PAGE: &str = include_str!("page.json");
 pub(crate) const PERSON_V1: &str = include_str!("person.json");
 pub(crate) const PLAYLIST_V1: &str = include_str!("playlist.json");
 pub(crate) const SONG_V1: &str = include_str!("song.json");
+pub(crate) const TREE_V1: &str = include_str!("tree.json");
@@ -6,5 +6,6 @@ pub(crate) const NAME_V1: &str = include_str!("name.json");
 pub(crate) const INTERESTS_V1: &str = include_str!("interests.json");
 pub(crate) const NUMBERS_V1: &str = include_str!("numbers.json");
 pub(crate) const TEXT_V1: &str = include_str!("text.json");
+pub(crate) const TREE_NODE_V1: &str = include_str!("tree_node.json");
 pub(crate) const USER_ID_V1: &str = include_str!("user_id_v1.json");
 pub(crate) const USER_ID_V2: &str = include_str!("user_id_v2.json");